@@ -0,0 +1,122 @@
+//! Geometric helper algorithms shared by readers, writers and processors.
+
+/// Signed area of a ring using the shoelace formula.
+///
+/// The result is positive for counterclockwise rings and negative for clockwise rings
+/// (in a y-up coordinate system). In a y-down system like MVT tile coordinates, the sign
+/// is inverted. The ring may be closed or open, the closing segment is implied.
+pub fn ring_signed_area(coords: &[[f64; 2]]) -> f64 {
+    if coords.len() < 3 {
+        return 0.0;
+    }
+    let mut sum = 0.0;
+    let mut prev = coords[coords.len() - 1];
+    for coord in coords {
+        sum += prev[0] * coord[1] - coord[0] * prev[1];
+        prev = *coord;
+    }
+    sum / 2.0
+}
+
+/// Extend the bounding box `[minx, miny, maxx, maxy]` with a coordinate.
+///
+/// Coordinates with NaN values (e.g. WKB `POINT EMPTY`) are skipped, returns whether
+/// the coordinate was added.
+pub fn extend_bbox(bbox: &mut Option<[f64; 4]>, x: f64, y: f64) -> bool {
+    if x.is_nan() || y.is_nan() {
+        return false;
+    }
+    *bbox = Some(match *bbox {
+        Some([minx, miny, maxx, maxy]) => [minx.min(x), miny.min(y), maxx.max(x), maxy.max(y)],
+        None => [x, y, x, y],
+    });
+    true
+}
+
+/// Coordinate value formatter shared by text writers.
+///
+/// Without `precision`, the value is written with the shortest digits parsing back to the
+/// same `f64`. Otherwise it is rounded to `precision` decimal places and written without
+/// trailing zeros, e.g. `1.5` instead of `1.5000`.
+pub struct CoordFormat {
+    pub value: f64,
+    pub precision: Option<usize>,
+}
+
+impl std::fmt::Display for CoordFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some(precision) = self.precision else {
+            return write!(f, "{}", self.value);
+        };
+        let text = format!("{:.precision$}", self.value);
+        let text = if text.contains('.') {
+            text.trim_end_matches('0').trim_end_matches('.')
+        } else {
+            &text
+        };
+        f.write_str(if text == "-0" { "0" } else { text })
+    }
+}
+
+/// Default number of decimal places of coordinates in the CRS `srid`.
+///
+/// 7 decimals (about 1 cm) for common geographic CRS and 2 decimals (1 cm) for common
+/// projected CRS with meter units, `None` for other SRIDs.
+pub fn srid_precision(srid: i32) -> Option<usize> {
+    match srid {
+        // WGS 84, NAD27, NAD83, ETRS89, GDA94, JGD2000, RGF93, SIRGAS 2000, CGCS2000, GDA2020
+        4326 | 4267 | 4269 | 4258 | 4283 | 4612 | 4171 | 4674 | 4490 | 7844 => Some(7),
+        // Web Mercator, WGS 84 / UTM, ETRS89 / UTM, NAD83 / UTM
+        3857 | 900913 | 32601..=32660 | 32701..=32760 | 25828..=25838 | 26901..=26923 => Some(2),
+        // ETRS89 / LAEA + LCC Europe, Swiss, British, French, Dutch, German, Swedish, Finnish, NZ, US Albers
+        3035 | 3034 | 2056 | 21781 | 27700 | 2154 | 28992 | 31467 | 31468 | 3006 | 3067 | 2193
+        | 5070 => Some(2),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn signed_area() {
+        let ccw = [[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0], [0.0, 0.0]];
+        assert_eq!(ring_signed_area(&ccw), 4.0);
+        let cw = [[0.0, 0.0], [0.0, 2.0], [2.0, 2.0], [2.0, 0.0], [0.0, 0.0]];
+        assert_eq!(ring_signed_area(&cw), -4.0);
+        // open ring
+        assert_eq!(ring_signed_area(&ccw[..4]), 4.0);
+        // degenerate
+        assert_eq!(ring_signed_area(&ccw[..2]), 0.0);
+    }
+
+    #[test]
+    fn bbox() {
+        let mut bbox = None;
+        assert!(!extend_bbox(&mut bbox, f64::NAN, f64::NAN));
+        assert_eq!(bbox, None);
+        assert!(extend_bbox(&mut bbox, 1.0, 2.0));
+        assert!(extend_bbox(&mut bbox, -1.0, 5.0));
+        assert!(!extend_bbox(&mut bbox, 0.0, f64::NAN));
+        assert_eq!(bbox, Some([-1.0, 2.0, 1.0, 5.0]));
+    }
+
+    #[test]
+    fn coord_format() {
+        let fmt = |value, precision| CoordFormat { value, precision }.to_string();
+        assert_eq!(fmt(0.1, None), "0.1");
+        assert_eq!(fmt(8.54321987, Some(4)), "8.5432");
+        assert_eq!(fmt(47.00004, Some(4)), "47");
+        assert_eq!(fmt(-0.00001, Some(4)), "0");
+        assert_eq!(fmt(2.5, Some(0)), "2");
+    }
+
+    #[test]
+    fn precision_by_srid() {
+        assert_eq!(srid_precision(4326), Some(7));
+        assert_eq!(srid_precision(3857), Some(2));
+        assert_eq!(srid_precision(32632), Some(2));
+        assert_eq!(srid_precision(0), None);
+    }
+}
@@ -1,5 +1,5 @@
 use crate::error::Result;
-use crate::wkb::wkb_reader::{process_wkb_geom_n, read_wkb_header};
+use crate::wkb::wkb_reader::{process_wkb_geom_n, read_wkb_header, WkbReadOptions};
 use crate::{GeomProcessor, GeozeroGeometry};
 use arrow2::array::BinaryArray;
 use arrow2::types::Offset;
@@ -23,10 +23,11 @@ pub fn process_geoarrow_wkb_geom<T: Offset>(
     let array_len = array.len();
     processor.geometrycollection_begin(array_len, 0)?;
 
+    let opts = WkbReadOptions::default();
     for i in 0..array_len {
         let raw = &mut array.value(i);
-        let info = read_wkb_header(raw)?;
-        process_wkb_geom_n(raw, &info, read_wkb_header, i, processor)?;
+        let info = read_wkb_header(raw, &opts)?;
+        process_wkb_geom_n(raw, &info, read_wkb_header, &opts, i, processor)?;
     }
 
     processor.geometrycollection_end(array_len - 1)
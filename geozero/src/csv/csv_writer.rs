@@ -249,11 +249,13 @@ impl<W: Write> GeomProcessor for CsvWriter<'_, W> {
 // incorporated in the output of the CSV writer. Is there a better way?
 mod buffering_wkt_writer {
     use crate::error::Result;
+    use crate::wkt::wkt_writer::WktState;
     use crate::{wkt::WktWriter, CoordDimensions, GeomProcessor};
 
     #[derive(Default)]
     pub(crate) struct BufferingWktWriter {
         buffer: Vec<u8>,
+        state: WktState,
         pub(crate) dims: CoordDimensions,
     }
 
@@ -264,14 +266,19 @@ mod buffering_wkt_writer {
 
         pub(crate) fn clear(&mut self) {
             self.buffer.clear();
+            self.state = WktState::default();
         }
 
         pub(crate) fn bytes(&self) -> &[u8] {
             &self.buffer
         }
 
-        fn wkt_writer(&mut self) -> WktWriter<'_, Vec<u8>> {
-            WktWriter::new(&mut self.buffer)
+        fn write(&mut self, f: impl FnOnce(&mut WktWriter<Vec<u8>>) -> Result<()>) -> Result<()> {
+            let mut writer = WktWriter::new(&mut self.buffer);
+            writer.state = self.state;
+            f(&mut writer)?;
+            self.state = writer.state;
+            Ok(())
         }
     }
 
@@ -280,7 +287,7 @@ mod buffering_wkt_writer {
             self.dims
         }
         fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
-            self.wkt_writer().xy(x, y, idx)
+            self.write(|w| w.xy(x, y, idx))
         }
 
         fn coordinate(
@@ -293,101 +300,101 @@ mod buffering_wkt_writer {
             tm: Option<u64>,
             idx: usize,
         ) -> Result<()> {
-            self.wkt_writer().coordinate(x, y, z, m, t, tm, idx)
+            self.write(|w| w.coordinate(x, y, z, m, t, tm, idx))
         }
 
         fn empty_point(&mut self, idx: usize) -> Result<()> {
-            self.wkt_writer().empty_point(idx)
+            self.write(|w| w.empty_point(idx))
         }
         fn point_begin(&mut self, idx: usize) -> Result<()> {
-            self.wkt_writer().point_begin(idx)
+            self.write(|w| w.point_begin(idx))
         }
         fn point_end(&mut self, idx: usize) -> Result<()> {
-            self.wkt_writer().point_end(idx)
+            self.write(|w| w.point_end(idx))
         }
         fn multipoint_begin(&mut self, size: usize, idx: usize) -> Result<()> {
-            self.wkt_writer().multipoint_begin(size, idx)
+            self.write(|w| w.multipoint_begin(size, idx))
         }
         fn multipoint_end(&mut self, idx: usize) -> Result<()> {
-            self.wkt_writer().multipoint_end(idx)
+            self.write(|w| w.multipoint_end(idx))
         }
         fn linestring_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
-            self.wkt_writer().linestring_begin(tagged, size, idx)
+            self.write(|w| w.linestring_begin(tagged, size, idx))
         }
         fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
-            self.wkt_writer().linestring_end(tagged, idx)
+            self.write(|w| w.linestring_end(tagged, idx))
         }
         fn multilinestring_begin(&mut self, size: usize, idx: usize) -> Result<()> {
-            self.wkt_writer().multilinestring_begin(size, idx)
+            self.write(|w| w.multilinestring_begin(size, idx))
         }
         fn multilinestring_end(&mut self, idx: usize) -> Result<()> {
-            self.wkt_writer().multilinestring_end(idx)
+            self.write(|w| w.multilinestring_end(idx))
         }
         fn polygon_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
-            self.wkt_writer().polygon_begin(tagged, size, idx)
+            self.write(|w| w.polygon_begin(tagged, size, idx))
         }
         fn polygon_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
-            self.wkt_writer().polygon_end(tagged, idx)
+            self.write(|w| w.polygon_end(tagged, idx))
         }
         fn multipolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
-            self.wkt_writer().multipolygon_begin(size, idx)
+            self.write(|w| w.multipolygon_begin(size, idx))
         }
         fn multipolygon_end(&mut self, idx: usize) -> Result<()> {
-            self.wkt_writer().multipolygon_end(idx)
+            self.write(|w| w.multipolygon_end(idx))
         }
         fn geometrycollection_begin(&mut self, size: usize, idx: usize) -> Result<()> {
-            self.wkt_writer().geometrycollection_begin(size, idx)
+            self.write(|w| w.geometrycollection_begin(size, idx))
         }
         fn geometrycollection_end(&mut self, idx: usize) -> Result<()> {
-            self.wkt_writer().geometrycollection_end(idx)
+            self.write(|w| w.geometrycollection_end(idx))
         }
         fn circularstring_begin(&mut self, size: usize, idx: usize) -> Result<()> {
-            self.wkt_writer().circularstring_begin(size, idx)
+            self.write(|w| w.circularstring_begin(size, idx))
         }
         fn circularstring_end(&mut self, idx: usize) -> Result<()> {
-            self.wkt_writer().circularstring_end(idx)
+            self.write(|w| w.circularstring_end(idx))
         }
         fn compoundcurve_begin(&mut self, size: usize, idx: usize) -> Result<()> {
-            self.wkt_writer().compoundcurve_begin(size, idx)
+            self.write(|w| w.compoundcurve_begin(size, idx))
         }
         fn compoundcurve_end(&mut self, idx: usize) -> Result<()> {
-            self.wkt_writer().compoundcurve_end(idx)
+            self.write(|w| w.compoundcurve_end(idx))
         }
         fn curvepolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
-            self.wkt_writer().curvepolygon_begin(size, idx)
+            self.write(|w| w.curvepolygon_begin(size, idx))
         }
         fn curvepolygon_end(&mut self, idx: usize) -> Result<()> {
-            self.wkt_writer().curvepolygon_end(idx)
+            self.write(|w| w.curvepolygon_end(idx))
         }
         fn multicurve_begin(&mut self, size: usize, idx: usize) -> Result<()> {
-            self.wkt_writer().multicurve_begin(size, idx)
+            self.write(|w| w.multicurve_begin(size, idx))
         }
         fn multicurve_end(&mut self, idx: usize) -> Result<()> {
-            self.wkt_writer().multicurve_end(idx)
+            self.write(|w| w.multicurve_end(idx))
         }
         fn multisurface_begin(&mut self, size: usize, idx: usize) -> Result<()> {
-            self.wkt_writer().multisurface_begin(size, idx)
+            self.write(|w| w.multisurface_begin(size, idx))
         }
         fn multisurface_end(&mut self, idx: usize) -> Result<()> {
-            self.wkt_writer().multisurface_end(idx)
+            self.write(|w| w.multisurface_end(idx))
         }
         fn triangle_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
-            self.wkt_writer().triangle_begin(tagged, size, idx)
+            self.write(|w| w.triangle_begin(tagged, size, idx))
         }
         fn triangle_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
-            self.wkt_writer().triangle_end(tagged, idx)
+            self.write(|w| w.triangle_end(tagged, idx))
         }
         fn polyhedralsurface_begin(&mut self, size: usize, idx: usize) -> Result<()> {
-            self.wkt_writer().polyhedralsurface_begin(size, idx)
+            self.write(|w| w.polyhedralsurface_begin(size, idx))
         }
         fn polyhedralsurface_end(&mut self, idx: usize) -> Result<()> {
-            self.wkt_writer().polyhedralsurface_end(idx)
+            self.write(|w| w.polyhedralsurface_end(idx))
         }
         fn tin_begin(&mut self, size: usize, idx: usize) -> Result<()> {
-            self.wkt_writer().tin_begin(size, idx)
+            self.write(|w| w.tin_begin(size, idx))
         }
         fn tin_end(&mut self, idx: usize) -> Result<()> {
-            self.wkt_writer().tin_end(idx)
+            self.write(|w| w.tin_end(idx))
         }
     }
 }
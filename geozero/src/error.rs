@@ -8,6 +8,9 @@ pub enum GeozeroError {
     GeometryIndex,
     #[error("geometry format")]
     GeometryFormat,
+    /// Valid geometry type code which is not supported by the reader
+    #[error("unsupported geometry type `{name}` ({type_id})")]
+    UnsupportedGeometryType { type_id: u32, name: String },
     // Http errors
     #[error("http status {0}")]
     HttpStatus(u16),
@@ -32,8 +35,13 @@ pub enum GeozeroError {
     // GeometryProcessor
     #[error("accessing requested coordinate")]
     Coord,
+    /// Geometry error, also used by processors to abort processing with a message.
+    /// Readers propagate it unchanged.
     #[error("processing geometry `{0}`")]
     Geometry(String),
+    /// Processing was cancelled
+    #[error("processing cancelled")]
+    Cancelled,
     // General
     #[error("I/O error")]
     IoError(#[from] std::io::Error),
@@ -0,0 +1,3 @@
+//! EsriJSON (ArcGIS JSON) conversions.
+mod writer;
+pub use writer::EsriJsonWriter;
@@ -0,0 +1,324 @@
+use crate::algorithm::ring_signed_area;
+use crate::error::{GeozeroError, Result};
+use crate::{CoordDimensions, FeatureProcessor, GeomProcessor, PropertyProcessor};
+use std::io::Write;
+
+/// EsriJSON (ArcGIS JSON) geometry writer.
+///
+/// LineStrings and MultiLineStrings are written as polylines (`paths`), Polygons and
+/// MultiPolygons as polygons (`rings`). Rings are reoriented to the Esri convention
+/// with clockwise exterior and counter-clockwise interior rings.
+/// GeometryCollections, curves and surfaces are not supported.
+pub struct EsriJsonWriter<'a, W: Write> {
+    pub dims: CoordDimensions,
+    srid: Option<i32>,
+    /// Index of the next ring in the `rings` array
+    ring_idx: usize,
+    /// Buffered x/y of the current point or polygon ring
+    buffer: Option<Vec<[f64; 2]>>,
+    /// Z/M values of the buffered coordinates
+    buffer_zm: Vec<(Option<f64>, Option<f64>)>,
+    in_multi: bool,
+    out: &'a mut W,
+}
+
+impl<'a, W: Write> EsriJsonWriter<'a, W> {
+    pub fn new(out: &'a mut W) -> EsriJsonWriter<'a, W> {
+        EsriJsonWriter {
+            dims: CoordDimensions::default(),
+            srid: None,
+            ring_idx: 0,
+            buffer: None,
+            buffer_zm: Vec::new(),
+            in_multi: false,
+            out,
+        }
+    }
+
+    fn comma(&mut self, idx: usize) -> Result<()> {
+        if idx > 0 {
+            self.out.write_all(b",")?;
+        }
+        Ok(())
+    }
+
+    /// Start an object with dimension flags and spatial reference.
+    fn object_begin(&mut self, flags: bool) -> Result<()> {
+        self.out.write_all(b"{")?;
+        if flags {
+            if self.dims.z {
+                self.out.write_all(br#""hasZ":true,"#)?;
+            }
+            if self.dims.m {
+                self.out.write_all(br#""hasM":true,"#)?;
+            }
+        }
+        if let Some(srid) = self.srid.take() {
+            write!(self.out, r#""spatialReference":{{"wkid":{srid}}},"#)?;
+        }
+        Ok(())
+    }
+
+    fn position(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        idx: usize,
+    ) -> Result<()> {
+        self.comma(idx)?;
+        write!(self.out, "[{x},{y}")?;
+        for value in [z, m].into_iter().flatten() {
+            write!(self.out, ",{value}")?;
+        }
+        self.out.write_all(b"]")?;
+        Ok(())
+    }
+
+    fn start_buffer(&mut self) {
+        self.buffer = Some(Vec::new());
+        self.buffer_zm.clear();
+    }
+
+    fn unsupported(&self, geometry_type: &str) -> Result<()> {
+        Err(GeozeroError::Geometry(format!(
+            "{geometry_type} not supported by EsriJSON writer"
+        )))
+    }
+}
+
+impl<W: Write> GeomProcessor for EsriJsonWriter<'_, W> {
+    fn dimensions(&self) -> CoordDimensions {
+        self.dims
+    }
+    fn srid(&mut self, srid: Option<i32>) -> Result<()> {
+        self.srid = srid;
+        Ok(())
+    }
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        self.coordinate(x, y, None, None, None, None, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        _t: Option<f64>,
+        _tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        match &mut self.buffer {
+            Some(ring) => {
+                ring.push([x, y]);
+                self.buffer_zm.push((z, m));
+                Ok(())
+            }
+            None => self.position(x, y, z, m, idx),
+        }
+    }
+    fn empty_point(&mut self, _idx: usize) -> Result<()> {
+        self.object_begin(false)?;
+        self.out.write_all(br#""x":null}"#)?;
+        Ok(())
+    }
+    fn point_begin(&mut self, _idx: usize) -> Result<()> {
+        self.object_begin(false)?;
+        // x/y/z/m members are written from the buffered position
+        self.start_buffer();
+        Ok(())
+    }
+    fn point_end(&mut self, _idx: usize) -> Result<()> {
+        if let Some([x, y]) = self.buffer.take().and_then(|mut xy| xy.pop()) {
+            write!(self.out, r#""x":{x},"y":{y}"#)?;
+            let (z, m) = self.buffer_zm.pop().unwrap_or_default();
+            if let Some(z) = z.filter(|_| self.dims.z) {
+                write!(self.out, r#","z":{z}"#)?;
+            }
+            if let Some(m) = m.filter(|_| self.dims.m) {
+                write!(self.out, r#","m":{m}"#)?;
+            }
+        }
+        self.out.write_all(b"}")?;
+        Ok(())
+    }
+    fn multipoint_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.object_begin(true)?;
+        self.out.write_all(br#""points":["#)?;
+        Ok(())
+    }
+    fn multipoint_end(&mut self, _idx: usize) -> Result<()> {
+        self.out.write_all(b"]}")?;
+        Ok(())
+    }
+    fn linestring_begin(&mut self, tagged: bool, _size: usize, idx: usize) -> Result<()> {
+        if tagged {
+            self.object_begin(true)?;
+            self.out.write_all(br#""paths":["#)?;
+        } else if self.buffer.is_none() && !self.in_multi {
+            // polygon ring
+            self.start_buffer();
+            return Ok(());
+        } else {
+            self.comma(idx)?;
+        }
+        self.out.write_all(b"[")?;
+        Ok(())
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        if let Some(mut ring) = self.buffer.take() {
+            let mut zm = std::mem::take(&mut self.buffer_zm);
+            // exterior rings clockwise, interior rings counter-clockwise
+            let area = ring_signed_area(&ring);
+            if (idx == 0 && area > 0.0) || (idx > 0 && area < 0.0) {
+                ring.reverse();
+                zm.reverse();
+            }
+            self.comma(self.ring_idx)?;
+            self.ring_idx += 1;
+            self.out.write_all(b"[")?;
+            for (i, ([x, y], (z, m))) in ring.into_iter().zip(zm).enumerate() {
+                self.position(x, y, z, m, i)?;
+            }
+            self.out.write_all(b"]")?;
+            return Ok(());
+        }
+        self.out.write_all(b"]")?;
+        if tagged {
+            self.out.write_all(b"]}")?;
+        }
+        Ok(())
+    }
+    fn multilinestring_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.object_begin(true)?;
+        self.out.write_all(br#""paths":["#)?;
+        self.in_multi = true;
+        Ok(())
+    }
+    fn multilinestring_end(&mut self, _idx: usize) -> Result<()> {
+        self.in_multi = false;
+        self.out.write_all(b"]}")?;
+        Ok(())
+    }
+    fn polygon_begin(&mut self, tagged: bool, _size: usize, _idx: usize) -> Result<()> {
+        if tagged {
+            self.object_begin(true)?;
+            self.out.write_all(br#""rings":["#)?;
+            self.ring_idx = 0;
+        }
+        Ok(())
+    }
+    fn polygon_end(&mut self, tagged: bool, _idx: usize) -> Result<()> {
+        if tagged {
+            self.out.write_all(b"]}")?;
+        }
+        Ok(())
+    }
+    fn multipolygon_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.object_begin(true)?;
+        self.out.write_all(br#""rings":["#)?;
+        self.ring_idx = 0;
+        Ok(())
+    }
+    fn multipolygon_end(&mut self, _idx: usize) -> Result<()> {
+        self.out.write_all(b"]}")?;
+        Ok(())
+    }
+    fn geometrycollection_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("GeometryCollection")
+    }
+    fn circularstring_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("CircularString")
+    }
+    fn compoundcurve_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("CompoundCurve")
+    }
+    fn curvepolygon_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("CurvePolygon")
+    }
+    fn multicurve_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("MultiCurve")
+    }
+    fn multisurface_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("MultiSurface")
+    }
+    fn triangle_begin(&mut self, _tagged: bool, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("Triangle")
+    }
+    fn polyhedralsurface_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("PolyhedralSurface")
+    }
+    fn tin_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("Tin")
+    }
+}
+
+impl<W: Write> PropertyProcessor for EsriJsonWriter<'_, W> {}
+
+impl<W: Write> FeatureProcessor for EsriJsonWriter<'_, W> {}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+    use crate::wkt::WktStr;
+    use crate::GeozeroGeometry;
+
+    fn to_esrijson(wkt: &str, srid: Option<i32>) -> String {
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = EsriJsonWriter::new(&mut out);
+        writer.srid(srid).unwrap();
+        WktStr(wkt).process_geom(&mut writer).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn point() {
+        assert_eq!(
+            to_esrijson("POINT(-118.15 33.8)", Some(4326)),
+            r#"{"spatialReference":{"wkid":4326},"x":-118.15,"y":33.8}"#
+        );
+
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = EsriJsonWriter::new(&mut out);
+        writer.dims = CoordDimensions::xyz();
+        writer.point_begin(0).unwrap();
+        writer
+            .coordinate(1.0, 2.0, Some(3.0), None, None, None, 0)
+            .unwrap();
+        writer.point_end(0).unwrap();
+        assert_eq!(std::str::from_utf8(&out).unwrap(), r#"{"x":1,"y":2,"z":3}"#);
+    }
+
+    #[test]
+    fn polyline() {
+        assert_eq!(
+            to_esrijson("LINESTRING(0 0,1 1,2 0)", None),
+            r#"{"paths":[[[0,0],[1,1],[2,0]]]}"#
+        );
+        assert_eq!(
+            to_esrijson("MULTILINESTRING((0 0,1 1),(2 2,3 3))", None),
+            r#"{"paths":[[[0,0],[1,1]],[[2,2],[3,3]]]}"#
+        );
+    }
+
+    #[test]
+    fn polygon_with_hole() {
+        // counter-clockwise exterior and clockwise hole are reversed
+        assert_eq!(
+            to_esrijson(
+                "POLYGON((0 0,10 0,10 10,0 10,0 0),(2 2,2 4,4 4,4 2,2 2))",
+                None
+            ),
+            r#"{"rings":[[[0,0],[0,10],[10,10],[10,0],[0,0]],[[2,2],[4,2],[4,4],[2,4],[2,2]]]}"#
+        );
+        assert_eq!(
+            to_esrijson(
+                "MULTIPOLYGON(((0 0,0 1,1 1,0 0)),((5 5,5 6,6 6,5 5)))",
+                None
+            ),
+            r#"{"rings":[[[0,0],[0,1],[1,1],[0,0]],[[5,5],[5,6],[6,6],[5,5]]]}"#
+        );
+    }
+}
@@ -174,4 +174,17 @@ mod test {
         let geo = Geometry::try_from(wkt::Wkt::from_str(wkt).unwrap()).unwrap();
         assert_eq!(geo.to_wkt().unwrap(), wkt);
     }
+
+    /// Drive a `WktWriter` from an in-memory geometry with [`process_geom`].
+    #[test]
+    fn geo_polygon_to_wkt() {
+        let exterior = LineString::from(vec![(0., 0.), (4., 0.), (4., 4.), (0., 0.)]);
+        let geo = Geometry::Polygon(Polygon::new(exterior, vec![]));
+        let mut wkt_data: Vec<u8> = Vec::new();
+        process_geom(&geo, &mut WktWriter::new(&mut wkt_data)).unwrap();
+        assert_eq!(
+            std::str::from_utf8(&wkt_data).unwrap(),
+            "POLYGON((0 0,4 0,4 4,0 0))"
+        );
+    }
 }
@@ -1,3 +1,4 @@
+use crate::algorithm::{srid_precision, CoordFormat};
 use crate::error::Result;
 use crate::{ColumnValue, CoordDimensions, FeatureProcessor, GeomProcessor, PropertyProcessor};
 use std::fmt::Display;
@@ -6,6 +7,18 @@ use std::io::Write;
 /// GeoJSON writer.
 pub struct GeoJsonWriter<'a, W: Write> {
     pub dims: CoordDimensions,
+    /// Write a `crs` member with the SRID of the geometry (deprecated by RFC 7946)
+    pub crs: bool,
+    /// Number of decimal places of coordinate values, `None` for full precision
+    ///
+    /// Rounding is the same as with [crate::wkt::WktWriter::precision].
+    pub precision: Option<usize>,
+    /// Use [srid_precision] of the geometry SRID if `precision` is not set
+    pub precision_from_srid: bool,
+    srid: Option<i32>,
+    srid_precision: Option<usize>,
+    /// Nesting level of geometry objects
+    depth: usize,
     out: &'a mut W,
 }
 
@@ -13,6 +26,12 @@ impl<'a, W: Write> GeoJsonWriter<'a, W> {
     pub fn new(out: &'a mut W) -> GeoJsonWriter<'a, W> {
         GeoJsonWriter {
             dims: CoordDimensions::default(),
+            crs: false,
+            precision: None,
+            precision_from_srid: false,
+            srid: None,
+            srid_precision: None,
+            depth: 0,
             out,
         }
     }
@@ -22,6 +41,33 @@ impl<'a, W: Write> GeoJsonWriter<'a, W> {
         }
         Ok(())
     }
+    fn number(&self, value: f64) -> CoordFormat {
+        CoordFormat {
+            value,
+            precision: self.precision.or(self.srid_precision),
+        }
+    }
+    fn object_begin(&mut self, geom_type: &str, member: &str) -> Result<()> {
+        write!(self.out, r#"{{"type": "{geom_type}", "#)?;
+        if let Some(srid) = self.srid.take() {
+            write!(
+                self.out,
+                r#""crs": {{"type": "name", "properties": {{"name": "EPSG:{srid}"}}}}, "#
+            )?;
+        }
+        write!(self.out, r#""{member}": "#)?;
+        self.depth += 1;
+        Ok(())
+    }
+    fn object_end(&mut self, closing: &[u8]) -> Result<()> {
+        self.out.write_all(closing)?;
+        self.depth = self.depth.saturating_sub(1);
+        if self.depth == 0 {
+            // the SRID of the next geometry is reported separately
+            self.srid_precision = None;
+        }
+        Ok(())
+    }
 }
 
 impl<W: Write> FeatureProcessor for GeoJsonWriter<'_, W> {
@@ -75,9 +121,18 @@ impl<W: Write> GeomProcessor for GeoJsonWriter<'_, W> {
     fn dimensions(&self) -> CoordDimensions {
         self.dims
     }
+    fn srid(&mut self, srid: Option<i32>) -> Result<()> {
+        if self.precision_from_srid {
+            self.srid_precision = srid.and_then(srid_precision);
+        }
+        if self.crs {
+            self.srid = srid;
+        }
+        Ok(())
+    }
     fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
         self.comma(idx)?;
-        self.out.write_all(format!("[{x},{y}]").as_bytes())?;
+        write!(self.out, "[{},{}]", self.number(x), self.number(y))?;
         Ok(())
     }
     fn coordinate(
@@ -91,98 +146,88 @@ impl<W: Write> GeomProcessor for GeoJsonWriter<'_, W> {
         idx: usize,
     ) -> Result<()> {
         self.comma(idx)?;
-        self.out.write_all(format!("[{x},{y}").as_bytes())?;
+        write!(self.out, "[{},{}", self.number(x), self.number(y))?;
         if let Some(z) = z {
-            self.out.write_all(format!(",{z}").as_bytes())?;
+            write!(self.out, ",{}", self.number(z))?;
         }
         self.out.write_all(b"]")?;
         Ok(())
     }
     fn point_begin(&mut self, idx: usize) -> Result<()> {
         self.comma(idx)?;
-        self.out
-            .write_all(br#"{"type": "Point", "coordinates": "#)?;
+        self.object_begin("Point", "coordinates")?;
         Ok(())
     }
     fn point_end(&mut self, _idx: usize) -> Result<()> {
-        self.out.write_all(b"}")?;
-        Ok(())
+        self.object_end(b"}")
     }
     fn multipoint_begin(&mut self, _size: usize, idx: usize) -> Result<()> {
         self.comma(idx)?;
-        self.out
-            .write_all(br#"{"type": "MultiPoint", "coordinates": ["#)?;
+        self.object_begin("MultiPoint", "coordinates")?;
+        self.out.write_all(b"[")?;
         Ok(())
     }
     fn multipoint_end(&mut self, _idx: usize) -> Result<()> {
-        self.out.write_all(b"]}")?;
-        Ok(())
+        self.object_end(b"]}")
     }
     fn linestring_begin(&mut self, tagged: bool, _size: usize, idx: usize) -> Result<()> {
         self.comma(idx)?;
         if tagged {
-            self.out
-                .write_all(br#"{"type": "LineString", "coordinates": ["#)?;
-        } else {
-            self.out.write_all(b"[")?;
+            self.object_begin("LineString", "coordinates")?;
         }
+        self.out.write_all(b"[")?;
         Ok(())
     }
     fn linestring_end(&mut self, tagged: bool, _idx: usize) -> Result<()> {
         if tagged {
-            self.out.write_all(b"]}")?;
+            self.object_end(b"]}")
         } else {
             self.out.write_all(b"]")?;
+            Ok(())
         }
-        Ok(())
     }
     fn multilinestring_begin(&mut self, _size: usize, idx: usize) -> Result<()> {
         self.comma(idx)?;
-        self.out
-            .write_all(br#"{"type": "MultiLineString", "coordinates": ["#)?;
+        self.object_begin("MultiLineString", "coordinates")?;
+        self.out.write_all(b"[")?;
         Ok(())
     }
     fn multilinestring_end(&mut self, _idx: usize) -> Result<()> {
-        self.out.write_all(b"]}")?;
-        Ok(())
+        self.object_end(b"]}")
     }
     fn polygon_begin(&mut self, tagged: bool, _size: usize, idx: usize) -> Result<()> {
         self.comma(idx)?;
         if tagged {
-            self.out
-                .write_all(br#"{"type": "Polygon", "coordinates": ["#)?;
-        } else {
-            self.out.write_all(b"[")?;
+            self.object_begin("Polygon", "coordinates")?;
         }
+        self.out.write_all(b"[")?;
         Ok(())
     }
     fn polygon_end(&mut self, tagged: bool, _idx: usize) -> Result<()> {
         if tagged {
-            self.out.write_all(b"]}")?;
+            self.object_end(b"]}")
         } else {
             self.out.write_all(b"]")?;
+            Ok(())
         }
-        Ok(())
     }
     fn multipolygon_begin(&mut self, _size: usize, idx: usize) -> Result<()> {
         self.comma(idx)?;
-        self.out
-            .write_all(br#"{"type": "MultiPolygon", "coordinates": ["#)?;
+        self.object_begin("MultiPolygon", "coordinates")?;
+        self.out.write_all(b"[")?;
         Ok(())
     }
     fn multipolygon_end(&mut self, _idx: usize) -> Result<()> {
-        self.out.write_all(b"]}")?;
-        Ok(())
+        self.object_end(b"]}")
     }
     fn geometrycollection_begin(&mut self, _size: usize, idx: usize) -> Result<()> {
         self.comma(idx)?;
-        self.out
-            .write_all(br#"{"type": "GeometryCollection", "geometries": ["#)?;
+        self.object_begin("GeometryCollection", "geometries")?;
+        self.out.write_all(b"[")?;
         Ok(())
     }
     fn geometrycollection_end(&mut self, _idx: usize) -> Result<()> {
-        self.out.write_all(b"]}")?;
-        Ok(())
+        self.object_end(b"]}")
     }
 }
 
@@ -232,6 +277,76 @@ mod test {
     use crate::geojson::read_geojson;
     use crate::ToJson;
 
+    #[test]
+    fn crs() -> Result<()> {
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = GeoJsonWriter::new(&mut out);
+        writer.crs = true;
+        writer.srid(Some(4326))?;
+        writer.point_begin(0)?;
+        writer.xy(10.0, 20.0, 0)?;
+        writer.point_end(0)?;
+        assert_json_eq(
+            &out,
+            r#"{"type": "Point", "crs": {"type": "name", "properties": {"name": "EPSG:4326"}}, "coordinates": [10,20]}"#,
+        );
+
+        // Off by default
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = GeoJsonWriter::new(&mut out);
+        writer.srid(Some(4326))?;
+        writer.point_begin(0)?;
+        writer.xy(10.0, 20.0, 0)?;
+        writer.point_end(0)?;
+        assert_json_eq(&out, r#"{"type": "Point", "coordinates": [10,20]}"#);
+        Ok(())
+    }
+
+    #[test]
+    fn precision() -> Result<()> {
+        let geojson = r#"{"type": "Polygon", "coordinates": [[[8.54321987,47.36965432],[8.5500049,47.36965432],[8.5500049,47.3751],[8.54321987,47.36965432]]]}"#;
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = GeoJsonWriter::new(&mut out);
+        writer.precision = Some(4);
+        read_geojson(geojson.as_bytes(), &mut writer)?;
+        assert_json_eq(
+            &out,
+            r#"{"type": "Polygon", "coordinates": [[[8.5432,47.3697],[8.55,47.3697],[8.55,47.3751],[8.5432,47.3697]]]}"#,
+        );
+        assert!(std::str::from_utf8(&out)
+            .unwrap()
+            .contains("[8.5432,47.3697],[8.55,47.3697]"));
+        Ok(())
+    }
+
+    #[test]
+    fn precision_from_srid() -> Result<()> {
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = GeoJsonWriter::new(&mut out);
+        writer.precision_from_srid = true;
+        writer.srid(Some(4326))?;
+        writer.point_begin(0)?;
+        writer.xy(8.123456789, 47.123456789, 0)?;
+        writer.point_end(0)?;
+        writer.srid(Some(3857))?;
+        writer.point_begin(0)?;
+        writer.xy(904297.123456, 5967009.123456, 0)?;
+        writer.point_end(0)?;
+        // without SRID
+        writer.point_begin(0)?;
+        writer.xy(8.123456789, 47.123456789, 0)?;
+        writer.point_end(0)?;
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            concat!(
+                r#"{"type": "Point", "coordinates": [8.1234568,47.1234568]}"#,
+                r#"{"type": "Point", "coordinates": [904297.12,5967009.12]}"#,
+                r#"{"type": "Point", "coordinates": [8.123456789,47.123456789]}"#
+            )
+        );
+        Ok(())
+    }
+
     #[test]
     fn geometries() -> Result<()> {
         // countries.fgb, id = ZAF
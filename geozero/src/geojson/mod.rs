@@ -4,6 +4,8 @@ pub(crate) mod geojson_writer;
 
 pub use geojson_reader::*;
 pub use geojson_writer::*;
+#[cfg(feature = "with-wkb")]
+pub use wkb::{ewkb_to_geojson, ewkb_to_geojson_value};
 
 pub(crate) mod conversion {
     use crate::error::Result;
@@ -56,11 +58,31 @@ impl From<geojson::Error> for crate::error::GeozeroError {
 
 #[cfg(feature = "with-wkb")]
 mod wkb {
-    use crate::error::Result;
+    use crate::error::{GeozeroError, Result};
     use crate::geojson::{GeoJsonString, GeoJsonWriter};
+    use crate::processors::WindingProcessor;
     use crate::wkb::{FromWkb, WkbDialect};
     use std::io::Read;
 
+    /// Convert EWKB into a GeoJSON geometry.
+    ///
+    /// Polygon rings are oriented according to the right-hand rule of RFC 7946.
+    pub fn ewkb_to_geojson(mut data: &[u8]) -> Result<String> {
+        let mut out: Vec<u8> = Vec::new();
+        let mut processor = WindingProcessor::new(GeoJsonWriter::new(&mut out));
+        crate::wkb::process_ewkb_geom(&mut data, &mut processor)?;
+        String::from_utf8(out)
+            .map_err(|_| GeozeroError::Geometry("Invalid UTF-8 encoding".to_string()))
+    }
+
+    /// Convert EWKB into a GeoJSON geometry value.
+    ///
+    /// Polygon rings are oriented according to the right-hand rule of RFC 7946.
+    pub fn ewkb_to_geojson_value(data: &[u8]) -> Result<serde_json::Value> {
+        let json = ewkb_to_geojson(data)?;
+        serde_json::from_str(&json).map_err(|e| GeozeroError::Geometry(e.to_string()))
+    }
+
     impl FromWkb for GeoJsonString {
         fn from_wkb<R: Read>(rdr: &mut R, dialect: WkbDialect) -> Result<Self> {
             let mut out: Vec<u8> = Vec::new();
@@ -81,4 +103,33 @@ mod wkb {
     // crate::impl_sqlx_gpkg_type_info!(GeoJsonString);
     // crate::impl_sqlx_gpkg_decode!(GeoJsonString);
     // crate::impl_sqlx_gpkg_encode!(GeoJsonString);
+
+    #[cfg(test)]
+    mod test {
+        use super::*;
+        use serde_json::json;
+
+        #[test]
+        fn geojson_value() {
+            // SELECT 'SRID=4326;POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))'::geometry
+            let ewkb = hex::decode("0103000020E610000001000000050000000000000000000000000000000000000000000000000000400000000000000000000000000000004000000000000000400000000000000000000000000000004000000000000000000000000000000000").unwrap();
+            assert_eq!(
+                ewkb_to_geojson_value(&ewkb).unwrap(),
+                json!({
+                    "type": "Polygon",
+                    "coordinates": [[[0, 0], [2, 0], [2, 2], [0, 2], [0, 0]]]
+                })
+            );
+        }
+
+        #[test]
+        fn right_hand_rule() {
+            // SELECT 'POLYGON ((0 0, 0 2, 2 2, 2 0, 0 0))'::geometry
+            let ewkb = hex::decode("010300000001000000050000000000000000000000000000000000000000000000000000000000000000000040000000000000004000000000000000400000000000000040000000000000000000000000000000000000000000000000").unwrap();
+            assert_eq!(
+                ewkb_to_geojson(&ewkb).unwrap(),
+                r#"{"type": "Polygon", "coordinates": [[[0,0],[2,0],[2,2],[0,2],[0,0]]]}"#
+            );
+        }
+    }
 }
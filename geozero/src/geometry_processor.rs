@@ -1,7 +1,7 @@
 use crate::error::{GeozeroError, Result};
 
 /// Dimensions requested for processing
-#[derive(Default, Clone, Copy)]
+#[derive(Default, Clone, Copy, Debug)]
 pub struct CoordDimensions {
     /// height
     pub z: bool,
@@ -48,6 +48,26 @@ impl CoordDimensions {
     }
 }
 
+/// Axis order of coordinates
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+pub enum AxisOrder {
+    /// x = longitude / easting, y = latitude / northing
+    #[default]
+    LonLat,
+    /// x = latitude / northing, y = longitude / easting
+    LatLon,
+}
+
+impl AxisOrder {
+    /// Order a lon/lat coordinate pair according to the axis order
+    pub fn order(self, x: f64, y: f64) -> (f64, f64) {
+        match self {
+            AxisOrder::LonLat => (x, y),
+            AxisOrder::LatLon => (y, x),
+        }
+    }
+}
+
 /// Geometry processing trait
 ///
 /// # Usage example:
@@ -89,6 +109,17 @@ pub trait GeomProcessor {
     }
 
     /// Process coordinate with all requested dimensions
+    ///
+    /// Called instead of [xy](Self::xy) if [multi_dim](Self::multi_dim) is true.
+    /// Ordinates not present in the input are `None`:
+    /// * `z`: height
+    /// * `m`: measurement
+    /// * `t`: geodetic decimal year time, e.g. `2023.5`
+    /// * `tm`: time nanosecond measurement
+    ///
+    /// The readers of this crate never pass T and TM ordinates, since WKB, WKT, GeoJSON
+    /// and the other supported formats can't store them. They are passed by readers
+    /// of formats with time ordinates like FlatGeobuf.
     fn coordinate(
         &mut self,
         x: f64,
@@ -326,3 +357,48 @@ fn error_message() {
         "processing geometry `test`".to_string()
     );
 }
+
+#[test]
+fn time_ordinates() {
+    use crate::GeozeroGeometry;
+
+    /// Reader of a format with time ordinates
+    struct TimedPoint;
+    impl GeozeroGeometry for TimedPoint {
+        fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> Result<()> {
+            processor.point_begin(0)?;
+            processor.coordinate(1.0, 2.0, None, None, Some(2023.5), Some(42), 0)?;
+            processor.point_end(0)
+        }
+    }
+
+    #[derive(Default)]
+    struct TimeCollector(Vec<(Option<f64>, Option<u64>)>);
+    impl GeomProcessor for TimeCollector {
+        fn dimensions(&self) -> CoordDimensions {
+            CoordDimensions {
+                t: true,
+                tm: true,
+                ..Default::default()
+            }
+        }
+        fn coordinate(
+            &mut self,
+            _x: f64,
+            _y: f64,
+            _z: Option<f64>,
+            _m: Option<f64>,
+            t: Option<f64>,
+            tm: Option<u64>,
+            _idx: usize,
+        ) -> Result<()> {
+            self.0.push((t, tm));
+            Ok(())
+        }
+    }
+
+    let mut processor =
+        crate::processors::ZTransformProcessor::new(TimeCollector::default(), |_, _, z| z);
+    TimedPoint.process_geom(&mut processor).unwrap();
+    assert_eq!(processor.into_inner().0, vec![(Some(2023.5), Some(42))]);
+}
@@ -0,0 +1,3 @@
+//! GML conversions.
+mod writer;
+pub use writer::GmlWriter;
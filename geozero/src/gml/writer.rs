@@ -0,0 +1,431 @@
+use crate::error::{GeozeroError, Result};
+use crate::{CoordDimensions, FeatureProcessor, GeomProcessor, PropertyProcessor};
+use std::io::Write;
+
+/// GML 3.2 geometry writer.
+///
+/// MultiLineStrings are written as `gml:MultiCurve`, MultiPolygons as `gml:MultiSurface`
+/// and GeometryCollections as `gml:MultiGeometry`. Curves, surfaces and TINs are not supported.
+/// A geometry is written at its end, when the `srsDimension` of its positions is known.
+pub struct GmlWriter<'a, W: Write> {
+    pub dims: CoordDimensions,
+    /// `srsName` of the outermost element, defaults to `urn:ogc:def:crs:EPSG::{srid}`
+    pub srs_name: Option<String>,
+    /// Prefix of the `gml:id` attributes, which are numbered `{id_prefix}.{n}` in written order
+    pub id_prefix: String,
+    srid: Option<i32>,
+    /// Open geometry elements
+    stack: Vec<Element>,
+    /// Number of written elements
+    id_count: usize,
+    root_id: usize,
+    /// Dimension of the written positions
+    pos_dim: Option<usize>,
+    /// Content of the outermost element
+    body: Vec<u8>,
+    out: &'a mut W,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Element {
+    Point,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiCurve,
+    MultiSurface,
+    MultiGeometry,
+}
+
+impl Element {
+    fn tag(self) -> &'static str {
+        match self {
+            Element::Point => "Point",
+            Element::LineString => "LineString",
+            Element::Polygon => "Polygon",
+            Element::MultiPoint => "MultiPoint",
+            Element::MultiCurve => "MultiCurve",
+            Element::MultiSurface => "MultiSurface",
+            Element::MultiGeometry => "MultiGeometry",
+        }
+    }
+
+    fn member_tag(self) -> Option<&'static str> {
+        match self {
+            Element::MultiPoint => Some("pointMember"),
+            Element::MultiCurve => Some("curveMember"),
+            Element::MultiSurface => Some("surfaceMember"),
+            Element::MultiGeometry => Some("geometryMember"),
+            _ => None,
+        }
+    }
+}
+
+impl<'a, W: Write> GmlWriter<'a, W> {
+    pub fn new(out: &'a mut W) -> GmlWriter<'a, W> {
+        GmlWriter {
+            dims: CoordDimensions::default(),
+            srs_name: None,
+            id_prefix: "geom".to_string(),
+            srid: None,
+            stack: Vec::new(),
+            id_count: 0,
+            root_id: 0,
+            pos_dim: None,
+            body: Vec::new(),
+            out,
+        }
+    }
+
+    fn begin(&mut self, element: Element) -> Result<()> {
+        let id = self.id_count;
+        self.id_count += 1;
+        if self.stack.is_empty() {
+            // start tag is written at the end
+            self.root_id = id;
+        } else {
+            if let Some(member) = self.stack.last().and_then(|parent| parent.member_tag()) {
+                write!(self.body, "<gml:{member}>")?;
+            }
+            write!(
+                self.body,
+                r#"<gml:{} gml:id="{}.{id}">"#,
+                element.tag(),
+                escape_attr(&self.id_prefix)
+            )?;
+        }
+        self.stack.push(element);
+        Ok(())
+    }
+
+    fn end(&mut self) -> Result<()> {
+        let element = self.stack.pop().ok_or(GeozeroError::GeometryFormat)?;
+        if self.stack.is_empty() {
+            return self.write_geometry(element);
+        }
+        write!(self.body, "</gml:{}>", element.tag())?;
+        if let Some(member) = self.stack.last().and_then(|parent| parent.member_tag()) {
+            write!(self.body, "</gml:{member}>")?;
+        }
+        Ok(())
+    }
+
+    /// Write the outermost element with the buffered content and reset the geometry state.
+    fn write_geometry(&mut self, element: Element) -> Result<()> {
+        let tag = element.tag();
+        let srs_name = self.srs_name.clone().or_else(|| {
+            self.srid
+                .map(|srid| format!("urn:ogc:def:crs:EPSG::{srid}"))
+        });
+        let pos_dim = self.pos_dim;
+        let body = std::mem::take(&mut self.body);
+        self.reset();
+        write!(
+            self.out,
+            r#"<gml:{tag} gml:id="{}.{}""#,
+            escape_attr(&self.id_prefix),
+            self.root_id
+        )?;
+        if let Some(srs_name) = srs_name {
+            write!(self.out, r#" srsName="{}""#, escape_attr(&srs_name))?;
+        }
+        if let Some(dimension) = pos_dim {
+            write!(self.out, r#" srsDimension="{dimension}""#)?;
+        }
+        write!(self.out, ">")?;
+        self.out.write_all(&body)?;
+        write!(self.out, "</gml:{tag}>")?;
+        Ok(())
+    }
+
+    /// Discard the state of the current geometry, e.g. after an error.
+    fn reset(&mut self) {
+        self.stack.clear();
+        self.body.clear();
+        self.srid = None;
+        self.pos_dim = None;
+    }
+
+    fn unsupported(&mut self, geometry_type: &str) -> Result<()> {
+        self.reset();
+        Err(GeozeroError::Geometry(format!(
+            "{geometry_type} not supported by GML writer"
+        )))
+    }
+
+    fn position(&mut self, x: f64, y: f64, z: Option<f64>, idx: usize) -> Result<()> {
+        let z = z.filter(|_| self.dims.z);
+        let dim = if z.is_some() { 3 } else { 2 };
+        if *self.pos_dim.get_or_insert(dim) != dim {
+            self.reset();
+            return Err(GeozeroError::Geometry(
+                "GML positions with and without Z can't be mixed".to_string(),
+            ));
+        }
+        let in_multipoint = self.stack.last() == Some(&Element::MultiPoint);
+        if in_multipoint {
+            self.begin(Element::Point)?;
+            write!(self.body, "<gml:pos>")?;
+        } else if idx > 0 {
+            write!(self.body, " ")?;
+        }
+        write!(self.body, "{x} {y}")?;
+        if let Some(z) = z {
+            write!(self.body, " {z}")?;
+        }
+        if in_multipoint {
+            write!(self.body, "</gml:pos>")?;
+            self.end()?;
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write> GeomProcessor for GmlWriter<'_, W> {
+    fn dimensions(&self) -> CoordDimensions {
+        self.dims
+    }
+    fn srid(&mut self, srid: Option<i32>) -> Result<()> {
+        self.srid = srid;
+        Ok(())
+    }
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        self.position(x, y, None, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        _m: Option<f64>,
+        _t: Option<f64>,
+        _tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        self.position(x, y, z, idx)
+    }
+    fn point_begin(&mut self, _idx: usize) -> Result<()> {
+        self.begin(Element::Point)?;
+        write!(self.body, "<gml:pos>")?;
+        Ok(())
+    }
+    fn point_end(&mut self, _idx: usize) -> Result<()> {
+        write!(self.body, "</gml:pos>")?;
+        self.end()
+    }
+    fn multipoint_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.begin(Element::MultiPoint)
+    }
+    fn multipoint_end(&mut self, _idx: usize) -> Result<()> {
+        self.end()
+    }
+    fn linestring_begin(&mut self, _tagged: bool, _size: usize, idx: usize) -> Result<()> {
+        if self.stack.last() == Some(&Element::Polygon) {
+            let boundary = if idx == 0 { "exterior" } else { "interior" };
+            write!(self.body, "<gml:{boundary}><gml:LinearRing><gml:posList>")?;
+            Ok(())
+        } else {
+            self.begin(Element::LineString)?;
+            write!(self.body, "<gml:posList>")?;
+            Ok(())
+        }
+    }
+    fn linestring_end(&mut self, _tagged: bool, idx: usize) -> Result<()> {
+        if self.stack.last() == Some(&Element::Polygon) {
+            let boundary = if idx == 0 { "exterior" } else { "interior" };
+            write!(
+                self.body,
+                "</gml:posList></gml:LinearRing></gml:{boundary}>"
+            )?;
+            Ok(())
+        } else {
+            write!(self.body, "</gml:posList>")?;
+            self.end()
+        }
+    }
+    fn multilinestring_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.begin(Element::MultiCurve)
+    }
+    fn multilinestring_end(&mut self, _idx: usize) -> Result<()> {
+        self.end()
+    }
+    fn polygon_begin(&mut self, _tagged: bool, _size: usize, _idx: usize) -> Result<()> {
+        self.begin(Element::Polygon)
+    }
+    fn polygon_end(&mut self, _tagged: bool, _idx: usize) -> Result<()> {
+        self.end()
+    }
+    fn multipolygon_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.begin(Element::MultiSurface)
+    }
+    fn multipolygon_end(&mut self, _idx: usize) -> Result<()> {
+        self.end()
+    }
+    fn geometrycollection_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.begin(Element::MultiGeometry)
+    }
+    fn geometrycollection_end(&mut self, _idx: usize) -> Result<()> {
+        self.end()
+    }
+    fn circularstring_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("CircularString")
+    }
+    fn compoundcurve_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("CompoundCurve")
+    }
+    fn curvepolygon_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("CurvePolygon")
+    }
+    fn multicurve_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("MultiCurve")
+    }
+    fn multisurface_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("MultiSurface")
+    }
+    fn triangle_begin(&mut self, _tagged: bool, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("Triangle")
+    }
+    fn polyhedralsurface_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("PolyhedralSurface")
+    }
+    fn tin_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("Tin")
+    }
+}
+
+/// Escape a value for a double-quoted XML attribute.
+fn escape_attr(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+impl<W: Write> PropertyProcessor for GmlWriter<'_, W> {}
+
+impl<W: Write> FeatureProcessor for GmlWriter<'_, W> {}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+    use crate::wkt::WktStr;
+    use crate::GeozeroGeometry;
+
+    fn to_gml(wkt: &str, srid: Option<i32>) -> String {
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = GmlWriter::new(&mut out);
+        writer.srid(srid).unwrap();
+        WktStr(wkt).process_geom(&mut writer).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn point() {
+        assert_eq!(
+            to_gml("POINT(1 2)", Some(4326)),
+            r#"<gml:Point gml:id="geom.0" srsName="urn:ogc:def:crs:EPSG::4326" srsDimension="2"><gml:pos>1 2</gml:pos></gml:Point>"#
+        );
+    }
+
+    #[test]
+    fn polygon_with_hole() {
+        assert_eq!(
+            to_gml("POLYGON((0 0,10 0,10 10,0 0),(2 1,8 1,8 7,2 1))", None),
+            concat!(
+                r#"<gml:Polygon gml:id="geom.0" srsDimension="2">"#,
+                "<gml:exterior><gml:LinearRing><gml:posList>0 0 10 0 10 10 0 0</gml:posList></gml:LinearRing></gml:exterior>",
+                "<gml:interior><gml:LinearRing><gml:posList>2 1 8 1 8 7 2 1</gml:posList></gml:LinearRing></gml:interior>",
+                "</gml:Polygon>"
+            )
+        );
+    }
+
+    #[test]
+    fn multi_geometries() {
+        assert_eq!(
+            to_gml("MULTIPOINT(1 2,3 4)", None),
+            concat!(
+                r#"<gml:MultiPoint gml:id="geom.0" srsDimension="2">"#,
+                r#"<gml:pointMember><gml:Point gml:id="geom.1"><gml:pos>1 2</gml:pos></gml:Point></gml:pointMember>"#,
+                r#"<gml:pointMember><gml:Point gml:id="geom.2"><gml:pos>3 4</gml:pos></gml:Point></gml:pointMember>"#,
+                "</gml:MultiPoint>"
+            )
+        );
+        assert_eq!(
+            to_gml("MULTILINESTRING((0 0,1 1))", None),
+            concat!(
+                r#"<gml:MultiCurve gml:id="geom.0" srsDimension="2">"#,
+                r#"<gml:curveMember><gml:LineString gml:id="geom.1"><gml:posList>0 0 1 1</gml:posList></gml:LineString></gml:curveMember>"#,
+                "</gml:MultiCurve>"
+            )
+        );
+    }
+
+    #[test]
+    fn written_dimensions() {
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = GmlWriter::new(&mut out);
+        writer.dims.z = true;
+        writer.id_prefix = "f1".to_string();
+        writer.srid(Some(4326)).unwrap();
+        writer.point_begin(0).unwrap();
+        writer
+            .coordinate(1.0, 2.0, Some(3.0), None, None, None, 0)
+            .unwrap();
+        writer.point_end(0).unwrap();
+        // without SRID and Z values
+        WktStr("LINESTRING(0 0,1 1)")
+            .process_geom(&mut writer)
+            .unwrap();
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            concat!(
+                r#"<gml:Point gml:id="f1.0" srsName="urn:ogc:def:crs:EPSG::4326" srsDimension="3"><gml:pos>1 2 3</gml:pos></gml:Point>"#,
+                r#"<gml:LineString gml:id="f1.1" srsDimension="2"><gml:posList>0 0 1 1</gml:posList></gml:LineString>"#
+            )
+        );
+
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = GmlWriter::new(&mut out);
+        writer.dims.z = true;
+        writer.linestring_begin(true, 2, 0).unwrap();
+        writer
+            .coordinate(0.0, 0.0, Some(1.0), None, None, None, 0)
+            .unwrap();
+        assert!(writer
+            .coordinate(1.0, 1.0, None, None, None, None, 1)
+            .is_err());
+        // the failed geometry is discarded
+        WktStr("POINT(1 2)").process_geom(&mut writer).unwrap();
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            r#"<gml:Point gml:id="geom.1" srsDimension="2"><gml:pos>1 2</gml:pos></gml:Point>"#
+        );
+    }
+
+    #[test]
+    fn escaped_attributes() {
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = GmlWriter::new(&mut out);
+        writer.srs_name = Some(r#"urn:"a"&<b>"#.to_string());
+        writer.id_prefix = "f<1>".to_string();
+        WktStr("MULTIPOINT(1 2)").process_geom(&mut writer).unwrap();
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            concat!(
+                r#"<gml:MultiPoint gml:id="f&lt;1&gt;.0" srsName="urn:&quot;a&quot;&amp;&lt;b&gt;" srsDimension="2">"#,
+                r#"<gml:pointMember><gml:Point gml:id="f&lt;1&gt;.1"><gml:pos>1 2</gml:pos></gml:Point></gml:pointMember>"#,
+                "</gml:MultiPoint>"
+            )
+        );
+    }
+}
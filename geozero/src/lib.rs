@@ -17,19 +17,22 @@
 //!
 //! ## Format conversion overview
 //!
-//! |           |                         [`GeozeroGeometry`]                          | Dimensions |                        [`GeozeroDatasource`]                         | Geometry Conversion |            [`GeomProcessor`]            |
-//! |-----------|----------------------------------------------------------------------|------------|----------------------------------------------------------------------|---------------------|-----------------------------------------|
-//! | CSV       | [csv::Csv], [csv::CsvString]                                         | XY         | -                                                                    | [ProcessToCsv]      | [CsvWriter](csv::CsvWriter)             |
-//! | geo-types | `geo_types::Geometry<f64>`                                           | XY         | -                                                                    | [ToGeo]             | [GeoWriter](geo_types::GeoWriter)       |
-//! | GeoArrow  | `arrow2::array::BinaryArray`                                         | XY         | -                                                                    | -                   | -                                       |
-//! | GeoJSON   | [GeoJson](geojson::GeoJson), [GeoJsonString](geojson::GeoJsonString) | XYZ        | [GeoJsonReader](geojson::GeoJsonReader), [GeoJson](geojson::GeoJson) | [ToJson]            | [GeoJsonWriter](geojson::GeoJsonWriter) |
-//! | GDAL      | `gdal::vector::Geometry`                                             | XYZ        | -                                                                    | [ToGdal]            | [GdalWriter](gdal::GdalWriter)          |
-//! | GEOS      | `geos::Geometry`                                                     | XYZ        | -                                                                    | [ToGeos]            | [GeosWriter](geos::GeosWriter)          |
-//! | GPX       |                                                                      | XY         | [GpxReader](gpx::GpxReader)                                          |                     |                                         |
-//! | MVT       | [mvt::tile::Feature]                                                 | XY         | [mvt::tile::Layer]                                                   | [ToMvt]             | [MvtWriter](mvt::MvtWriter)             |
-//! | SVG       | -                                                                    | XY         | -                                                                    | [ToSvg]             | [SvgWriter](svg::SvgWriter)             |
-//! | WKB       | [Wkb](wkb::Wkb), [Ewkb](wkb::Ewkb), [GpkgWkb](wkb::GpkgWkb)          | XYZM       | -                                                                    | [ToWkb]             | [WkbWriter](wkb::WkbWriter)             |
-//! | WKT       | [wkt::WktStr], [wkt::WktString]                                      | XYZM       | [wkt::WktReader], [wkt::WktStr], [wkt::WktString]                    | [ToWkt]             | [WktWriter](wkt::WktWriter)             |
+//! |           |                         [`GeozeroGeometry`]                          | Dimensions |                        [`GeozeroDatasource`]                         | Geometry Conversion |            [`GeomProcessor`]             |
+//! |-----------|----------------------------------------------------------------------|------------|----------------------------------------------------------------------|---------------------|------------------------------------------|
+//! | CSV       | [csv::Csv], [csv::CsvString]                                         | XY         | -                                                                    | [ProcessToCsv]      | [CsvWriter](csv::CsvWriter)              |
+//! | geo-types | `geo_types::Geometry<f64>`                                           | XY         | -                                                                    | [ToGeo]             | [GeoWriter](geo_types::GeoWriter)        |
+//! | GeoArrow  | `arrow2::array::BinaryArray`                                         | XY         | -                                                                    | -                   | -                                        |
+//! | GeoJSON   | [GeoJson](geojson::GeoJson), [GeoJsonString](geojson::GeoJsonString) | XYZ        | [GeoJsonReader](geojson::GeoJsonReader), [GeoJson](geojson::GeoJson) | [ToJson]            | [GeoJsonWriter](geojson::GeoJsonWriter)  |
+//! | EsriJSON  | -                                                                    | XYZM       | -                                                                    | -                   | [EsriJsonWriter](esrijson::EsriJsonWriter) |
+//! | GDAL      | `gdal::vector::Geometry`                                             | XYZ        | -                                                                    | [ToGdal]            | [GdalWriter](gdal::GdalWriter)           |
+//! | GEOS      | `geos::Geometry`                                                     | XYZ        | -                                                                    | [ToGeos]            | [GeosWriter](geos::GeosWriter)           |
+//! | GML       | -                                                                    | XYZ        | -                                                                    | -                   | [GmlWriter](gml::GmlWriter)              |
+//! | GPX       |                                                                      | XY         | [GpxReader](gpx::GpxReader)                                          |                     |                                          |
+//! | MVT       | [mvt::tile::Feature]                                                 | XY         | [mvt::tile::Layer]                                                   | [ToMvt]             | [MvtWriter](mvt::MvtWriter)              |
+//! | OBJ       | -                                                                    | XYZ        | -                                                                    | -                   | [ObjWriter](obj::ObjWriter)              |
+//! | SVG       | -                                                                    | XY         | -                                                                    | [ToSvg]             | [SvgWriter](svg::SvgWriter)              |
+//! | WKB       | [Wkb](wkb::Wkb), [Ewkb](wkb::Ewkb), [GpkgWkb](wkb::GpkgWkb)          | XYZM       | -                                                                    | [ToWkb]             | [WkbWriter](wkb::WkbWriter)              |
+//! | WKT       | [wkt::WktStr], [wkt::WktString]                                      | XYZM       | [wkt::WktReader], [wkt::WktStr], [wkt::WktString]                    | [ToWkt]             | [WktWriter](wkt::WktWriter)              |
 
 #![allow(
     clippy::many_single_char_names,
@@ -46,6 +49,7 @@
     clippy::module_name_repetitions
 )]
 
+pub mod algorithm;
 mod api;
 pub mod error;
 mod feature_processor;
@@ -59,6 +63,8 @@ pub use geometry_processor::*;
 pub use multiplex::*;
 pub use property_processor::*;
 
+pub mod processors;
+
 #[cfg(feature = "with-arrow")]
 pub mod arrow;
 
@@ -67,6 +73,9 @@ pub mod csv;
 #[cfg(feature = "with-csv")]
 pub use crate::csv::conversion::*;
 
+#[cfg(feature = "with-esrijson")]
+pub mod esrijson;
+
 #[cfg(feature = "with-gdal")]
 pub mod gdal;
 #[cfg(feature = "with-gdal")]
@@ -87,12 +96,18 @@ pub mod geos;
 #[cfg(feature = "with-geos")]
 pub use crate::geos::conversion::*;
 
+#[cfg(feature = "with-gml")]
+pub mod gml;
+
 #[cfg(feature = "with-gpkg")]
 pub mod gpkg;
 
 #[cfg(feature = "with-gpx")]
 pub mod gpx;
 
+#[cfg(feature = "with-obj")]
+pub mod obj;
+
 #[cfg(any(
     feature = "with-postgis-postgres",
     feature = "with-postgis-sqlx",
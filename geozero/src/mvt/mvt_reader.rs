@@ -1,3 +1,4 @@
+use crate::algorithm::ring_signed_area;
 use crate::error::{GeozeroError, Result};
 use crate::mvt::vector_tile::{tile, tile::GeomType};
 use crate::{ColumnValue, FeatureProcessor, GeomProcessor, GeozeroDatasource, GeozeroGeometry};
@@ -276,24 +277,17 @@ fn process_polygons<P: GeomProcessor>(
     }
 }
 
-// using surveyor's formula
 fn is_area_positive(mut cursor: [i32; 2], first: &[u32], rest: &[u32]) -> bool {
-    let nb = 1 + rest.len() / 2;
-    let mut area = 0;
-    let mut coords = first
-        .iter()
-        .chain(rest)
-        .chain(first.iter())
-        .map(|&x| ParameterInteger(x).value());
-    cursor[0] += coords.next().unwrap();
-    cursor[1] += coords.next().unwrap();
-    for _i in 0..nb {
-        let [x0, y0] = cursor;
-        cursor[0] += coords.next().unwrap();
-        cursor[1] += coords.next().unwrap();
-        area += x0 * cursor[1] - cursor[0] * y0;
-    }
-    area > 0
+    let ring: Vec<[f64; 2]> = first
+        .chunks(2)
+        .chain(rest.chunks(2))
+        .map(|delta| {
+            cursor[0] += ParameterInteger(delta[0]).value();
+            cursor[1] += ParameterInteger(delta[1]).value();
+            [cursor[0] as f64, cursor[1] as f64]
+        })
+        .collect();
+    ring_signed_area(&ring) > 0.0
 }
 
 #[cfg(test)]
@@ -0,0 +1,3 @@
+//! Wavefront OBJ conversions.
+mod writer;
+pub use writer::ObjWriter;
@@ -0,0 +1,166 @@
+use crate::error::{GeozeroError, Result};
+use crate::{CoordDimensions, GeomProcessor};
+use std::collections::HashMap;
+use std::io::Write;
+
+/// Wavefront OBJ mesh writer for TINs, PolyhedralSurfaces and other polygon surfaces.
+///
+/// Each polygon or triangle is written as face (`f`) referencing its vertices (`v`).
+/// Vertices are deduplicated over all processed geometries and written before their
+/// first use. Coordinates require a Z value, polygons with interior rings, points,
+/// lines and curves are not supported.
+pub struct ObjWriter<'a, W: Write> {
+    /// 1-based vertex indices by coordinate
+    vertices: HashMap<[u64; 3], usize>,
+    /// Vertex indices of the current face
+    face: Option<Vec<usize>>,
+    in_surface: bool,
+    out: &'a mut W,
+}
+
+impl<'a, W: Write> ObjWriter<'a, W> {
+    pub fn new(out: &'a mut W) -> ObjWriter<'a, W> {
+        ObjWriter {
+            vertices: HashMap::new(),
+            face: None,
+            in_surface: false,
+            out,
+        }
+    }
+
+    fn vertex(&mut self, x: f64, y: f64, z: f64) -> Result<usize> {
+        // adding 0.0 maps -0.0 to 0.0
+        let key = [
+            (x + 0.0).to_bits(),
+            (y + 0.0).to_bits(),
+            (z + 0.0).to_bits(),
+        ];
+        if let Some(idx) = self.vertices.get(&key) {
+            return Ok(*idx);
+        }
+        let idx = self.vertices.len() + 1;
+        self.vertices.insert(key, idx);
+        writeln!(self.out, "v {x} {y} {z}")?;
+        Ok(idx)
+    }
+
+    fn unsupported(&self, what: &str) -> Result<()> {
+        Err(GeozeroError::Geometry(format!(
+            "{what} not supported by OBJ writer"
+        )))
+    }
+}
+
+impl<W: Write> GeomProcessor for ObjWriter<'_, W> {
+    fn dimensions(&self) -> CoordDimensions {
+        CoordDimensions::xyz()
+    }
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        self.coordinate(x, y, None, None, None, None, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        _m: Option<f64>,
+        _t: Option<f64>,
+        _tm: Option<u64>,
+        _idx: usize,
+    ) -> Result<()> {
+        if self.face.is_none() {
+            return self.unsupported("Coordinates outside of polygon surfaces");
+        }
+        let Some(z) = z else {
+            return self.unsupported("Coordinates without Z");
+        };
+        let vertex = self.vertex(x, y, z)?;
+        if let Some(face) = &mut self.face {
+            face.push(vertex);
+        }
+        Ok(())
+    }
+    fn empty_point(&mut self, _idx: usize) -> Result<()> {
+        self.unsupported("Point")
+    }
+    fn linestring_begin(&mut self, tagged: bool, _size: usize, idx: usize) -> Result<()> {
+        if tagged || !self.in_surface {
+            return self.unsupported("LineString");
+        }
+        if idx > 0 {
+            return self.unsupported("Polygon with interior rings");
+        }
+        self.face = Some(Vec::new());
+        Ok(())
+    }
+    fn linestring_end(&mut self, _tagged: bool, _idx: usize) -> Result<()> {
+        let mut face = self.face.take().unwrap_or_default();
+        // closing vertex
+        if face.len() > 1 && face.first() == face.last() {
+            face.pop();
+        }
+        if face.len() < 3 {
+            return Err(GeozeroError::Geometry(
+                "OBJ face with less than 3 vertices".to_string(),
+            ));
+        }
+        self.out.write_all(b"f")?;
+        for vertex in face {
+            write!(self.out, " {vertex}")?;
+        }
+        self.out.write_all(b"\n")?;
+        Ok(())
+    }
+    fn polygon_begin(&mut self, _tagged: bool, _size: usize, _idx: usize) -> Result<()> {
+        self.in_surface = true;
+        Ok(())
+    }
+    fn polygon_end(&mut self, _tagged: bool, _idx: usize) -> Result<()> {
+        self.in_surface = false;
+        Ok(())
+    }
+    fn triangle_begin(&mut self, _tagged: bool, _size: usize, _idx: usize) -> Result<()> {
+        self.in_surface = true;
+        Ok(())
+    }
+    fn triangle_end(&mut self, _tagged: bool, _idx: usize) -> Result<()> {
+        self.in_surface = false;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn tin_to_obj() {
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = ObjWriter::new(&mut out);
+        let triangles = [
+            [(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (0.0, 1.0, 1.0)],
+            [(1.0, 0.0, 0.0), (1.0, 1.0, 0.5), (0.0, 1.0, 1.0)],
+        ];
+        writer.tin_begin(2, 0).unwrap();
+        for (i, triangle) in triangles.iter().enumerate() {
+            writer.triangle_begin(false, 1, i).unwrap();
+            writer.linestring_begin(false, 4, 0).unwrap();
+            for (j, (x, y, z)) in triangle.iter().chain(&triangle[..1]).enumerate() {
+                writer
+                    .coordinate(*x, *y, Some(*z), None, None, None, j)
+                    .unwrap();
+            }
+            writer.linestring_end(false, 0).unwrap();
+            writer.triangle_end(false, i).unwrap();
+        }
+        writer.tin_end(0).unwrap();
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            "v 0 0 0\nv 1 0 0\nv 0 1 1\nf 1 2 3\nv 1 1 0.5\nf 2 4 3\n"
+        );
+
+        let mut writer = ObjWriter::new(&mut out);
+        writer.point_begin(0).unwrap();
+        assert!(writer.xy(1.0, 2.0, 0).is_err());
+    }
+}
@@ -3,6 +3,10 @@
 //! All geometry types implementing [GeozeroGeometry](crate::GeozeroGeometry) can be encoded as PostGIS EWKB geometry using [wkb::Encode](crate::wkb::Encode).
 //!
 //! Geometry types implementing [FromWkb](crate::wkb::FromWkb) can be decoded from PostGIS geometries using [wkb::Decode](crate::wkb::Decode).
+//!
+//! Raw binary protocol fields of `geometry` and `geography` columns can be processed with
+//! [process_postgis_geometry](crate::wkb::process_postgis_geometry) and
+//! [process_postgis_geography](crate::wkb::process_postgis_geography).
 #[cfg(feature = "with-postgis-diesel")]
 mod postgis_diesel;
 #[cfg(feature = "with-postgis-postgres")]
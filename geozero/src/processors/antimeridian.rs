@@ -0,0 +1,309 @@
+use super::Coord;
+use crate::error::{GeozeroError, Result};
+use crate::GeomProcessor;
+
+type Line = Vec<Coord>;
+
+/// Split linestrings and polygons crossing the antimeridian (±180° longitude).
+///
+/// Segments with a longitude difference above 180° are taken as crossing the antimeridian
+/// and split at the interpolated crossing latitude. Crossing linestrings and polygons are
+/// forwarded as MultiLineStrings and MultiPolygons. Polygons are supported with
+/// an exterior ring crossing twice and holes not crossing the antimeridian,
+/// other polygons (e.g. containing a pole) are rejected.
+pub struct AntimeridianSplitProcessor<P: GeomProcessor> {
+    /// Maximal number of buffered coordinates, larger geometries are rejected
+    pub max_buffered_coords: Option<usize>,
+    inner: P,
+    lines: Vec<Line>,
+    polygons: Vec<Vec<Line>>,
+    buffered: usize,
+    in_polygon: bool,
+    in_multi: bool,
+}
+
+impl<P: GeomProcessor> AntimeridianSplitProcessor<P> {
+    pub fn new(inner: P) -> Self {
+        AntimeridianSplitProcessor {
+            max_buffered_coords: None,
+            inner,
+            lines: Vec::new(),
+            polygons: Vec::new(),
+            buffered: 0,
+            in_polygon: false,
+            in_multi: false,
+        }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    fn emit_lines(&mut self, lines: &[Line], idx: usize) -> Result<()> {
+        self.inner.multilinestring_begin(lines.len(), idx)?;
+        for (i, line) in lines.iter().enumerate() {
+            super::emit_line(&mut self.inner, line, false, i)?;
+        }
+        self.inner.multilinestring_end(idx)
+    }
+
+    fn emit_polygons(&mut self, polygons: &[Vec<Line>], idx: usize) -> Result<()> {
+        self.inner.multipolygon_begin(polygons.len(), idx)?;
+        for (i, rings) in polygons.iter().enumerate() {
+            super::emit_polygon(&mut self.inner, rings, false, i)?;
+        }
+        self.inner.multipolygon_end(idx)
+    }
+}
+
+/// Split a line at antimeridian crossings.
+fn split_line(line: &[Coord]) -> Vec<Line> {
+    let mut parts = vec![Vec::new()];
+    for (i, &c) in line.iter().enumerate() {
+        if let Some(&p) = i.checked_sub(1).and_then(|prev| line.get(prev)) {
+            if (c.0 - p.0).abs() > 180.0 {
+                let (end_lon, start_lon, shift) = if p.0 > 0.0 {
+                    (180.0, -180.0, 360.0)
+                } else {
+                    (-180.0, 180.0, -360.0)
+                };
+                let t = (end_lon - p.0) / (c.0 + shift - p.0);
+                let lat = p.1 + (c.1 - p.1) * t;
+                let (z, m) = (super::lerp(p.2, c.2, t), super::lerp(p.3, c.3, t));
+                if let Some(part) = parts.last_mut() {
+                    part.push((end_lon, lat, z, m));
+                }
+                parts.push(vec![(start_lon, lat, z, m)]);
+            }
+        }
+        if let Some(part) = parts.last_mut() {
+            part.push(c);
+        }
+    }
+    parts
+}
+
+/// Split a closed ring crossing the antimeridian twice into two closed rings.
+fn split_ring(ring: &[Coord]) -> Result<Vec<Line>> {
+    let mut parts = split_line(ring);
+    match parts.len() {
+        1 => Ok(parts),
+        3 => {
+            let first = parts.remove(0);
+            // the ring start lies within the last part
+            let mut wrapped = parts.pop().unwrap_or_default();
+            wrapped.extend(first.into_iter().skip(1));
+            let mut rings = vec![wrapped, parts.remove(0)];
+            for ring in &mut rings {
+                if let Some(&start) = ring.first() {
+                    ring.push(start);
+                }
+            }
+            Ok(rings)
+        }
+        _ => Err(GeozeroError::Geometry(
+            "ring crossing the antimeridian more than twice or enclosing a pole".to_string(),
+        )),
+    }
+}
+
+fn split_polygon(rings: Vec<Line>) -> Result<Vec<Vec<Line>>> {
+    let mut rings = rings.into_iter();
+    let Some(exterior) = rings.next() else {
+        return Ok(vec![Vec::new()]);
+    };
+    let exteriors = split_ring(&exterior)?;
+    if exteriors.len() == 1 {
+        return Ok(vec![std::iter::once(exterior).chain(rings).collect()]);
+    }
+    // split exteriors start on the antimeridian of their side
+    let mut polygons: Vec<Vec<Line>> = exteriors.into_iter().map(|ring| vec![ring]).collect();
+    for hole in rings {
+        if split_line(&hole).len() > 1 {
+            return Err(GeozeroError::Geometry(
+                "hole crossing the antimeridian".to_string(),
+            ));
+        }
+        let east = hole.first().is_some_and(|c| c.0 >= 0.0);
+        if let Some(polygon) = polygons
+            .iter_mut()
+            .find(|polygon| (polygon[0][0].0 > 0.0) == east)
+        {
+            polygon.push(hole);
+        }
+    }
+    Ok(polygons)
+}
+
+impl<P: GeomProcessor> GeomProcessor for AntimeridianSplitProcessor<P> {
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        self.coordinate(x, y, None, None, None, None, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        let line = if self.in_polygon {
+            self.polygons.last_mut().and_then(|rings| rings.last_mut())
+        } else {
+            self.lines.last_mut()
+        };
+        match line {
+            Some(line) => {
+                super::check_buffer_limit(self.buffered, self.max_buffered_coords)?;
+                self.buffered += 1;
+                line.push((x, y, z, m));
+                Ok(())
+            }
+            None if self.inner.multi_dim() => self.inner.coordinate(x, y, z, m, t, tm, idx),
+            None => self.inner.xy(x, y, idx),
+        }
+    }
+    fn point_begin(&mut self, idx: usize) -> Result<()> {
+        // coordinates of points are not buffered
+        self.lines.clear();
+        self.inner.point_begin(idx)
+    }
+    fn multipoint_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.lines.clear();
+        self.inner.multipoint_begin(size, idx)
+    }
+    fn linestring_begin(&mut self, _tagged: bool, size: usize, _idx: usize) -> Result<()> {
+        if self.in_polygon {
+            if let Some(rings) = self.polygons.last_mut() {
+                rings.push(Vec::with_capacity(size));
+            }
+        } else {
+            if !self.in_multi {
+                self.buffered = 0;
+            }
+            self.lines.push(Vec::with_capacity(size));
+        }
+        Ok(())
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        if self.in_polygon || self.in_multi {
+            return Ok(());
+        }
+        let line = self.lines.pop().unwrap_or_default();
+        let parts = split_line(&line);
+        if parts.len() == 1 {
+            super::emit_line(&mut self.inner, &line, tagged, idx)
+        } else {
+            self.emit_lines(&parts, idx)
+        }
+    }
+    fn multilinestring_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.in_multi = true;
+        self.lines.clear();
+        self.buffered = 0;
+        Ok(())
+    }
+    fn multilinestring_end(&mut self, idx: usize) -> Result<()> {
+        self.in_multi = false;
+        let lines: Vec<Line> = std::mem::take(&mut self.lines)
+            .iter()
+            .flat_map(|line| split_line(line))
+            .collect();
+        self.emit_lines(&lines, idx)
+    }
+    fn polygon_begin(&mut self, _tagged: bool, size: usize, _idx: usize) -> Result<()> {
+        if !self.in_multi {
+            self.polygons.clear();
+            self.buffered = 0;
+        }
+        self.in_polygon = true;
+        self.polygons.push(Vec::with_capacity(size));
+        Ok(())
+    }
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.in_polygon = false;
+        if self.in_multi {
+            return Ok(());
+        }
+        let rings = self.polygons.pop().unwrap_or_default();
+        let polygons = split_polygon(rings)?;
+        if polygons.len() == 1 {
+            super::emit_polygon(&mut self.inner, &polygons[0], tagged, idx)
+        } else {
+            self.emit_polygons(&polygons, idx)
+        }
+    }
+    fn multipolygon_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.in_multi = true;
+        self.polygons.clear();
+        self.buffered = 0;
+        Ok(())
+    }
+    fn multipolygon_end(&mut self, idx: usize) -> Result<()> {
+        self.in_multi = false;
+        let mut polygons = Vec::new();
+        for rings in std::mem::take(&mut self.polygons) {
+            polygons.extend(split_polygon(rings)?);
+        }
+        self.emit_polygons(&polygons, idx)
+    }
+    forward_geom_processor!(inner;
+        dimensions, srid, empty_point, point_end, multipoint_end,
+        geometrycollection_begin, geometrycollection_end,
+        circularstring_begin, circularstring_end, compoundcurve_begin, compoundcurve_end,
+        curvepolygon_begin, curvepolygon_end, multicurve_begin, multicurve_end,
+        multisurface_begin, multisurface_end, triangle_begin, triangle_end,
+        polyhedralsurface_begin, polyhedralsurface_end, tin_begin, tin_end,
+    );
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+    use crate::wkt::WktStr;
+    use crate::{GeozeroGeometry, ProcessorSink};
+
+    fn split(wkt: &str) -> String {
+        process_wkt!(wkt, AntimeridianSplitProcessor::new)
+    }
+
+    #[test]
+    fn split_linestring() {
+        assert_eq!(
+            split("LINESTRING(170 0,-170 10)"),
+            "MULTILINESTRING((170 0,180 5),(-180 5,-170 10))"
+        );
+        assert_eq!(split("LINESTRING(10 0,20 10)"), "LINESTRING(10 0,20 10)");
+        assert_eq!(
+            split("MULTILINESTRING((-175 0,175 0),(0 0,1 1))"),
+            "MULTILINESTRING((-175 0,-180 0),(180 0,175 0),(0 0,1 1))"
+        );
+    }
+
+    #[test]
+    fn split_polygon() {
+        assert_eq!(
+            split("POLYGON((170 -10,-170 -10,-170 10,170 10,170 -10),(172 -1,174 -1,174 1,172 -1))"),
+            "MULTIPOLYGON(((180 10,170 10,170 -10,180 -10,180 10),(172 -1,174 -1,174 1,172 -1)),((-180 -10,-170 -10,-170 10,-180 10,-180 -10)))"
+        );
+    }
+
+    #[test]
+    fn buffer_limit() {
+        let mut processor = AntimeridianSplitProcessor::new(ProcessorSink);
+        processor.max_buffered_coords = Some(3);
+        assert!(WktStr("LINESTRING(170 0,-170 10,-160 10)")
+            .process_geom(&mut processor)
+            .is_ok());
+        assert_eq!(
+            WktStr("MULTILINESTRING((170 0,-170 10),(0 0,1 1))")
+                .process_geom(&mut processor)
+                .unwrap_err()
+                .to_string(),
+            "processing geometry `geometry exceeds the limit of 3 buffered coordinates`"
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use crate::error::Result;
+use crate::{AxisOrder, GeomProcessor};
+
+/// Swap x and y of coordinates according to an axis order.
+///
+/// With [AxisOrder::LatLon], lon/lat coordinates are forwarded as lat/lon and vice versa.
+pub struct AxisOrderProcessor<P: GeomProcessor> {
+    pub axis_order: AxisOrder,
+    inner: P,
+}
+
+impl<P: GeomProcessor> AxisOrderProcessor<P> {
+    pub fn new(inner: P, axis_order: AxisOrder) -> Self {
+        AxisOrderProcessor { axis_order, inner }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+}
+
+impl<P: GeomProcessor> GeomProcessor for AxisOrderProcessor<P> {
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        let (x, y) = self.axis_order.order(x, y);
+        self.inner.xy(x, y, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        let (x, y) = self.axis_order.order(x, y);
+        self.inner.coordinate(x, y, z, m, t, tm, idx)
+    }
+    forward_geom_processor!(inner;
+        dimensions, srid, empty_point, point_begin, point_end,
+        multipoint_begin, multipoint_end, linestring_begin, linestring_end,
+        multilinestring_begin, multilinestring_end, polygon_begin, polygon_end,
+        multipolygon_begin, multipolygon_end, geometrycollection_begin, geometrycollection_end,
+        circularstring_begin, circularstring_end, compoundcurve_begin, compoundcurve_end,
+        curvepolygon_begin, curvepolygon_end, multicurve_begin, multicurve_end,
+        multisurface_begin, multisurface_end, triangle_begin, triangle_end,
+        polyhedralsurface_begin, polyhedralsurface_end, tin_begin, tin_end,
+    );
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+
+    #[test]
+    fn swap_axes() {
+        assert_eq!(
+            process_wkt!("LINESTRING(10 20,30 40)", |w| AxisOrderProcessor::new(
+                w,
+                AxisOrder::LatLon
+            )),
+            "LINESTRING(20 10,40 30)"
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use crate::error::{GeozeroError, Result};
+use crate::{CoordDimensions, GeomProcessor};
+
+/// Coordinate outside of the bounds of a [BoundsCheckProcessor].
+#[derive(Clone, PartialEq, Debug)]
+pub struct BoundsViolation {
+    /// Number of coordinates processed before this coordinate
+    pub index: usize,
+    pub x: f64,
+    pub y: f64,
+    pub z: Option<f64>,
+}
+
+/// Check that coordinates are within the valid bounds of a CRS, e.g. `[-180, -90, 180, 90]` for EPSG:4326.
+///
+/// Out of range coordinates often indicate swapped axes or a wrong CRS. This is a data
+/// quality check independent of geometric validity. The first violation fails processing,
+/// unless `collect` is set. Coordinates are forwarded unchanged.
+pub struct BoundsCheckProcessor<P: GeomProcessor> {
+    /// Valid bounds `[minx, miny, maxx, maxy]`
+    pub bounds: [f64; 4],
+    /// Valid Z range, Z values are not checked if `None`
+    pub z_range: Option<(f64, f64)>,
+    /// Collect violations in `violations` instead of failing at the first violation
+    pub collect: bool,
+    /// Violations found with `collect`
+    pub violations: Vec<BoundsViolation>,
+    inner: P,
+    index: usize,
+}
+
+impl<P: GeomProcessor> BoundsCheckProcessor<P> {
+    pub fn new(inner: P, bounds: [f64; 4]) -> Self {
+        BoundsCheckProcessor {
+            bounds,
+            z_range: None,
+            collect: false,
+            violations: Vec::new(),
+            inner,
+            index: 0,
+        }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    fn check(&mut self, x: f64, y: f64, z: Option<f64>) -> Result<()> {
+        let [minx, miny, maxx, maxy] = self.bounds;
+        let z_valid = match (z, self.z_range) {
+            (Some(z), Some((min, max))) => (min..=max).contains(&z),
+            _ => true,
+        };
+        let index = self.index;
+        self.index += 1;
+        if (minx..=maxx).contains(&x) && (miny..=maxy).contains(&y) && z_valid {
+            return Ok(());
+        }
+        if self.collect {
+            self.violations.push(BoundsViolation { index, x, y, z });
+            Ok(())
+        } else {
+            Err(GeozeroError::Geometry(format!(
+                "coordinate {index} ({x} {y}) out of bounds"
+            )))
+        }
+    }
+}
+
+impl<P: GeomProcessor> GeomProcessor for BoundsCheckProcessor<P> {
+    fn dimensions(&self) -> CoordDimensions {
+        let mut dims = self.inner.dimensions();
+        dims.z |= self.z_range.is_some();
+        dims
+    }
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        self.check(x, y, None)?;
+        self.inner.xy(x, y, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        self.check(x, y, z)?;
+        if self.inner.multi_dim() {
+            self.inner.coordinate(x, y, z, m, t, tm, idx)
+        } else {
+            self.inner.xy(x, y, idx)
+        }
+    }
+    forward_geom_processor!(inner;
+        srid, empty_point, point_begin, point_end,
+        multipoint_begin, multipoint_end, linestring_begin, linestring_end,
+        multilinestring_begin, multilinestring_end, polygon_begin, polygon_end,
+        multipolygon_begin, multipolygon_end, geometrycollection_begin, geometrycollection_end,
+        circularstring_begin, circularstring_end, compoundcurve_begin, compoundcurve_end,
+        curvepolygon_begin, curvepolygon_end, multicurve_begin, multicurve_end,
+        multisurface_begin, multisurface_end, triangle_begin, triangle_end,
+        polyhedralsurface_begin, polyhedralsurface_end, tin_begin, tin_end,
+    );
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+    use crate::wkt::{WktStr, WktWriter};
+    use crate::GeozeroGeometry;
+
+    const WGS84: [f64; 4] = [-180.0, -90.0, 180.0, 90.0];
+
+    #[test]
+    fn out_of_bounds() {
+        let mut out: Vec<u8> = Vec::new();
+        let mut processor = BoundsCheckProcessor::new(WktWriter::new(&mut out), WGS84);
+        let err = WktStr("POINT(200 45)")
+            .process_geom(&mut processor)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "processing geometry `coordinate 0 (200 45) out of bounds`"
+        );
+
+        let mut out: Vec<u8> = Vec::new();
+        let mut processor = BoundsCheckProcessor::new(WktWriter::new(&mut out), WGS84);
+        processor.collect = true;
+        WktStr("LINESTRING(8.5 47.4,47.4 8.5,200 45,45 -100)")
+            .process_geom(&mut processor)
+            .unwrap();
+        assert_eq!(
+            processor.violations,
+            vec![
+                BoundsViolation {
+                    index: 2,
+                    x: 200.0,
+                    y: 45.0,
+                    z: None
+                },
+                BoundsViolation {
+                    index: 3,
+                    x: 45.0,
+                    y: -100.0,
+                    z: None
+                },
+            ]
+        );
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "LINESTRING(8.5 47.4,47.4 8.5,200 45,45 -100)"
+        );
+    }
+}
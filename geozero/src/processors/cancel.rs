@@ -0,0 +1,100 @@
+use crate::error::{GeozeroError, Result};
+use crate::GeomProcessor;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Abort processing when a cancellation flag is set.
+///
+/// The flag is checked before each coordinate is forwarded to the inner processor.
+/// Once set, processing fails with [GeozeroError::Cancelled].
+pub struct CancelProcessor<P: GeomProcessor> {
+    inner: P,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl<P: GeomProcessor> CancelProcessor<P> {
+    pub fn new(inner: P, cancelled: Arc<AtomicBool>) -> Self {
+        CancelProcessor { inner, cancelled }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    fn check(&self) -> Result<()> {
+        if self.cancelled.load(Ordering::Relaxed) {
+            Err(GeozeroError::Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<P: GeomProcessor> GeomProcessor for CancelProcessor<P> {
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        self.check()?;
+        self.inner.xy(x, y, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        self.check()?;
+        self.inner.coordinate(x, y, z, m, t, tm, idx)
+    }
+    forward_geom_processor!(inner;
+        dimensions, srid, empty_point, point_begin, point_end,
+        multipoint_begin, multipoint_end, linestring_begin, linestring_end,
+        multilinestring_begin, multilinestring_end, polygon_begin, polygon_end,
+        multipolygon_begin, multipolygon_end, geometrycollection_begin, geometrycollection_end,
+        circularstring_begin, circularstring_end, compoundcurve_begin, compoundcurve_end,
+        curvepolygon_begin, curvepolygon_end, multicurve_begin, multicurve_end,
+        multisurface_begin, multisurface_end, triangle_begin, triangle_end,
+        polyhedralsurface_begin, polyhedralsurface_end, tin_begin, tin_end,
+    );
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+    use crate::wkt::WktStr;
+    use crate::GeozeroGeometry;
+
+    /// Sets the flag after a number of coordinates, like a disconnecting client
+    struct Client {
+        coords: usize,
+        cancelled: Arc<AtomicBool>,
+    }
+
+    impl GeomProcessor for Client {
+        fn xy(&mut self, _x: f64, _y: f64, _idx: usize) -> Result<()> {
+            self.coords += 1;
+            if self.coords == 100 {
+                self.cancelled.store(true, Ordering::Relaxed);
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn cancel() {
+        let coords: Vec<String> = (0..1000).map(|i| format!("{i} {i}")).collect();
+        let wkt = format!("LINESTRING({})", coords.join(","));
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let client = Client {
+            coords: 0,
+            cancelled: cancelled.clone(),
+        };
+        let mut processor = CancelProcessor::new(client, cancelled);
+        let result = WktStr(&wkt).process_geom(&mut processor);
+        assert!(matches!(result, Err(GeozeroError::Cancelled)));
+        assert_eq!(processor.into_inner().coords, 100);
+    }
+}
@@ -0,0 +1,210 @@
+use crate::error::Result;
+use crate::GeomProcessor;
+
+/// Replace polygons with an approximate centerline, e.g. for labeling elongated polygons.
+///
+/// Heuristic: the main axis of the polygon is the principal axis of its exterior ring
+/// vertices. The polygon is cut into `segments` slices perpendicular to this axis and
+/// the centerline connects the midpoints of the exterior ring crossings of each slice.
+/// This works well for elongated, moderately curved polygons, but not for branched or
+/// strongly bent shapes, where a medial axis would be needed.
+///
+/// Polygons become LineStrings and MultiPolygons become MultiLineStrings with XY
+/// coordinates. Other geometries are forwarded unchanged.
+pub struct CenterlineProcessor<P: GeomProcessor> {
+    /// Number of slices, i.e. points of the centerline
+    pub segments: usize,
+    /// Maximal number of buffered coordinates, larger exterior rings are rejected
+    pub max_buffered_coords: Option<usize>,
+    inner: P,
+    ring: Vec<(f64, f64)>,
+    in_polygon: bool,
+    ring_idx: usize,
+}
+
+impl<P: GeomProcessor> CenterlineProcessor<P> {
+    pub fn new(inner: P, segments: usize) -> Self {
+        CenterlineProcessor {
+            segments,
+            max_buffered_coords: None,
+            inner,
+            ring: Vec::new(),
+            in_polygon: false,
+            ring_idx: 0,
+        }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    fn centerline(&self) -> Vec<(f64, f64)> {
+        // without closing vertex
+        let vertices = match (self.ring.first(), self.ring.last()) {
+            (Some(first), Some(last)) if self.ring.len() > 1 && first == last => {
+                &self.ring[..self.ring.len() - 1]
+            }
+            _ => &self.ring[..],
+        };
+        if vertices.len() < 3 || self.segments == 0 {
+            return Vec::new();
+        }
+        let n = vertices.len() as f64;
+        let cx = vertices.iter().map(|p| p.0).sum::<f64>() / n;
+        let cy = vertices.iter().map(|p| p.1).sum::<f64>() / n;
+        let (mut sxx, mut syy, mut sxy) = (0.0, 0.0, 0.0);
+        for (x, y) in vertices {
+            sxx += (x - cx) * (x - cx);
+            syy += (y - cy) * (y - cy);
+            sxy += (x - cx) * (y - cy);
+        }
+        let angle = 0.5 * (2.0 * sxy).atan2(sxx - syy);
+        let (sin, cos) = angle.sin_cos();
+        // axis and normal coordinates relative to the center
+        let to_axis = |(x, y): (f64, f64)| {
+            let (dx, dy) = (x - cx, y - cy);
+            (dx * cos + dy * sin, -dx * sin + dy * cos)
+        };
+        let local: Vec<(f64, f64)> = self.ring.iter().map(|p| to_axis(*p)).collect();
+        let t_min = local.iter().map(|p| p.0).fold(f64::INFINITY, f64::min);
+        let t_max = local.iter().map(|p| p.0).fold(f64::NEG_INFINITY, f64::max);
+
+        let mut line = Vec::with_capacity(self.segments);
+        for i in 0..self.segments {
+            let t = t_min + (t_max - t_min) * (i as f64 + 0.5) / self.segments as f64;
+            let mut range: Option<(f64, f64)> = None;
+            for w in local.windows(2) {
+                let (a, b) = (w[0], w[1]);
+                if a.0 == b.0 || (a.0 - t) * (b.0 - t) > 0.0 {
+                    continue;
+                }
+                let s = a.1 + (b.1 - a.1) * (t - a.0) / (b.0 - a.0);
+                range = Some(match range {
+                    Some((min, max)) => (min.min(s), max.max(s)),
+                    None => (s, s),
+                });
+            }
+            if let Some((min, max)) = range {
+                let s = (min + max) / 2.0;
+                line.push((cx + t * cos - s * sin, cy + t * sin + s * cos));
+            }
+        }
+        line
+    }
+}
+
+impl<P: GeomProcessor> GeomProcessor for CenterlineProcessor<P> {
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        if !self.in_polygon {
+            return self.inner.xy(x, y, idx);
+        }
+        if self.ring_idx == 0 {
+            super::check_buffer_limit(self.ring.len(), self.max_buffered_coords)?;
+            self.ring.push((x, y));
+        }
+        Ok(())
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        if self.in_polygon {
+            self.xy(x, y, idx)
+        } else {
+            self.inner.coordinate(x, y, z, m, t, tm, idx)
+        }
+    }
+    fn linestring_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        if self.in_polygon {
+            self.ring_idx = idx;
+            Ok(())
+        } else {
+            self.inner.linestring_begin(tagged, size, idx)
+        }
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        if self.in_polygon {
+            Ok(())
+        } else {
+            self.inner.linestring_end(tagged, idx)
+        }
+    }
+    fn polygon_begin(&mut self, _tagged: bool, _size: usize, _idx: usize) -> Result<()> {
+        self.in_polygon = true;
+        self.ring.clear();
+        Ok(())
+    }
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.in_polygon = false;
+        let line = self.centerline();
+        self.inner.linestring_begin(tagged, line.len(), idx)?;
+        for (i, (x, y)) in line.into_iter().enumerate() {
+            self.inner.xy(x, y, i)?;
+        }
+        self.inner.linestring_end(tagged, idx)
+    }
+    fn multipolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.inner.multilinestring_begin(size, idx)
+    }
+    fn multipolygon_end(&mut self, idx: usize) -> Result<()> {
+        self.inner.multilinestring_end(idx)
+    }
+    forward_geom_processor!(inner;
+        dimensions, srid, empty_point, point_begin, point_end,
+        multipoint_begin, multipoint_end, multilinestring_begin, multilinestring_end,
+        geometrycollection_begin, geometrycollection_end,
+        circularstring_begin, circularstring_end, compoundcurve_begin, compoundcurve_end,
+        curvepolygon_begin, curvepolygon_end, multicurve_begin, multicurve_end,
+        multisurface_begin, multisurface_end, triangle_begin, triangle_end,
+        polyhedralsurface_begin, polyhedralsurface_end, tin_begin, tin_end,
+    );
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+    use crate::wkt::WktStr;
+    use crate::{GeozeroGeometry, ProcessorSink};
+
+    fn centerline(wkt: &str, segments: usize) -> String {
+        process_wkt!(wkt, |w| CenterlineProcessor::new(w, segments))
+    }
+
+    #[test]
+    fn long_rectangle() {
+        assert_eq!(
+            centerline("POLYGON((0 0,10 0,10 2,0 2,0 0))", 4),
+            "LINESTRING(1.25 1,3.75 1,6.25 1,8.75 1)"
+        );
+        assert_eq!(
+            centerline(
+                "MULTIPOLYGON(((0 0,2 0,2 10,0 10,0 0)),((5 0,9 0,9 1,5 1,5 0)))",
+                2
+            ),
+            "MULTILINESTRING((1 2.5,1 7.5),(6 0.5,8 0.5))"
+        );
+    }
+
+    #[test]
+    fn buffer_limit() {
+        let mut processor = CenterlineProcessor::new(ProcessorSink, 2);
+        processor.max_buffered_coords = Some(4);
+        let polygon = WktStr("POLYGON((0 0,10 0,10 2,0 2,0 0))");
+        assert_eq!(
+            polygon
+                .process_geom(&mut processor)
+                .unwrap_err()
+                .to_string(),
+            "processing geometry `geometry exceeds the limit of 4 buffered coordinates`"
+        );
+        processor.max_buffered_coords = Some(5);
+        assert!(polygon.process_geom(&mut processor).is_ok());
+    }
+}
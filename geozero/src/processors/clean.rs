@@ -0,0 +1,233 @@
+use super::Coord;
+use crate::error::Result;
+use crate::GeomProcessor;
+
+type Line = Vec<Coord>;
+
+/// Remove zero-length segments and degenerate linestrings and rings.
+///
+/// Consecutive duplicate coordinates are dropped. Afterwards, rings with less than
+/// 4 coordinates and linestrings with less than 2 coordinates are removed. Polygons
+/// with a degenerate exterior ring are removed from multipolygons, other polygons
+/// are replaced by an empty polygon (linestrings likewise). This is no full MakeValid,
+/// e.g. self-intersections are kept.
+pub struct CleanProcessor<P: GeomProcessor> {
+    /// Maximal number of buffered coordinates, larger geometries are rejected
+    pub max_buffered_coords: Option<usize>,
+    inner: P,
+    /// Buffered polygons (multipolygon) or linestrings (multilinestring, single entry otherwise)
+    parts: Vec<Vec<Line>>,
+    in_polygon: bool,
+    in_multi: bool,
+    buffering: bool,
+    buffered: usize,
+}
+
+impl<P: GeomProcessor> CleanProcessor<P> {
+    pub fn new(inner: P) -> Self {
+        CleanProcessor {
+            max_buffered_coords: None,
+            inner,
+            parts: Vec::new(),
+            in_polygon: false,
+            in_multi: false,
+            buffering: false,
+            buffered: 0,
+        }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    /// Start buffering a new top-level geometry.
+    fn clear(&mut self) {
+        self.parts.clear();
+        self.buffered = 0;
+    }
+
+    /// Remove degenerate rings, returns `None` if the exterior ring is degenerate.
+    fn clean_polygon(rings: Vec<Line>) -> Option<Vec<Line>> {
+        let mut rings = rings.into_iter();
+        let exterior = rings.next()?;
+        if exterior.len() < 4 {
+            return None;
+        }
+        Some(
+            std::iter::once(exterior)
+                .chain(rings.filter(|ring| ring.len() >= 4))
+                .collect(),
+        )
+    }
+}
+
+impl<P: GeomProcessor> GeomProcessor for CleanProcessor<P> {
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        self.coordinate(x, y, None, None, None, None, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        if !self.buffering {
+            return if self.inner.multi_dim() {
+                self.inner.coordinate(x, y, z, m, t, tm, idx)
+            } else {
+                self.inner.xy(x, y, idx)
+            };
+        }
+        if let Some(line) = self.parts.last_mut().and_then(|lines| lines.last_mut()) {
+            if line.last() != Some(&(x, y, z, m)) {
+                super::check_buffer_limit(self.buffered, self.max_buffered_coords)?;
+                self.buffered += 1;
+                line.push((x, y, z, m));
+            }
+        }
+        Ok(())
+    }
+    fn linestring_begin(&mut self, _tagged: bool, size: usize, _idx: usize) -> Result<()> {
+        if !self.in_polygon && !self.in_multi {
+            self.clear();
+            self.parts.push(Vec::new());
+        }
+        if let Some(lines) = self.parts.last_mut() {
+            lines.push(Vec::with_capacity(size));
+        }
+        self.buffering = true;
+        Ok(())
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.buffering = false;
+        if self.in_polygon || self.in_multi {
+            return Ok(());
+        }
+        let mut line = self
+            .parts
+            .pop()
+            .unwrap_or_default()
+            .pop()
+            .unwrap_or_default();
+        if line.len() < 2 {
+            line.clear();
+        }
+        super::emit_line(&mut self.inner, &line, tagged, idx)
+    }
+    fn multilinestring_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.in_multi = true;
+        self.clear();
+        self.parts.push(Vec::new());
+        Ok(())
+    }
+    fn multilinestring_end(&mut self, idx: usize) -> Result<()> {
+        self.in_multi = false;
+        let lines: Vec<Line> = self
+            .parts
+            .pop()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|line| line.len() >= 2)
+            .collect();
+        self.inner.multilinestring_begin(lines.len(), idx)?;
+        for (i, line) in lines.iter().enumerate() {
+            super::emit_line(&mut self.inner, line, false, i)?;
+        }
+        self.inner.multilinestring_end(idx)
+    }
+    fn polygon_begin(&mut self, _tagged: bool, size: usize, _idx: usize) -> Result<()> {
+        if !self.in_multi {
+            self.clear();
+        }
+        self.in_polygon = true;
+        self.parts.push(Vec::with_capacity(size));
+        Ok(())
+    }
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.in_polygon = false;
+        if self.in_multi {
+            return Ok(());
+        }
+        let rings = self.parts.pop().unwrap_or_default();
+        let rings = Self::clean_polygon(rings).unwrap_or_default();
+        super::emit_polygon(&mut self.inner, &rings, tagged, idx)
+    }
+    fn multipolygon_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.in_multi = true;
+        self.clear();
+        Ok(())
+    }
+    fn multipolygon_end(&mut self, idx: usize) -> Result<()> {
+        self.in_multi = false;
+        let polygons: Vec<_> = std::mem::take(&mut self.parts)
+            .into_iter()
+            .filter_map(Self::clean_polygon)
+            .collect();
+        self.inner.multipolygon_begin(polygons.len(), idx)?;
+        for (i, rings) in polygons.iter().enumerate() {
+            super::emit_polygon(&mut self.inner, rings, false, i)?;
+        }
+        self.inner.multipolygon_end(idx)
+    }
+    forward_geom_processor!(inner;
+        dimensions, srid, empty_point, point_begin, point_end,
+        multipoint_begin, multipoint_end, geometrycollection_begin, geometrycollection_end,
+        circularstring_begin, circularstring_end, compoundcurve_begin, compoundcurve_end,
+        curvepolygon_begin, curvepolygon_end, multicurve_begin, multicurve_end,
+        multisurface_begin, multisurface_end, triangle_begin, triangle_end,
+        polyhedralsurface_begin, polyhedralsurface_end, tin_begin, tin_end,
+    );
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+    use crate::wkt::WktStr;
+    use crate::{GeozeroGeometry, ProcessorSink};
+
+    fn clean(wkt: &str) -> String {
+        process_wkt!(wkt, CleanProcessor::new)
+    }
+
+    #[test]
+    fn collapsed_hole() {
+        assert_eq!(
+            clean("POLYGON((0 0,4 0,4 4,4 4,0 4,0 0),(1 1,2 2,2 2,1 1))"),
+            "POLYGON((0 0,4 0,4 4,0 4,0 0))"
+        );
+    }
+
+    #[test]
+    fn degenerate_parts() {
+        assert_eq!(
+            clean("MULTIPOLYGON(((0 0,1 1,0 0)),((0 0,1 0,1 1,0 0)))"),
+            "MULTIPOLYGON(((0 0,1 0,1 1,0 0)))"
+        );
+        assert_eq!(clean("POLYGON((0 0,1 1,1 1,0 0))"), "POLYGON EMPTY");
+        assert_eq!(
+            clean("MULTILINESTRING((0 0,0 0),(0 0,1 1,1 1))"),
+            "MULTILINESTRING((0 0,1 1))"
+        );
+        assert_eq!(clean("LINESTRING(3 3,3 3)"), "LINESTRING EMPTY");
+    }
+
+    #[test]
+    fn buffer_limit() {
+        let mut processor = CleanProcessor::new(ProcessorSink);
+        processor.max_buffered_coords = Some(4);
+        // dropped duplicates are not buffered
+        assert!(WktStr("POLYGON((0 0,1 0,1 1,1 1,0 0))")
+            .process_geom(&mut processor)
+            .is_ok());
+        assert!(
+            WktStr("MULTIPOLYGON(((0 0,1 0,1 1,0 0)),((5 5,6 5,6 6,5 5)))")
+                .process_geom(&mut processor)
+                .is_err()
+        );
+    }
+}
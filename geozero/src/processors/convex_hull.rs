@@ -0,0 +1,189 @@
+use crate::error::Result;
+use crate::GeomProcessor;
+
+/// Compute the convex hull of all processed coordinates.
+///
+/// Coordinates are accumulated until [finish](ConvexHullProcessor::finish) is called,
+/// which forwards the hull to the inner processor: a polygon with a counter-clockwise
+/// ring, or a point or linestring if the hull has less than 3 vertices.
+/// NaN coordinates (e.g. WKB `POINT EMPTY`) are ignored.
+pub struct ConvexHullProcessor<P: GeomProcessor> {
+    /// Maximal number of buffered coordinates, more coordinates are rejected
+    pub max_buffered_coords: Option<usize>,
+    inner: P,
+    points: Vec<(f64, f64)>,
+}
+
+impl<P: GeomProcessor> ConvexHullProcessor<P> {
+    pub fn new(inner: P) -> Self {
+        ConvexHullProcessor {
+            max_buffered_coords: None,
+            inner,
+            points: Vec::new(),
+        }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    /// Hull vertices in counter-clockwise order, without closing vertex.
+    ///
+    /// Computed with Andrew's monotone chain algorithm, collinear vertices are omitted.
+    pub fn hull(&self) -> Vec<(f64, f64)> {
+        convex_hull(&self.points)
+    }
+
+    /// Forward the hull of the accumulated coordinates and start over.
+    pub fn finish(&mut self) -> Result<()> {
+        let hull = self.hull();
+        self.points.clear();
+        match hull.len() {
+            0 => {
+                self.inner.polygon_begin(true, 0, 0)?;
+                self.inner.polygon_end(true, 0)
+            }
+            1 => {
+                self.inner.point_begin(0)?;
+                self.inner.xy(hull[0].0, hull[0].1, 0)?;
+                self.inner.point_end(0)
+            }
+            2 => {
+                self.inner.linestring_begin(true, 2, 0)?;
+                for (i, &(x, y)) in hull.iter().enumerate() {
+                    self.inner.xy(x, y, i)?;
+                }
+                self.inner.linestring_end(true, 0)
+            }
+            n => {
+                self.inner.polygon_begin(true, 1, 0)?;
+                self.inner.linestring_begin(false, n + 1, 0)?;
+                for (i, &(x, y)) in hull.iter().chain(&hull[..1]).enumerate() {
+                    self.inner.xy(x, y, i)?;
+                }
+                self.inner.linestring_end(false, 0)?;
+                self.inner.polygon_end(true, 0)
+            }
+        }
+    }
+}
+
+/// Convex hull vertices in counter-clockwise order, without closing vertex.
+pub(crate) fn convex_hull(points: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    let mut points = points.to_vec();
+    points.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+    points.dedup();
+    if points.len() < 3 {
+        return points;
+    }
+    let mut lower = half_hull(points.iter());
+    let mut upper = half_hull(points.iter().rev());
+    // chain ends are the start of the other chain
+    lower.pop();
+    upper.pop();
+    lower.append(&mut upper);
+    lower
+}
+
+/// Lower (or upper) part of the hull of points sorted by x (or reverse sorted).
+fn half_hull<'a>(points: impl Iterator<Item = &'a (f64, f64)>) -> Vec<(f64, f64)> {
+    let mut chain: Vec<(f64, f64)> = Vec::new();
+    for &p in points {
+        while chain.len() >= 2 && cross(chain[chain.len() - 2], chain[chain.len() - 1], p) <= 0.0 {
+            chain.pop();
+        }
+        chain.push(p);
+    }
+    chain
+}
+
+/// Cross product of `oa` and `ob`, positive for a counter-clockwise turn.
+fn cross(o: (f64, f64), a: (f64, f64), b: (f64, f64)) -> f64 {
+    (a.0 - o.0) * (b.1 - o.1) - (a.1 - o.1) * (b.0 - o.0)
+}
+
+impl<P: GeomProcessor> GeomProcessor for ConvexHullProcessor<P> {
+    fn srid(&mut self, srid: Option<i32>) -> Result<()> {
+        self.inner.srid(srid)
+    }
+    fn xy(&mut self, x: f64, y: f64, _idx: usize) -> Result<()> {
+        // WKB encodes POINT EMPTY as NaN coordinates
+        if x.is_nan() || y.is_nan() {
+            return Ok(());
+        }
+        super::check_buffer_limit(self.points.len(), self.max_buffered_coords)?;
+        self.points.push((x, y));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+    use crate::wkt::{WktStr, WktWriter};
+    use crate::{GeozeroGeometry, ProcessorSink};
+
+    fn hull(wkt: &str) -> String {
+        process_wkt!(
+            wkt,
+            ConvexHullProcessor::new,
+            |p: &mut ConvexHullProcessor<_>| p.finish()
+        )
+    }
+
+    #[test]
+    fn scattered_multipoint() {
+        assert_eq!(
+            hull("MULTIPOINT(0 0,2 1,4 0,3 2,4 4,1 3,0 4,2 2,2 4)"),
+            "POLYGON((0 0,4 0,4 4,0 4,0 0))"
+        );
+        assert_eq!(
+            hull("MULTIPOINT(5 1,-1 2,3 7,2 3,1 1)"),
+            "POLYGON((-1 2,1 1,5 1,3 7,-1 2))"
+        );
+    }
+
+    #[test]
+    fn degenerate_hulls() {
+        assert_eq!(hull("MULTIPOINT(1 1,1 1)"), "POINT(1 1)");
+        assert_eq!(hull("LINESTRING(0 0,1 1,2 2)"), "LINESTRING(0 0,2 2)");
+    }
+
+    #[test]
+    #[cfg(feature = "with-wkb")]
+    fn wkb_empty_point() {
+        use crate::wkb::process_wkb_geom;
+
+        let mut out: Vec<u8> = Vec::new();
+        let mut processor = ConvexHullProcessor::new(WktWriter::new(&mut out));
+        WktStr("MULTIPOINT(0 0,2 0,0 2)")
+            .process_geom(&mut processor)
+            .unwrap();
+        // POINT EMPTY
+        let wkb = hex::decode("0101000000000000000000F87F000000000000F87F").unwrap();
+        process_wkb_geom(&mut wkb.as_slice(), &mut processor).unwrap();
+        processor.finish().unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "POLYGON((0 0,2 0,0 2,0 0))"
+        );
+    }
+
+    #[test]
+    fn buffer_limit() {
+        let mut processor = ConvexHullProcessor::new(ProcessorSink);
+        processor.max_buffered_coords = Some(3);
+        assert!(WktStr("MULTIPOINT(0 0,2 0,0 2)")
+            .process_geom(&mut processor)
+            .is_ok());
+        processor.finish().unwrap();
+        assert_eq!(
+            WktStr("MULTIPOINT(0 0,2 0,0 2,2 2)")
+                .process_geom(&mut processor)
+                .unwrap_err()
+                .to_string(),
+            "processing geometry `geometry exceeds the limit of 3 buffered coordinates`"
+        );
+    }
+}
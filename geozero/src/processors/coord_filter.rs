@@ -0,0 +1,168 @@
+use crate::error::Result;
+use crate::GeomProcessor;
+
+type Coord = (f64, f64, Option<f64>, Option<f64>);
+
+/// Drop or replace coordinates with a filter function.
+///
+/// The filter receives `(x, y, z, m)` and returns `None` to drop the coordinate
+/// or the (possibly modified) coordinate to keep. Points, linestrings, rings and
+/// multipoints are buffered and forwarded with the count of the remaining coordinates.
+/// A point without remaining coordinate is forwarded as empty point.
+/// The filter is not aware of geometry validity, e.g. rings may lose their closing point.
+pub struct CoordFilterProcessor<P: GeomProcessor, F> {
+    /// Maximal number of buffered coordinates, larger geometries are rejected
+    pub max_buffered_coords: Option<usize>,
+    inner: P,
+    filter: F,
+    buffer: Vec<(Coord, Option<f64>, Option<u64>)>,
+    buffering: bool,
+}
+
+impl<P: GeomProcessor, F> CoordFilterProcessor<P, F>
+where
+    F: FnMut(f64, f64, Option<f64>, Option<f64>) -> Option<Coord>,
+{
+    pub fn new(inner: P, filter: F) -> Self {
+        CoordFilterProcessor {
+            max_buffered_coords: None,
+            inner,
+            filter,
+            buffer: Vec::new(),
+            buffering: false,
+        }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    fn start(&mut self) {
+        self.buffering = true;
+        self.buffer.clear();
+    }
+
+    /// Forward the buffered coordinates.
+    fn flush(&mut self) -> Result<()> {
+        self.buffering = false;
+        let multi_dim = self.inner.multi_dim();
+        for (i, ((x, y, z, m), t, tm)) in self.buffer.drain(..).enumerate() {
+            if multi_dim {
+                self.inner.coordinate(x, y, z, m, t, tm, i)?;
+            } else {
+                self.inner.xy(x, y, i)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<P: GeomProcessor, F> GeomProcessor for CoordFilterProcessor<P, F>
+where
+    F: FnMut(f64, f64, Option<f64>, Option<f64>) -> Option<Coord>,
+{
+    fn multi_dim(&self) -> bool {
+        self.inner.multi_dim()
+    }
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        self.coordinate(x, y, None, None, None, None, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        let Some(coord) = (self.filter)(x, y, z, m) else {
+            return Ok(());
+        };
+        if self.buffering {
+            super::check_buffer_limit(self.buffer.len(), self.max_buffered_coords)?;
+            self.buffer.push((coord, t, tm));
+            Ok(())
+        } else {
+            // Coordinates of unbuffered geometries, e.g. circular strings
+            let (x, y, z, m) = coord;
+            if self.inner.multi_dim() {
+                self.inner.coordinate(x, y, z, m, t, tm, idx)
+            } else {
+                self.inner.xy(x, y, idx)
+            }
+        }
+    }
+    fn point_begin(&mut self, _idx: usize) -> Result<()> {
+        self.start();
+        Ok(())
+    }
+    fn point_end(&mut self, idx: usize) -> Result<()> {
+        if self.buffer.is_empty() {
+            self.buffering = false;
+            return self.inner.empty_point(idx);
+        }
+        self.inner.point_begin(idx)?;
+        self.flush()?;
+        self.inner.point_end(idx)
+    }
+    fn multipoint_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.start();
+        Ok(())
+    }
+    fn multipoint_end(&mut self, idx: usize) -> Result<()> {
+        self.inner.multipoint_begin(self.buffer.len(), idx)?;
+        self.flush()?;
+        self.inner.multipoint_end(idx)
+    }
+    fn linestring_begin(&mut self, _tagged: bool, _size: usize, _idx: usize) -> Result<()> {
+        self.start();
+        Ok(())
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.inner
+            .linestring_begin(tagged, self.buffer.len(), idx)?;
+        self.flush()?;
+        self.inner.linestring_end(tagged, idx)
+    }
+    forward_geom_processor!(inner;
+        dimensions, srid, empty_point,
+        multilinestring_begin, multilinestring_end, polygon_begin, polygon_end,
+        multipolygon_begin, multipolygon_end, geometrycollection_begin, geometrycollection_end,
+        circularstring_begin, circularstring_end, compoundcurve_begin, compoundcurve_end,
+        curvepolygon_begin, curvepolygon_end, multicurve_begin, multicurve_end,
+        multisurface_begin, multisurface_end, triangle_begin, triangle_end,
+        polyhedralsurface_begin, polyhedralsurface_end, tin_begin, tin_end,
+    );
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+
+    #[test]
+    fn drop_every_other() {
+        let wkt = process_wkt!("LINESTRING(0 0,1 1,2 2,3 3,4 4)", |w| {
+            let mut keep = false;
+            CoordFilterProcessor::new(w, move |x, y, z, m| {
+                keep = !keep;
+                keep.then_some((x, y, z, m))
+            })
+        });
+        assert_eq!(wkt, "LINESTRING(0 0,2 2,4 4)");
+    }
+
+    #[test]
+    fn replace_and_drop_point() {
+        let wkt = process_wkt!(
+            "GEOMETRYCOLLECTION(POINT(1 1),POINT(-1 1),MULTIPOINT(-2 0,3 0))",
+            |w| CoordFilterProcessor::new(w, |x, y, z, m| (x >= 0.0).then_some((x * 2.0, y, z, m)))
+        );
+        assert_eq!(
+            wkt,
+            "GEOMETRYCOLLECTION(POINT(2 1),POINT EMPTY,MULTIPOINT(6 0))"
+        );
+    }
+}
@@ -0,0 +1,86 @@
+use crate::error::Result;
+use crate::{CoordDimensions, GeomProcessor};
+
+/// Consumer of coordinates, ignoring the geometry structure.
+pub trait CoordSink {
+    fn coord(&mut self, x: f64, y: f64, z: Option<f64>, m: Option<f64>) -> Result<()>;
+}
+
+/// Forward all coordinates of processed geometries to a [CoordSink].
+pub struct CoordSinkProcessor<S: CoordSink> {
+    /// Requested coordinate dimensions
+    pub dims: CoordDimensions,
+    sink: S,
+}
+
+impl<S: CoordSink> CoordSinkProcessor<S> {
+    pub fn new(sink: S) -> Self {
+        CoordSinkProcessor {
+            dims: CoordDimensions::default(),
+            sink,
+        }
+    }
+
+    pub fn into_inner(self) -> S {
+        self.sink
+    }
+}
+
+impl<S: CoordSink> GeomProcessor for CoordSinkProcessor<S> {
+    fn dimensions(&self) -> CoordDimensions {
+        self.dims
+    }
+    fn xy(&mut self, x: f64, y: f64, _idx: usize) -> Result<()> {
+        self.sink.coord(x, y, None, None)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        _t: Option<f64>,
+        _tm: Option<u64>,
+        _idx: usize,
+    ) -> Result<()> {
+        self.sink.coord(x, y, z, m)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+    use crate::wkt::WktStr;
+    use crate::GeozeroGeometry;
+
+    struct PointCloud(Vec<[f64; 2]>);
+
+    impl CoordSink for PointCloud {
+        fn coord(&mut self, x: f64, y: f64, _z: Option<f64>, _m: Option<f64>) -> Result<()> {
+            self.0.push([x, y]);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn polygon_coords() {
+        let mut processor = CoordSinkProcessor::new(PointCloud(Vec::new()));
+        WktStr("POLYGON((0 0,2 0,2 2,0 0),(1 1,1.5 1,1.5 1.5,1 1))")
+            .process_geom(&mut processor)
+            .unwrap();
+        assert_eq!(
+            processor.into_inner().0,
+            vec![
+                [0.0, 0.0],
+                [2.0, 0.0],
+                [2.0, 2.0],
+                [0.0, 0.0],
+                [1.0, 1.0],
+                [1.5, 1.0],
+                [1.5, 1.5],
+                [1.0, 1.0]
+            ]
+        );
+    }
+}
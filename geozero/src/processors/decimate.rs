@@ -0,0 +1,145 @@
+use super::Coord;
+use crate::error::Result;
+use crate::GeomProcessor;
+
+/// Keep every `stride`th coordinate of linestrings and rings, e.g. for quick-look rendering.
+///
+/// Coordinates `0, stride, 2*stride, ...` and the last coordinate are kept, so endpoints
+/// and ring closure are preserved. Polygon rings with less than 4 remaining coordinates
+/// are forwarded unchanged. A `stride` of 0 or 1 keeps all coordinates.
+pub struct DecimateProcessor<P: GeomProcessor> {
+    /// Distance between kept coordinate indices
+    pub stride: usize,
+    /// Maximal number of buffered coordinates, larger linestrings are rejected
+    pub max_buffered_coords: Option<usize>,
+    inner: P,
+    buffer: Vec<Coord>,
+    buffering: bool,
+    in_polygon: bool,
+}
+
+impl<P: GeomProcessor> DecimateProcessor<P> {
+    pub fn new(inner: P, stride: usize) -> Self {
+        DecimateProcessor {
+            stride,
+            max_buffered_coords: None,
+            inner,
+            buffer: Vec::new(),
+            buffering: false,
+            in_polygon: false,
+        }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    fn decimate(&self, ring: bool) -> Vec<Coord> {
+        let stride = self.stride.max(1);
+        let last = self.buffer.len().saturating_sub(1);
+        let coords: Vec<Coord> = self
+            .buffer
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| i % stride == 0 || *i == last)
+            .map(|(_, c)| *c)
+            .collect();
+        if ring && coords.len() < 4 {
+            self.buffer.clone()
+        } else {
+            coords
+        }
+    }
+}
+
+impl<P: GeomProcessor> GeomProcessor for DecimateProcessor<P> {
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        self.coordinate(x, y, None, None, None, None, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        if self.buffering {
+            super::check_buffer_limit(self.buffer.len(), self.max_buffered_coords)?;
+            self.buffer.push((x, y, z, m));
+            Ok(())
+        } else if self.inner.multi_dim() {
+            self.inner.coordinate(x, y, z, m, t, tm, idx)
+        } else {
+            self.inner.xy(x, y, idx)
+        }
+    }
+    fn linestring_begin(&mut self, _tagged: bool, _size: usize, _idx: usize) -> Result<()> {
+        self.buffering = true;
+        self.buffer.clear();
+        Ok(())
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.buffering = false;
+        let coords = self.decimate(!tagged && self.in_polygon);
+        super::emit_line(&mut self.inner, &coords, tagged, idx)
+    }
+    fn polygon_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.in_polygon = true;
+        self.inner.polygon_begin(tagged, size, idx)
+    }
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.in_polygon = false;
+        self.inner.polygon_end(tagged, idx)
+    }
+    fn triangle_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.in_polygon = true;
+        self.inner.triangle_begin(tagged, size, idx)
+    }
+    fn triangle_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.in_polygon = false;
+        self.inner.triangle_end(tagged, idx)
+    }
+    forward_geom_processor!(inner;
+        dimensions, srid, empty_point, point_begin, point_end,
+        multipoint_begin, multipoint_end,
+        multilinestring_begin, multilinestring_end,
+        multipolygon_begin, multipolygon_end, geometrycollection_begin, geometrycollection_end,
+        circularstring_begin, circularstring_end, compoundcurve_begin, compoundcurve_end,
+        curvepolygon_begin, curvepolygon_end, multicurve_begin, multicurve_end,
+        multisurface_begin, multisurface_end,
+        polyhedralsurface_begin, polyhedralsurface_end, tin_begin, tin_end,
+    );
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+
+    fn decimate(wkt: &str, stride: usize) -> String {
+        process_wkt!(wkt, |w| DecimateProcessor::new(w, stride))
+    }
+
+    #[test]
+    fn stride() {
+        assert_eq!(
+            decimate("LINESTRING(0 0,1 0,2 0,3 0,4 0,5 0,6 0,7 0,8 0,9 0)", 3),
+            "LINESTRING(0 0,3 0,6 0,9 0)"
+        );
+        assert_eq!(
+            decimate("LINESTRING(0 0,1 0,2 0,3 0,4 0)", 3),
+            "LINESTRING(0 0,3 0,4 0)"
+        );
+        assert_eq!(
+            decimate("POLYGON((0 0,1 0,2 0,2 2,0 2,0 0))", 2),
+            "POLYGON((0 0,2 0,0 2,0 0))"
+        );
+        assert_eq!(
+            decimate("POLYGON((0 0,2 0,2 2,0 0))", 2),
+            "POLYGON((0 0,2 0,2 2,0 0))"
+        );
+    }
+}
@@ -0,0 +1,287 @@
+use super::Coord;
+use crate::error::Result;
+use crate::GeomProcessor;
+
+/// Insert coordinates into linestrings and rings so that no segment exceeds `max_length`.
+///
+/// Segments are split into equal parts, Z and M values are interpolated linearly.
+/// With `geodesic` set, coordinates are treated as lon/lat degrees on a sphere:
+/// points are interpolated along the great circle and `max_length` is the maximal
+/// central angle of a segment in degrees. Segments between antipodal points are kept.
+/// The number of inserted coordinates grows with `1 / max_length`, so untrusted lengths
+/// should be combined with `max_buffered_coords`.
+pub struct DensifyProcessor<P: GeomProcessor> {
+    /// Maximal segment length, `0` disables densification
+    pub max_length: f64,
+    /// Interpolate lon/lat coordinates along great circles
+    pub geodesic: bool,
+    /// Maximal number of buffered coordinates, including inserted coordinates,
+    /// larger linestrings are rejected
+    pub max_buffered_coords: Option<usize>,
+    inner: P,
+    buffer: Vec<Coord>,
+    buffering: bool,
+}
+
+impl<P: GeomProcessor> DensifyProcessor<P> {
+    pub fn new(inner: P, max_length: f64) -> Self {
+        DensifyProcessor {
+            max_length,
+            geodesic: false,
+            max_buffered_coords: None,
+            inner,
+            buffer: Vec::new(),
+            buffering: false,
+        }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    fn densify(&self) -> Result<Vec<Coord>> {
+        let limit = self.max_buffered_coords;
+        let mut coords = Vec::with_capacity(self.buffer.len());
+        for (i, &b) in self.buffer.iter().enumerate() {
+            if let Some(&a) = i.checked_sub(1).and_then(|prev| self.buffer.get(prev)) {
+                if self.geodesic {
+                    densify_geodesic(a, b, self.max_length, limit, &mut coords)?;
+                } else {
+                    densify_planar(a, b, self.max_length, limit, &mut coords)?;
+                }
+            }
+            super::check_buffer_limit(coords.len(), limit)?;
+            coords.push(b);
+        }
+        Ok(coords)
+    }
+}
+
+fn segments(length: f64, max_length: f64) -> usize {
+    if max_length > 0.0 && length > max_length {
+        (length / max_length).ceil() as usize
+    } else {
+        1
+    }
+}
+
+/// Push intermediate points of segment `a`-`b` (excluding both ends).
+fn densify_planar(
+    a: Coord,
+    b: Coord,
+    max_length: f64,
+    limit: Option<usize>,
+    coords: &mut Vec<Coord>,
+) -> Result<()> {
+    let n = segments((b.0 - a.0).hypot(b.1 - a.1), max_length);
+    for k in 1..n {
+        super::check_buffer_limit(coords.len(), limit)?;
+        let f = k as f64 / n as f64;
+        coords.push((
+            a.0 + (b.0 - a.0) * f,
+            a.1 + (b.1 - a.1) * f,
+            super::lerp(a.2, b.2, f),
+            super::lerp(a.3, b.3, f),
+        ));
+    }
+    Ok(())
+}
+
+fn to_vector(lon: f64, lat: f64) -> [f64; 3] {
+    let (lon, lat) = (lon.to_radians(), lat.to_radians());
+    [lat.cos() * lon.cos(), lat.cos() * lon.sin(), lat.sin()]
+}
+
+/// Push intermediate great circle points of segment `a`-`b` (excluding both ends).
+fn densify_geodesic(
+    a: Coord,
+    b: Coord,
+    max_length: f64,
+    limit: Option<usize>,
+    coords: &mut Vec<Coord>,
+) -> Result<()> {
+    let (va, vb) = (to_vector(a.0, a.1), to_vector(b.0, b.1));
+    let dot: f64 = va.iter().zip(&vb).map(|(p, q)| p * q).sum();
+    let cross = [
+        va[1] * vb[2] - va[2] * vb[1],
+        va[2] * vb[0] - va[0] * vb[2],
+        va[0] * vb[1] - va[1] * vb[0],
+    ];
+    let sin_angle = cross.iter().map(|c| c * c).sum::<f64>().sqrt();
+    let angle = sin_angle.atan2(dot);
+    if sin_angle < 1e-12 {
+        // identical or antipodal points, the great circle is not defined
+        return Ok(());
+    }
+    let n = segments(angle.to_degrees(), max_length);
+    for k in 1..n {
+        super::check_buffer_limit(coords.len(), limit)?;
+        let f = k as f64 / n as f64;
+        let (wa, wb) = (
+            ((1.0 - f) * angle).sin() / sin_angle,
+            (f * angle).sin() / sin_angle,
+        );
+        let v: Vec<f64> = va.iter().zip(&vb).map(|(p, q)| wa * p + wb * q).collect();
+        coords.push((
+            v[1].atan2(v[0]).to_degrees(),
+            v[2].atan2(v[0].hypot(v[1])).to_degrees(),
+            super::lerp(a.2, b.2, f),
+            super::lerp(a.3, b.3, f),
+        ));
+    }
+    Ok(())
+}
+
+impl<P: GeomProcessor> GeomProcessor for DensifyProcessor<P> {
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        self.coordinate(x, y, None, None, None, None, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        if self.buffering {
+            super::check_buffer_limit(self.buffer.len(), self.max_buffered_coords)?;
+            self.buffer.push((x, y, z, m));
+            Ok(())
+        } else if self.inner.multi_dim() {
+            self.inner.coordinate(x, y, z, m, t, tm, idx)
+        } else {
+            self.inner.xy(x, y, idx)
+        }
+    }
+    fn linestring_begin(&mut self, _tagged: bool, _size: usize, _idx: usize) -> Result<()> {
+        self.buffering = true;
+        self.buffer.clear();
+        Ok(())
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.buffering = false;
+        let coords = self.densify()?;
+        let multi_dim = self.inner.multi_dim();
+        self.inner.linestring_begin(tagged, coords.len(), idx)?;
+        for (i, (x, y, z, m)) in coords.into_iter().enumerate() {
+            if multi_dim {
+                self.inner.coordinate(x, y, z, m, None, None, i)?;
+            } else {
+                self.inner.xy(x, y, i)?;
+            }
+        }
+        self.inner.linestring_end(tagged, idx)
+    }
+    forward_geom_processor!(inner;
+        dimensions, srid, empty_point, point_begin, point_end,
+        multipoint_begin, multipoint_end,
+        multilinestring_begin, multilinestring_end, polygon_begin, polygon_end,
+        multipolygon_begin, multipolygon_end, geometrycollection_begin, geometrycollection_end,
+        circularstring_begin, circularstring_end, compoundcurve_begin, compoundcurve_end,
+        curvepolygon_begin, curvepolygon_end, multicurve_begin, multicurve_end,
+        multisurface_begin, multisurface_end, triangle_begin, triangle_end,
+        polyhedralsurface_begin, polyhedralsurface_end, tin_begin, tin_end,
+    );
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+    use crate::wkt::WktStr;
+    use crate::{GeozeroGeometry, ProcessorSink};
+
+    struct Line(Vec<(f64, f64)>);
+
+    impl GeomProcessor for Line {
+        fn xy(&mut self, x: f64, y: f64, _idx: usize) -> Result<()> {
+            self.0.push((x, y));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn planar() {
+        assert_eq!(
+            process_wkt!("POLYGON((0 0,6 0,6 1,0 0))", |w| DensifyProcessor::new(
+                w, 2.0
+            )),
+            "POLYGON((0 0,2 0,4 0,6 0,6 1,4.5 0.75,3 0.5,1.5 0.25,0 0))"
+        );
+    }
+
+    #[test]
+    fn buffer_limit() {
+        let mut processor = DensifyProcessor::new(ProcessorSink, 1.0);
+        processor.max_buffered_coords = Some(4);
+        let polygon = WktStr("POLYGON((0 0,1 0,1 1,0 1,0 0))");
+        assert_eq!(
+            polygon
+                .process_geom(&mut processor)
+                .unwrap_err()
+                .to_string(),
+            "processing geometry `geometry exceeds the limit of 4 buffered coordinates`"
+        );
+        processor.max_buffered_coords = Some(5);
+        assert!(polygon.process_geom(&mut processor).is_ok());
+
+        // inserted coordinates count against the limit
+        processor.max_length = 1e-300;
+        assert_eq!(
+            polygon
+                .process_geom(&mut processor)
+                .unwrap_err()
+                .to_string(),
+            "processing geometry `geometry exceeds the limit of 5 buffered coordinates`"
+        );
+        processor.geodesic = true;
+        assert!(polygon.process_geom(&mut processor).is_err());
+    }
+
+    #[test]
+    fn great_circle() {
+        let mut processor = DensifyProcessor::new(Line(Vec::new()), 10.0);
+        processor.geodesic = true;
+        WktStr("LINESTRING(0 0,90 60)")
+            .process_geom(&mut processor)
+            .unwrap();
+        let line = processor.into_inner().0;
+        // central angle of 90°
+        assert_eq!(line.len(), 10);
+        assert_eq!(line[0], (0.0, 0.0));
+        assert_eq!(line[9], (90.0, 60.0));
+        let (a, b) = (to_vector(0.0, 0.0), to_vector(90.0, 60.0));
+        let normal = [
+            a[1] * b[2] - a[2] * b[1],
+            a[2] * b[0] - a[0] * b[2],
+            a[0] * b[1] - a[1] * b[0],
+        ];
+        for &(lon, lat) in &line[1..9] {
+            let v = to_vector(lon, lat);
+            let dist: f64 = v.iter().zip(&normal).map(|(p, q)| p * q).sum();
+            assert!(dist.abs() < 1e-12, "({lon} {lat}) off the great circle");
+        }
+        // great circle arcs bend towards the pole
+        assert!(line[5].1 > 60.0 * line[5].0 / 90.0 + 1.0);
+
+        // equator to pole along the meridian
+        let mut processor = DensifyProcessor::new(Line(Vec::new()), 10.0);
+        processor.geodesic = true;
+        WktStr("LINESTRING(30 0,30 90)")
+            .process_geom(&mut processor)
+            .unwrap();
+        let line = processor.into_inner().0;
+        assert_eq!(line.len(), 10);
+        assert_eq!(line[9], (30.0, 90.0));
+        for (k, &(lon, lat)) in line[..9].iter().enumerate() {
+            assert!((lon - 30.0).abs() < 1e-9, "({lon} {lat}) off the meridian");
+            assert!(
+                (lat - 10.0 * k as f64).abs() < 1e-9,
+                "({lon} {lat}) not equidistant"
+            );
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use crate::error::Result;
+use crate::{CoordDimensions, GeomProcessor};
+use std::collections::BTreeMap;
+
+/// Geometry types for routing top-level geometries in [DispatchProcessor].
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum GeometryType {
+    Point,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+    CircularString,
+    CompoundCurve,
+    CurvePolygon,
+    MultiCurve,
+    MultiSurface,
+    Triangle,
+    PolyhedralSurface,
+    Tin,
+}
+
+/// Route top-level geometries to registered processors by geometry type.
+///
+/// Nested geometries go to the processor of their top-level geometry and geometries
+/// of types without registered processor are dropped. The SRID is forwarded to the
+/// processor of the following geometry.
+#[derive(Default)]
+pub struct DispatchProcessor<'a> {
+    handlers: Vec<&'a mut dyn GeomProcessor>,
+    routes: BTreeMap<GeometryType, usize>,
+    active: Option<usize>,
+    depth: usize,
+    srid: Option<Option<i32>>,
+}
+
+impl<'a> DispatchProcessor<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Route geometries of the given types to `handler`, replacing previous routes of these types.
+    pub fn register(&mut self, types: &[GeometryType], handler: &'a mut dyn GeomProcessor) {
+        let idx = self.handlers.len();
+        self.handlers.push(handler);
+        for geometry_type in types {
+            self.routes.insert(*geometry_type, idx);
+        }
+    }
+
+    fn handler(&mut self) -> Option<&mut (dyn GeomProcessor + 'a)> {
+        match self.active {
+            Some(idx) => Some(&mut *self.handlers[idx]),
+            None => None,
+        }
+    }
+
+    fn begin(
+        &mut self,
+        geometry_type: GeometryType,
+    ) -> Result<Option<&mut (dyn GeomProcessor + 'a)>> {
+        if self.depth == 0 {
+            self.active = self.routes.get(&geometry_type).copied();
+            if let Some(srid) = self.srid.take() {
+                if let Some(handler) = self.handler() {
+                    handler.srid(srid)?;
+                }
+            }
+        }
+        self.depth += 1;
+        Ok(self.handler())
+    }
+
+    fn end(&mut self) {
+        self.depth -= 1;
+        if self.depth == 0 {
+            self.active = None;
+        }
+    }
+}
+
+macro_rules! dispatch_nesting {
+    ($($begin:ident, $end:ident => $geometry_type:ident;)*) => {
+        $(
+            fn $begin(&mut self, size: usize, idx: usize) -> Result<()> {
+                if let Some(handler) = self.begin(GeometryType::$geometry_type)? {
+                    handler.$begin(size, idx)?;
+                }
+                Ok(())
+            }
+            fn $end(&mut self, idx: usize) -> Result<()> {
+                if let Some(handler) = self.handler() {
+                    handler.$end(idx)?;
+                }
+                self.end();
+                Ok(())
+            }
+        )*
+    };
+}
+
+macro_rules! dispatch_tagged_nesting {
+    ($($begin:ident, $end:ident => $geometry_type:ident;)*) => {
+        $(
+            fn $begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+                if let Some(handler) = self.begin(GeometryType::$geometry_type)? {
+                    handler.$begin(tagged, size, idx)?;
+                }
+                Ok(())
+            }
+            fn $end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+                if let Some(handler) = self.handler() {
+                    handler.$end(tagged, idx)?;
+                }
+                self.end();
+                Ok(())
+            }
+        )*
+    };
+}
+
+impl GeomProcessor for DispatchProcessor<'_> {
+    fn dimensions(&self) -> CoordDimensions {
+        self.handlers
+            .iter()
+            .map(|handler| handler.dimensions())
+            .fold(CoordDimensions::xy(), |dims, other| CoordDimensions {
+                z: dims.z || other.z,
+                m: dims.m || other.m,
+                t: dims.t || other.t,
+                tm: dims.tm || other.tm,
+            })
+    }
+    fn srid(&mut self, srid: Option<i32>) -> Result<()> {
+        match self.handler() {
+            Some(handler) => handler.srid(srid),
+            None => {
+                self.srid = Some(srid);
+                Ok(())
+            }
+        }
+    }
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        match self.handler() {
+            Some(handler) => handler.xy(x, y, idx),
+            None => Ok(()),
+        }
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        match self.handler() {
+            Some(handler) if handler.multi_dim() => handler.coordinate(x, y, z, m, t, tm, idx),
+            Some(handler) => handler.xy(x, y, idx),
+            None => Ok(()),
+        }
+    }
+    fn empty_point(&mut self, idx: usize) -> Result<()> {
+        if let Some(handler) = self.begin(GeometryType::Point)? {
+            handler.empty_point(idx)?;
+        }
+        self.end();
+        Ok(())
+    }
+    fn point_begin(&mut self, idx: usize) -> Result<()> {
+        if let Some(handler) = self.begin(GeometryType::Point)? {
+            handler.point_begin(idx)?;
+        }
+        Ok(())
+    }
+    fn point_end(&mut self, idx: usize) -> Result<()> {
+        if let Some(handler) = self.handler() {
+            handler.point_end(idx)?;
+        }
+        self.end();
+        Ok(())
+    }
+    dispatch_tagged_nesting! {
+        linestring_begin, linestring_end => LineString;
+        polygon_begin, polygon_end => Polygon;
+        triangle_begin, triangle_end => Triangle;
+    }
+    dispatch_nesting! {
+        multipoint_begin, multipoint_end => MultiPoint;
+        multilinestring_begin, multilinestring_end => MultiLineString;
+        multipolygon_begin, multipolygon_end => MultiPolygon;
+        geometrycollection_begin, geometrycollection_end => GeometryCollection;
+        circularstring_begin, circularstring_end => CircularString;
+        compoundcurve_begin, compoundcurve_end => CompoundCurve;
+        curvepolygon_begin, curvepolygon_end => CurvePolygon;
+        multicurve_begin, multicurve_end => MultiCurve;
+        multisurface_begin, multisurface_end => MultiSurface;
+        polyhedralsurface_begin, polyhedralsurface_end => PolyhedralSurface;
+        tin_begin, tin_end => Tin;
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+    use crate::wkt::{WktStr, WktWriter};
+    use crate::GeozeroGeometry;
+
+    #[test]
+    fn route_by_type() {
+        let mut points_out: Vec<u8> = Vec::new();
+        let mut polygons_out: Vec<u8> = Vec::new();
+        let mut points = WktWriter::new(&mut points_out);
+        points.record_terminator = Some("\n".to_string());
+        let mut polygons = WktWriter::new(&mut polygons_out);
+        polygons.record_terminator = Some("\n".to_string());
+
+        let mut dispatcher = DispatchProcessor::new();
+        dispatcher.register(&[GeometryType::Point], &mut points);
+        dispatcher.register(&[GeometryType::Polygon], &mut polygons);
+        for wkt in [
+            "POINT(1 2)",
+            "POLYGON((0 0,1 0,0 1,0 0))",
+            "LINESTRING(0 0,1 1)",
+            "POINT(3 4)",
+        ] {
+            WktStr(wkt).process_geom(&mut dispatcher).unwrap();
+        }
+        drop(dispatcher);
+        drop(points);
+        drop(polygons);
+        assert_eq!(
+            std::str::from_utf8(&points_out).unwrap(),
+            "POINT(1 2)\nPOINT(3 4)\n"
+        );
+        assert_eq!(
+            std::str::from_utf8(&polygons_out).unwrap(),
+            "POLYGON((0 0,1 0,0 1,0 0))\n"
+        );
+    }
+}
@@ -0,0 +1,96 @@
+use crate::error::Result;
+use crate::{CoordDimensions, GeomProcessor};
+
+/// Count Z and M values dropped by an inner processor without these dimensions.
+///
+/// Requests Z and M from the reader and forwards coordinates according to the
+/// dimensions of the inner processor, e.g. for audit logging of ETL jobs writing
+/// ZM input into a 2D format. NaN values are counted as dropped values as well.
+pub struct DroppedDimsProcessor<P: GeomProcessor> {
+    /// Number of Z values not forwarded
+    pub dropped_z: usize,
+    /// Number of M values not forwarded
+    pub dropped_m: usize,
+    inner: P,
+}
+
+impl<P: GeomProcessor> DroppedDimsProcessor<P> {
+    pub fn new(inner: P) -> Self {
+        DroppedDimsProcessor {
+            dropped_z: 0,
+            dropped_m: 0,
+            inner,
+        }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+}
+
+impl<P: GeomProcessor> GeomProcessor for DroppedDimsProcessor<P> {
+    fn dimensions(&self) -> CoordDimensions {
+        CoordDimensions {
+            z: true,
+            m: true,
+            ..self.inner.dimensions()
+        }
+    }
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        self.inner.xy(x, y, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        let dims = self.inner.dimensions();
+        if z.is_some() && !dims.z {
+            self.dropped_z += 1;
+        }
+        if m.is_some() && !dims.m {
+            self.dropped_m += 1;
+        }
+        if self.inner.multi_dim() {
+            self.inner.coordinate(x, y, z, m, t, tm, idx)
+        } else {
+            self.inner.xy(x, y, idx)
+        }
+    }
+    forward_geom_processor!(inner;
+        srid, empty_point, point_begin, point_end,
+        multipoint_begin, multipoint_end, linestring_begin, linestring_end,
+        multilinestring_begin, multilinestring_end, polygon_begin, polygon_end,
+        multipolygon_begin, multipolygon_end, geometrycollection_begin, geometrycollection_end,
+        circularstring_begin, circularstring_end, compoundcurve_begin, compoundcurve_end,
+        curvepolygon_begin, curvepolygon_end, multicurve_begin, multicurve_end,
+        multisurface_begin, multisurface_end, triangle_begin, triangle_end,
+        polyhedralsurface_begin, polyhedralsurface_end, tin_begin, tin_end,
+    );
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkb")]
+mod test {
+    use super::*;
+    use crate::wkb::Ewkb;
+    use crate::wkt::WktWriter;
+    use crate::GeozeroGeometry;
+
+    #[test]
+    fn zm_to_2d() {
+        let mut out: Vec<u8> = Vec::new();
+        let mut processor = DroppedDimsProcessor::new(WktWriter::new(&mut out));
+        // LINESTRING ZM (1 2 3 4,5 6 7 8)
+        Ewkb(hex::decode("01020000C002000000000000000000F03F000000000000004000000000000008400000000000001040000000000000144000000000000018400000000000001C400000000000002040").unwrap())
+            .process_geom(&mut processor)
+            .unwrap();
+        assert_eq!((processor.dropped_z, processor.dropped_m), (2, 2));
+        assert_eq!(String::from_utf8(out).unwrap(), "LINESTRING(1 2,5 6)");
+    }
+}
@@ -0,0 +1,96 @@
+use crate::algorithm::extend_bbox;
+use crate::error::Result;
+use crate::GeomProcessor;
+
+/// Replace each geometry with its bounding box as rectangle polygon.
+///
+/// The bounds are only known when a geometry ends, so the polygon
+/// `(minx miny, maxx miny, maxx maxy, minx maxy, minx miny)` is forwarded
+/// to the inner processor afterwards. Empty geometries become empty polygons.
+pub struct EnvelopePolyProcessor<P: GeomProcessor> {
+    inner: P,
+    depth: usize,
+    idx: usize,
+    bounds: Option<[f64; 4]>,
+}
+
+impl<P: GeomProcessor> EnvelopePolyProcessor<P> {
+    pub fn new(inner: P) -> Self {
+        EnvelopePolyProcessor {
+            inner,
+            depth: 0,
+            idx: 0,
+            bounds: None,
+        }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    fn begin(&mut self, idx: usize) -> Result<()> {
+        if self.depth == 0 {
+            self.idx = idx;
+            self.bounds = None;
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    fn end(&mut self, _idx: usize) -> Result<()> {
+        self.depth -= 1;
+        if self.depth > 0 {
+            return Ok(());
+        }
+        let Some([minx, miny, maxx, maxy]) = self.bounds.take() else {
+            self.inner.polygon_begin(true, 0, self.idx)?;
+            return self.inner.polygon_end(true, self.idx);
+        };
+        self.inner.polygon_begin(true, 1, self.idx)?;
+        self.inner.linestring_begin(false, 5, 0)?;
+        let ring = [
+            (minx, miny),
+            (maxx, miny),
+            (maxx, maxy),
+            (minx, maxy),
+            (minx, miny),
+        ];
+        for (i, (x, y)) in ring.into_iter().enumerate() {
+            self.inner.xy(x, y, i)?;
+        }
+        self.inner.linestring_end(false, 0)?;
+        self.inner.polygon_end(true, self.idx)
+    }
+}
+
+impl<P: GeomProcessor> GeomProcessor for EnvelopePolyProcessor<P> {
+    fn srid(&mut self, srid: Option<i32>) -> Result<()> {
+        self.inner.srid(srid)
+    }
+    fn xy(&mut self, x: f64, y: f64, _idx: usize) -> Result<()> {
+        extend_bbox(&mut self.bounds, x, y);
+        Ok(())
+    }
+    fn empty_point(&mut self, idx: usize) -> Result<()> {
+        if self.depth == 0 {
+            self.begin(idx)?;
+            self.end(idx)?;
+        }
+        Ok(())
+    }
+    nesting_geom_processor!(begin, end);
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+
+    #[test]
+    fn multipoint_bbox() {
+        assert_eq!(
+            process_wkt!("MULTIPOINT(1 5,3 -2,-4 0)", EnvelopePolyProcessor::new),
+            "POLYGON((-4 -2,3 -2,3 5,-4 5,-4 -2))"
+        );
+    }
+}
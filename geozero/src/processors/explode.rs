@@ -0,0 +1,332 @@
+use crate::error::Result;
+use crate::{CoordDimensions, GeomProcessor};
+
+/// Explode multi geometries and collections into their components.
+///
+/// The members of a top-level multi geometry or geometry collection are forwarded to the
+/// inner processor as standalone, tagged geometries with index 0. Points of a MultiPoint
+/// are forwarded as individual Points. Nested geometries are forwarded unchanged.
+pub struct ExplodeProcessor<P: GeomProcessor> {
+    inner: P,
+    depth: usize,
+    exploding: bool,
+    multipoint: bool,
+}
+
+impl<P: GeomProcessor> ExplodeProcessor<P> {
+    pub fn new(inner: P) -> Self {
+        ExplodeProcessor {
+            inner,
+            depth: 0,
+            exploding: false,
+            multipoint: false,
+        }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    fn is_member(&self) -> bool {
+        self.exploding && self.depth == 1
+    }
+
+    /// Enter a geometry. Returns whether it is an exploded member and the index to forward.
+    fn enter(&mut self, idx: usize) -> (bool, usize) {
+        let member = self.is_member();
+        self.depth += 1;
+        (member, if member { 0 } else { idx })
+    }
+
+    /// Enter a multi geometry or collection. Returns `None` if it is exploded.
+    fn enter_multi(&mut self, idx: usize) -> Option<usize> {
+        if self.depth == 0 {
+            self.exploding = true;
+            self.depth = 1;
+            None
+        } else {
+            Some(self.enter(idx).1)
+        }
+    }
+
+    /// Leave a geometry. Returns `None` for an exploded container.
+    fn leave(&mut self, idx: usize) -> Option<(bool, usize)> {
+        self.depth -= 1;
+        if self.exploding && self.depth == 0 {
+            self.exploding = false;
+            self.multipoint = false;
+            None
+        } else if self.is_member() {
+            Some((true, 0))
+        } else {
+            Some((false, idx))
+        }
+    }
+}
+
+impl<P: GeomProcessor> GeomProcessor for ExplodeProcessor<P> {
+    fn dimensions(&self) -> CoordDimensions {
+        self.inner.dimensions()
+    }
+    fn srid(&mut self, srid: Option<i32>) -> Result<()> {
+        self.inner.srid(srid)
+    }
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        if self.multipoint && self.depth == 1 {
+            self.inner.point_begin(0)?;
+            self.inner.xy(x, y, 0)?;
+            self.inner.point_end(0)
+        } else {
+            self.inner.xy(x, y, idx)
+        }
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        if self.multipoint && self.depth == 1 {
+            self.inner.point_begin(0)?;
+            self.inner.coordinate(x, y, z, m, t, tm, 0)?;
+            self.inner.point_end(0)
+        } else {
+            self.inner.coordinate(x, y, z, m, t, tm, idx)
+        }
+    }
+    fn empty_point(&mut self, idx: usize) -> Result<()> {
+        let idx = if self.is_member() { 0 } else { idx };
+        self.inner.empty_point(idx)
+    }
+    fn point_begin(&mut self, idx: usize) -> Result<()> {
+        let (_, idx) = self.enter(idx);
+        self.inner.point_begin(idx)
+    }
+    fn point_end(&mut self, idx: usize) -> Result<()> {
+        match self.leave(idx) {
+            Some((_, idx)) => self.inner.point_end(idx),
+            None => Ok(()),
+        }
+    }
+    fn multipoint_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        match self.enter_multi(idx) {
+            Some(idx) => self.inner.multipoint_begin(size, idx),
+            None => {
+                self.multipoint = true;
+                Ok(())
+            }
+        }
+    }
+    fn multipoint_end(&mut self, idx: usize) -> Result<()> {
+        match self.leave(idx) {
+            Some((_, idx)) => self.inner.multipoint_end(idx),
+            None => Ok(()),
+        }
+    }
+    fn linestring_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        let (member, idx) = self.enter(idx);
+        self.inner.linestring_begin(tagged || member, size, idx)
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        match self.leave(idx) {
+            Some((member, idx)) => self.inner.linestring_end(tagged || member, idx),
+            None => Ok(()),
+        }
+    }
+    fn multilinestring_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        match self.enter_multi(idx) {
+            Some(idx) => self.inner.multilinestring_begin(size, idx),
+            None => Ok(()),
+        }
+    }
+    fn multilinestring_end(&mut self, idx: usize) -> Result<()> {
+        match self.leave(idx) {
+            Some((_, idx)) => self.inner.multilinestring_end(idx),
+            None => Ok(()),
+        }
+    }
+    fn polygon_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        let (member, idx) = self.enter(idx);
+        self.inner.polygon_begin(tagged || member, size, idx)
+    }
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        match self.leave(idx) {
+            Some((member, idx)) => self.inner.polygon_end(tagged || member, idx),
+            None => Ok(()),
+        }
+    }
+    fn multipolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        match self.enter_multi(idx) {
+            Some(idx) => self.inner.multipolygon_begin(size, idx),
+            None => Ok(()),
+        }
+    }
+    fn multipolygon_end(&mut self, idx: usize) -> Result<()> {
+        match self.leave(idx) {
+            Some((_, idx)) => self.inner.multipolygon_end(idx),
+            None => Ok(()),
+        }
+    }
+    fn geometrycollection_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        match self.enter_multi(idx) {
+            Some(idx) => self.inner.geometrycollection_begin(size, idx),
+            None => Ok(()),
+        }
+    }
+    fn geometrycollection_end(&mut self, idx: usize) -> Result<()> {
+        match self.leave(idx) {
+            Some((_, idx)) => self.inner.geometrycollection_end(idx),
+            None => Ok(()),
+        }
+    }
+    fn circularstring_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        let (_, idx) = self.enter(idx);
+        self.inner.circularstring_begin(size, idx)
+    }
+    fn circularstring_end(&mut self, idx: usize) -> Result<()> {
+        match self.leave(idx) {
+            Some((_, idx)) => self.inner.circularstring_end(idx),
+            None => Ok(()),
+        }
+    }
+    fn compoundcurve_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        let (_, idx) = self.enter(idx);
+        self.inner.compoundcurve_begin(size, idx)
+    }
+    fn compoundcurve_end(&mut self, idx: usize) -> Result<()> {
+        match self.leave(idx) {
+            Some((_, idx)) => self.inner.compoundcurve_end(idx),
+            None => Ok(()),
+        }
+    }
+    fn curvepolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        let (_, idx) = self.enter(idx);
+        self.inner.curvepolygon_begin(size, idx)
+    }
+    fn curvepolygon_end(&mut self, idx: usize) -> Result<()> {
+        match self.leave(idx) {
+            Some((_, idx)) => self.inner.curvepolygon_end(idx),
+            None => Ok(()),
+        }
+    }
+    fn multicurve_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        match self.enter_multi(idx) {
+            Some(idx) => self.inner.multicurve_begin(size, idx),
+            None => Ok(()),
+        }
+    }
+    fn multicurve_end(&mut self, idx: usize) -> Result<()> {
+        match self.leave(idx) {
+            Some((_, idx)) => self.inner.multicurve_end(idx),
+            None => Ok(()),
+        }
+    }
+    fn multisurface_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        match self.enter_multi(idx) {
+            Some(idx) => self.inner.multisurface_begin(size, idx),
+            None => Ok(()),
+        }
+    }
+    fn multisurface_end(&mut self, idx: usize) -> Result<()> {
+        match self.leave(idx) {
+            Some((_, idx)) => self.inner.multisurface_end(idx),
+            None => Ok(()),
+        }
+    }
+    fn triangle_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        let (member, idx) = self.enter(idx);
+        self.inner.triangle_begin(tagged || member, size, idx)
+    }
+    fn triangle_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        match self.leave(idx) {
+            Some((member, idx)) => self.inner.triangle_end(tagged || member, idx),
+            None => Ok(()),
+        }
+    }
+    fn polyhedralsurface_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        let (_, idx) = self.enter(idx);
+        self.inner.polyhedralsurface_begin(size, idx)
+    }
+    fn polyhedralsurface_end(&mut self, idx: usize) -> Result<()> {
+        match self.leave(idx) {
+            Some((_, idx)) => self.inner.polyhedralsurface_end(idx),
+            None => Ok(()),
+        }
+    }
+    fn tin_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        let (_, idx) = self.enter(idx);
+        self.inner.tin_begin(size, idx)
+    }
+    fn tin_end(&mut self, idx: usize) -> Result<()> {
+        match self.leave(idx) {
+            Some((_, idx)) => self.inner.tin_end(idx),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+    use crate::wkt::WktStr;
+    use crate::GeozeroGeometry;
+
+    #[derive(Default)]
+    struct GeomCounter {
+        polygons: usize,
+        multipolygons: usize,
+    }
+
+    impl GeomProcessor for GeomCounter {
+        fn polygon_begin(&mut self, tagged: bool, _size: usize, _idx: usize) -> Result<()> {
+            if tagged {
+                self.polygons += 1;
+            }
+            Ok(())
+        }
+        fn multipolygon_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+            self.multipolygons += 1;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn explode_multipolygon() {
+        let mut processor = ExplodeProcessor::new(GeomCounter::default());
+        WktStr("MULTIPOLYGON(((0 0,2 0,2 2,0 0)),((10 10,12 10,12 12,10 10)))")
+            .process_geom(&mut processor)
+            .unwrap();
+        let counter = processor.into_inner();
+        assert_eq!(counter.polygons, 2);
+        assert_eq!(counter.multipolygons, 0);
+    }
+
+    fn explode_to_wkt(wkt: &str) -> String {
+        process_wkt!(wkt, ExplodeProcessor::new)
+    }
+
+    #[test]
+    fn explode_geometries() {
+        assert_eq!(
+            explode_to_wkt("MULTIPOINT(1 2,3 4)"),
+            "POINT(1 2)POINT(3 4)"
+        );
+        assert_eq!(
+            explode_to_wkt("MULTILINESTRING((0 0,1 1),(2 2,3 3))"),
+            "LINESTRING(0 0,1 1)LINESTRING(2 2,3 3)"
+        );
+        assert_eq!(
+            explode_to_wkt("GEOMETRYCOLLECTION(POINT(1 2),MULTIPOINT(3 4,5 6))"),
+            "POINT(1 2)MULTIPOINT(3 4,5 6)"
+        );
+        assert_eq!(
+            explode_to_wkt("POLYGON((0 0,2 0,2 2,0 0))"),
+            "POLYGON((0 0,2 0,2 2,0 0))"
+        );
+    }
+}
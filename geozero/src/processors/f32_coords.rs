@@ -0,0 +1,114 @@
+use crate::error::Result;
+use crate::GeomProcessor;
+
+/// Geometry processor receiving `f32` coordinates, e.g. for GPU upload.
+///
+/// Geometry structure is reported by the [GeomProcessor] callbacks, its `f64`
+/// coordinate callbacks are not called when used with [F32Processor].
+pub trait GeomProcessorF32: GeomProcessor {
+    fn xy_f32(&mut self, _x: f32, _y: f32, _idx: usize) -> Result<()> {
+        Ok(())
+    }
+    fn coordinate_f32(
+        &mut self,
+        _x: f32,
+        _y: f32,
+        _z: Option<f32>,
+        _m: Option<f32>,
+        _idx: usize,
+    ) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Narrow coordinates to `f32` and forward them to a [GeomProcessorF32].
+pub struct F32Processor<P: GeomProcessorF32> {
+    inner: P,
+}
+
+impl<P: GeomProcessorF32> F32Processor<P> {
+    pub fn new(inner: P) -> Self {
+        F32Processor { inner }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+}
+
+impl<P: GeomProcessorF32> GeomProcessor for F32Processor<P> {
+    fn multi_dim(&self) -> bool {
+        self.inner.multi_dim()
+    }
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        self.inner.xy_f32(x as f32, y as f32, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        _t: Option<f64>,
+        _tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        self.inner.coordinate_f32(
+            x as f32,
+            y as f32,
+            z.map(|z| z as f32),
+            m.map(|m| m as f32),
+            idx,
+        )
+    }
+    forward_geom_processor!(inner;
+        dimensions, srid, empty_point, point_begin, point_end,
+        multipoint_begin, multipoint_end, linestring_begin, linestring_end,
+        multilinestring_begin, multilinestring_end, polygon_begin, polygon_end,
+        multipolygon_begin, multipolygon_end, geometrycollection_begin, geometrycollection_end,
+        circularstring_begin, circularstring_end, compoundcurve_begin, compoundcurve_end,
+        curvepolygon_begin, curvepolygon_end, multicurve_begin, multicurve_end,
+        multisurface_begin, multisurface_end, triangle_begin, triangle_end,
+        polyhedralsurface_begin, polyhedralsurface_end, tin_begin, tin_end,
+    );
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+    use crate::wkt::WktStr;
+    use crate::GeozeroGeometry;
+
+    #[derive(Default)]
+    struct Rings(Vec<Vec<[f32; 2]>>);
+
+    impl GeomProcessor for Rings {
+        fn linestring_begin(&mut self, _tagged: bool, size: usize, _idx: usize) -> Result<()> {
+            self.0.push(Vec::with_capacity(size));
+            Ok(())
+        }
+    }
+
+    impl GeomProcessorF32 for Rings {
+        fn xy_f32(&mut self, x: f32, y: f32, _idx: usize) -> Result<()> {
+            self.0.last_mut().unwrap().push([x, y]);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn polygon_f32() {
+        let mut processor = F32Processor::new(Rings::default());
+        WktStr("POLYGON((0 0,2 0,2 2,0 0),(1 1,1.5 1,1.5 1.1,1 1))")
+            .process_geom(&mut processor)
+            .unwrap();
+        assert_eq!(
+            processor.into_inner().0,
+            vec![
+                vec![[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 0.0]],
+                vec![[1.0, 1.0], [1.5, 1.0], [1.5, 1.1], [1.0, 1.0]],
+            ]
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use super::VertexCentroid;
+use crate::error::Result;
+use crate::GeomProcessor;
+
+const BASE32: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// Encode a lon/lat position as geohash with `precision` characters.
+pub fn encode_geohash(lon: f64, lat: f64, precision: usize) -> String {
+    let mut lon_range = (-180.0, 180.0);
+    let mut lat_range = (-90.0, 90.0);
+    let mut hash = String::with_capacity(precision);
+    let mut even = true;
+    let mut bits = 0;
+    let mut ch = 0;
+    while hash.len() < precision {
+        let (range, value) = if even {
+            (&mut lon_range, lon)
+        } else {
+            (&mut lat_range, lat)
+        };
+        let mid = (range.0 + range.1) / 2.0;
+        ch <<= 1;
+        if value >= mid {
+            ch |= 1;
+            range.0 = mid;
+        } else {
+            range.1 = mid;
+        }
+        even = !even;
+        bits += 1;
+        if bits == 5 {
+            hash.push(BASE32[ch] as char);
+            bits = 0;
+            ch = 0;
+        }
+    }
+    hash
+}
+
+/// Compute a geohash for each processed geometry.
+///
+/// Points are encoded at their position, other geometries at the centroid of their
+/// vertices. Empty geometries yield `None`, NaN coordinates (e.g. WKB `POINT EMPTY`)
+/// are ignored. Coordinates are expected as lon/lat.
+pub struct GeohashProcessor {
+    /// Number of geohash characters
+    pub precision: usize,
+    /// Geohashes of the processed geometries
+    pub hashes: Vec<Option<String>>,
+    centroid: VertexCentroid,
+}
+
+impl GeohashProcessor {
+    pub fn new(precision: usize) -> Self {
+        GeohashProcessor {
+            precision,
+            hashes: Vec::new(),
+            centroid: VertexCentroid::default(),
+        }
+    }
+
+    fn begin(&mut self, _idx: usize) -> Result<()> {
+        self.centroid.begin();
+        Ok(())
+    }
+
+    fn end(&mut self, _idx: usize) -> Result<()> {
+        if let Some(centroid) = self.centroid.end() {
+            let hash = centroid.map(|(x, y)| encode_geohash(x, y, self.precision));
+            self.hashes.push(hash);
+        }
+        Ok(())
+    }
+}
+
+impl GeomProcessor for GeohashProcessor {
+    fn xy(&mut self, x: f64, y: f64, _idx: usize) -> Result<()> {
+        self.centroid.add(x, y);
+        Ok(())
+    }
+    fn empty_point(&mut self, _idx: usize) -> Result<()> {
+        if self.centroid.is_top_level() {
+            self.hashes.push(None);
+        }
+        Ok(())
+    }
+    nesting_geom_processor!(begin, end);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn known_geohash() {
+        assert_eq!(encode_geohash(10.40744, 57.64911, 11), "u4pruydqqvj");
+        assert_eq!(encode_geohash(-5.6, 42.6, 5), "ezs42");
+    }
+
+    #[test]
+    #[cfg(feature = "with-wkt")]
+    fn geometry_hashes() {
+        use crate::wkt::WktStr;
+        use crate::GeozeroGeometry;
+
+        let mut processor = GeohashProcessor::new(5);
+        WktStr("POINT(-5.6 42.6)")
+            .process_geom(&mut processor)
+            .unwrap();
+        WktStr("LINESTRING(-5.7 42.5,-5.5 42.7)")
+            .process_geom(&mut processor)
+            .unwrap();
+        WktStr("POINT EMPTY").process_geom(&mut processor).unwrap();
+        assert_eq!(
+            processor.hashes,
+            vec![Some("ezs42".to_string()), Some("ezs42".to_string()), None]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "with-wkb")]
+    fn wkb_empty_point() {
+        use crate::wkb::process_wkb_geom;
+
+        // POINT EMPTY
+        let wkb = hex::decode("0101000000000000000000F87F000000000000F87F").unwrap();
+        let mut processor = GeohashProcessor::new(5);
+        process_wkb_geom(&mut wkb.as_slice(), &mut processor).unwrap();
+        assert_eq!(processor.hashes, vec![None]);
+    }
+}
@@ -0,0 +1,261 @@
+use crate::error::Result;
+use crate::{CoordDimensions, GeomProcessor};
+
+/// Compute a hash of the geometry structure and coordinates, e.g. for deduplication.
+///
+/// Geometry types, sizes and coordinate values are hashed in processing order,
+/// so the hash does not depend on the encoding of the input (e.g. WKB byte order).
+/// The 64-bit FNV-1a hash of little-endian values is used, so hashes are stable
+/// across platforms and Rust releases and can be persisted.
+pub struct GeomHasher {
+    hasher: Fnv1a,
+}
+
+/// 64-bit FNV-1a hash
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(Self::PRIME);
+        }
+    }
+
+    fn write_u8(&mut self, value: u8) {
+        self.write(&[value]);
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.write(&value.to_le_bytes());
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Tag {
+    Coord,
+    EmptyPoint,
+    Point,
+    LineString,
+    Polygon,
+    Triangle,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+    CircularString,
+    CompoundCurve,
+    CurvePolygon,
+    MultiCurve,
+    MultiSurface,
+    PolyhedralSurface,
+    Tin,
+    End,
+}
+
+impl GeomHasher {
+    pub fn new() -> Self {
+        GeomHasher {
+            hasher: Fnv1a(Fnv1a::OFFSET_BASIS),
+        }
+    }
+
+    /// Hash of the geometries processed so far.
+    pub fn finish(&self) -> u64 {
+        self.hasher.0
+    }
+
+    fn tag(&mut self, tag: Tag) -> Result<()> {
+        self.hasher.write_u8(tag as u8);
+        Ok(())
+    }
+
+    fn begin(&mut self, tag: Tag, size: usize) -> Result<()> {
+        self.hasher.write_u8(tag as u8);
+        self.hasher.write_u64(size as u64);
+        Ok(())
+    }
+
+    fn ordinate(&mut self, value: Option<f64>) {
+        match value {
+            // Normalize -0.0
+            Some(v) => self.hasher.write_u64((v + 0.0).to_bits()),
+            None => self.hasher.write_u8(0),
+        }
+    }
+}
+
+impl Default for GeomHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GeomProcessor for GeomHasher {
+    fn dimensions(&self) -> CoordDimensions {
+        CoordDimensions::xyzm()
+    }
+    fn multi_dim(&self) -> bool {
+        true
+    }
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        self.coordinate(x, y, None, None, None, None, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        _idx: usize,
+    ) -> Result<()> {
+        self.tag(Tag::Coord)?;
+        for ord in [Some(x), Some(y), z, m, t] {
+            self.ordinate(ord);
+        }
+        self.hasher.write_u64(tm.unwrap_or(0));
+        Ok(())
+    }
+    fn empty_point(&mut self, _idx: usize) -> Result<()> {
+        self.tag(Tag::EmptyPoint)
+    }
+    fn point_begin(&mut self, _idx: usize) -> Result<()> {
+        self.tag(Tag::Point)
+    }
+    fn point_end(&mut self, _idx: usize) -> Result<()> {
+        self.tag(Tag::End)
+    }
+    fn multipoint_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
+        self.begin(Tag::MultiPoint, size)
+    }
+    fn multipoint_end(&mut self, _idx: usize) -> Result<()> {
+        self.tag(Tag::End)
+    }
+    fn linestring_begin(&mut self, tagged: bool, size: usize, _idx: usize) -> Result<()> {
+        self.hasher.write_u8(tagged as u8);
+        self.begin(Tag::LineString, size)
+    }
+    fn linestring_end(&mut self, _tagged: bool, _idx: usize) -> Result<()> {
+        self.tag(Tag::End)
+    }
+    fn multilinestring_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
+        self.begin(Tag::MultiLineString, size)
+    }
+    fn multilinestring_end(&mut self, _idx: usize) -> Result<()> {
+        self.tag(Tag::End)
+    }
+    fn polygon_begin(&mut self, tagged: bool, size: usize, _idx: usize) -> Result<()> {
+        self.hasher.write_u8(tagged as u8);
+        self.begin(Tag::Polygon, size)
+    }
+    fn polygon_end(&mut self, _tagged: bool, _idx: usize) -> Result<()> {
+        self.tag(Tag::End)
+    }
+    fn multipolygon_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
+        self.begin(Tag::MultiPolygon, size)
+    }
+    fn multipolygon_end(&mut self, _idx: usize) -> Result<()> {
+        self.tag(Tag::End)
+    }
+    fn geometrycollection_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
+        self.begin(Tag::GeometryCollection, size)
+    }
+    fn geometrycollection_end(&mut self, _idx: usize) -> Result<()> {
+        self.tag(Tag::End)
+    }
+    fn circularstring_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
+        self.begin(Tag::CircularString, size)
+    }
+    fn circularstring_end(&mut self, _idx: usize) -> Result<()> {
+        self.tag(Tag::End)
+    }
+    fn compoundcurve_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
+        self.begin(Tag::CompoundCurve, size)
+    }
+    fn compoundcurve_end(&mut self, _idx: usize) -> Result<()> {
+        self.tag(Tag::End)
+    }
+    fn curvepolygon_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
+        self.begin(Tag::CurvePolygon, size)
+    }
+    fn curvepolygon_end(&mut self, _idx: usize) -> Result<()> {
+        self.tag(Tag::End)
+    }
+    fn multicurve_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
+        self.begin(Tag::MultiCurve, size)
+    }
+    fn multicurve_end(&mut self, _idx: usize) -> Result<()> {
+        self.tag(Tag::End)
+    }
+    fn multisurface_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
+        self.begin(Tag::MultiSurface, size)
+    }
+    fn multisurface_end(&mut self, _idx: usize) -> Result<()> {
+        self.tag(Tag::End)
+    }
+    fn triangle_begin(&mut self, tagged: bool, size: usize, _idx: usize) -> Result<()> {
+        self.hasher.write_u8(tagged as u8);
+        self.begin(Tag::Triangle, size)
+    }
+    fn triangle_end(&mut self, _tagged: bool, _idx: usize) -> Result<()> {
+        self.tag(Tag::End)
+    }
+    fn polyhedralsurface_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
+        self.begin(Tag::PolyhedralSurface, size)
+    }
+    fn polyhedralsurface_end(&mut self, _idx: usize) -> Result<()> {
+        self.tag(Tag::End)
+    }
+    fn tin_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
+        self.begin(Tag::Tin, size)
+    }
+    fn tin_end(&mut self, _idx: usize) -> Result<()> {
+        self.tag(Tag::End)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkb")]
+mod test {
+    use super::*;
+    use crate::wkb::Wkb;
+    use crate::GeozeroGeometry;
+
+    fn hash(wkb: &str) -> u64 {
+        let mut hasher = GeomHasher::new();
+        Wkb(hex::decode(wkb).unwrap())
+            .process_geom(&mut hasher)
+            .unwrap();
+        hasher.finish()
+    }
+
+    #[test]
+    fn endian_independent() {
+        // POLYGON((3 4,5 4,5 6,3 4))
+        let le = hash("0103000000010000000400000000000000000008400000000000001040000000000000144000000000000010400000000000001440000000000000184000000000000008400000000000001040");
+        let be = hash("0000000003000000010000000440080000000000004010000000000000401400000000000040100000000000004014000000000000401800000000000040080000000000004010000000000000");
+        assert_eq!(le, be);
+
+        // LINESTRING(3 4,5 4,5 6,3 4)
+        let line = hash("01020000000400000000000000000008400000000000001040000000000000144000000000000010400000000000001440000000000000184000000000000008400000000000001040");
+        assert_ne!(le, line);
+    }
+
+    #[test]
+    fn stable_hash() {
+        // FNV-1a test vector
+        let mut hasher = Fnv1a(Fnv1a::OFFSET_BASIS);
+        hasher.write(b"a");
+        assert_eq!(hasher.0, 0xaf63_dc4c_8601_ec8c);
+
+        // POINT(3 4)
+        assert_eq!(
+            hash("010100000000000000000008400000000000001040"),
+            0xa74f_cc70_e799_39c4
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use super::VertexCentroid;
+use crate::error::{GeozeroError, Result};
+use crate::GeomProcessor;
+
+/// Hilbert curve index of a position within `bbox` (`[minx, miny, maxx, maxy]`).
+///
+/// The bbox is divided into a grid of `2^order` x `2^order` cells, positions outside
+/// of the bbox are clamped to the border cells. Orders above 32 are rejected, since
+/// the index wouldn't fit into `u64`.
+pub fn hilbert_index(x: f64, y: f64, bbox: [f64; 4], order: u32) -> Result<u64> {
+    check_order(order)?;
+    let n = 1u64 << order;
+    let cell = |value: f64, min: f64, max: f64| {
+        let pos = if max > min {
+            (value - min) / (max - min) * n as f64
+        } else {
+            0.0
+        };
+        (pos.max(0.0) as u64).min(n - 1)
+    };
+    let mut x = cell(x, bbox[0], bbox[2]);
+    let mut y = cell(y, bbox[1], bbox[3]);
+    let mut d = 0;
+    let mut s = n / 2;
+    while s > 0 {
+        let rx = u64::from(x & s > 0);
+        let ry = u64::from(y & s > 0);
+        d += s * s * ((3 * rx) ^ ry);
+        // rotate quadrant
+        if ry == 0 {
+            if rx == 1 {
+                x = n - 1 - x;
+                y = n - 1 - y;
+            }
+            std::mem::swap(&mut x, &mut y);
+        }
+        s /= 2;
+    }
+    Ok(d)
+}
+
+fn check_order(order: u32) -> Result<()> {
+    if order > 32 {
+        return Err(GeozeroError::Geometry(format!(
+            "Hilbert curve order {order} exceeds the maximum of 32"
+        )));
+    }
+    Ok(())
+}
+
+/// Compute the Hilbert curve index of each processed geometry, e.g. for sorting
+/// geometries before bulk loading a packed R-tree.
+///
+/// Geometries are indexed at the centroid of their vertices. Empty geometries yield `None`,
+/// NaN coordinates (e.g. WKB `POINT EMPTY`) are ignored.
+pub struct HilbertProcessor {
+    /// Extent of the Hilbert curve (`[minx, miny, maxx, maxy]`)
+    pub bbox: [f64; 4],
+    /// Hilbert indices of the processed geometries
+    pub indices: Vec<Option<u64>>,
+    order: u32,
+    centroid: VertexCentroid,
+}
+
+impl HilbertProcessor {
+    /// Processor dividing `bbox` into `2^order` x `2^order` cells (`order` <= 32).
+    pub fn new(bbox: [f64; 4], order: u32) -> Result<Self> {
+        check_order(order)?;
+        Ok(HilbertProcessor {
+            bbox,
+            indices: Vec::new(),
+            order,
+            centroid: VertexCentroid::default(),
+        })
+    }
+
+    /// Curve order
+    pub fn order(&self) -> u32 {
+        self.order
+    }
+
+    fn begin(&mut self, _idx: usize) -> Result<()> {
+        self.centroid.begin();
+        Ok(())
+    }
+
+    fn end(&mut self, _idx: usize) -> Result<()> {
+        if let Some(centroid) = self.centroid.end() {
+            let index = centroid.map(|(x, y)| hilbert_index(x, y, self.bbox, self.order));
+            self.indices.push(index.transpose()?);
+        }
+        Ok(())
+    }
+}
+
+impl GeomProcessor for HilbertProcessor {
+    fn xy(&mut self, x: f64, y: f64, _idx: usize) -> Result<()> {
+        self.centroid.add(x, y);
+        Ok(())
+    }
+    fn empty_point(&mut self, _idx: usize) -> Result<()> {
+        if self.centroid.is_top_level() {
+            self.indices.push(None);
+        }
+        Ok(())
+    }
+    nesting_geom_processor!(begin, end);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn hilbert_order() {
+        let bbox = [0.0, 0.0, 2.0, 2.0];
+        let quadrants = [(0.5, 0.5), (0.5, 1.5), (1.5, 1.5), (1.5, 0.5)];
+        let indices: Vec<u64> = quadrants
+            .iter()
+            .map(|(x, y)| hilbert_index(*x, *y, bbox, 1).unwrap())
+            .collect();
+        assert_eq!(indices, vec![0, 1, 2, 3]);
+
+        let indices: Vec<u64> = quadrants
+            .iter()
+            .map(|(x, y)| hilbert_index(*x, *y, bbox, 16).unwrap())
+            .collect();
+        assert!(indices.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(hilbert_index(-1.0, -1.0, bbox, 16).unwrap(), 0);
+
+        assert_eq!(hilbert_index(2.0, 0.0, bbox, 32).unwrap(), u64::MAX);
+        assert!(hilbert_index(0.5, 0.5, bbox, 33).is_err());
+        assert!(hilbert_index(0.5, 0.5, bbox, 64).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "with-wkt")]
+    fn geometry_indices() {
+        use crate::wkt::WktStr;
+        use crate::GeozeroGeometry;
+
+        assert!(HilbertProcessor::new([0.0, 0.0, 2.0, 2.0], 33).is_err());
+        let mut processor = HilbertProcessor::new([0.0, 0.0, 2.0, 2.0], 1).unwrap();
+        WktStr("POINT(1.5 1.5)")
+            .process_geom(&mut processor)
+            .unwrap();
+        WktStr("LINESTRING(1 0,2 1)")
+            .process_geom(&mut processor)
+            .unwrap();
+        WktStr("POINT EMPTY").process_geom(&mut processor).unwrap();
+        assert_eq!(processor.indices, vec![Some(2), Some(3), None]);
+    }
+
+    #[test]
+    #[cfg(feature = "with-wkb")]
+    fn wkb_empty_point() {
+        use crate::wkb::process_wkb_geom;
+
+        // POINT EMPTY
+        let wkb = hex::decode("0101000000000000000000F87F000000000000F87F").unwrap();
+        let mut processor = HilbertProcessor::new([0.0, 0.0, 2.0, 2.0], 1).unwrap();
+        process_wkb_geom(&mut wkb.as_slice(), &mut processor).unwrap();
+        // MULTIPOINT((1.5 1.5),EMPTY)
+        let wkb = hex::decode(
+            "010400000002000000010100000000000000\
+            0000F83F000000000000F83F0101000000000000000000F87F000000000000F87F",
+        )
+        .unwrap();
+        process_wkb_geom(&mut wkb.as_slice(), &mut processor).unwrap();
+        assert_eq!(processor.indices, vec![None, Some(2)]);
+    }
+}
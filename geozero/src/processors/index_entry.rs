@@ -0,0 +1,105 @@
+use crate::algorithm::extend_bbox;
+use crate::error::Result;
+use crate::GeomProcessor;
+
+/// Collect bounding boxes and feature ids of geometries for bulk loading a spatial index.
+///
+/// For each top-level geometry, `(minx, miny, maxx, maxy)` is appended to `boxes` and
+/// the feature id to `ids`. The id is set with [IndexEntryProcessor::feature_id] before
+/// processing a geometry and defaults to the number of processed geometries.
+/// Empty geometries have no bounding box and are skipped.
+#[derive(Default)]
+pub struct IndexEntryProcessor {
+    pub ids: Vec<u64>,
+    pub boxes: Vec<[f64; 4]>,
+    feature_id: Option<u64>,
+    count: u64,
+    depth: usize,
+    bounds: Option<[f64; 4]>,
+}
+
+impl IndexEntryProcessor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the feature id of the next geometry.
+    pub fn feature_id(&mut self, id: u64) {
+        self.feature_id = Some(id);
+    }
+
+    fn begin(&mut self, _idx: usize) -> Result<()> {
+        if self.depth == 0 {
+            self.bounds = None;
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    fn end(&mut self, _idx: usize) -> Result<()> {
+        self.depth -= 1;
+        if self.depth == 0 {
+            let id = self.feature_id.take().unwrap_or(self.count);
+            self.count += 1;
+            if let Some(bounds) = self.bounds.take() {
+                self.ids.push(id);
+                self.boxes.push(bounds);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl GeomProcessor for IndexEntryProcessor {
+    fn xy(&mut self, x: f64, y: f64, _idx: usize) -> Result<()> {
+        extend_bbox(&mut self.bounds, x, y);
+        Ok(())
+    }
+    fn empty_point(&mut self, idx: usize) -> Result<()> {
+        if self.depth == 0 {
+            self.begin(idx)?;
+            self.end(idx)?;
+        }
+        Ok(())
+    }
+    nesting_geom_processor!(begin, end);
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+    use crate::wkt::WktStr;
+    use crate::GeozeroGeometry;
+
+    #[test]
+    fn index_entries() {
+        let mut processor = IndexEntryProcessor::new();
+        for (id, wkt) in [
+            (10, "POINT(1 2)"),
+            (20, "LINESTRING(0 0,3 -1,2 5)"),
+            (
+                30,
+                "MULTIPOLYGON(((0 0,4 0,4 4,0 0)),((-2 -2,-1 -2,-1 -1,-2 -2)))",
+            ),
+        ] {
+            processor.feature_id(id);
+            WktStr(wkt).process_geom(&mut processor).unwrap();
+        }
+        WktStr("POINT(7 8)").process_geom(&mut processor).unwrap();
+        // WKB POINT EMPTY
+        processor.point_begin(0).unwrap();
+        processor.xy(f64::NAN, f64::NAN, 0).unwrap();
+        processor.point_end(0).unwrap();
+        assert_eq!(processor.ids, vec![10, 20, 30, 3]);
+        assert_eq!(
+            processor.boxes,
+            vec![
+                [1.0, 2.0, 1.0, 2.0],
+                [0.0, -1.0, 3.0, 5.0],
+                [-2.0, -2.0, 4.0, 4.0],
+                [7.0, 8.0, 7.0, 8.0],
+            ]
+        );
+    }
+}
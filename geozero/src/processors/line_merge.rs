@@ -0,0 +1,169 @@
+use super::Coord;
+use crate::error::Result;
+use crate::GeomProcessor;
+
+/// Merge the parts of MultiLineStrings into maximal lines, e.g. for road network segments.
+///
+/// Parts are joined where the end of one part equals the start or end of another part
+/// in x/y, reversing parts if needed. Parts meeting at a node with more than two lines
+/// are joined in input order. A MultiLineString merged into a single line is forwarded as
+/// LineString, otherwise as MultiLineString of the merged lines. Other geometries are
+/// forwarded unchanged.
+pub struct LineMergeProcessor<P: GeomProcessor> {
+    /// Maximal number of buffered coordinates, larger geometries are rejected
+    pub max_buffered_coords: Option<usize>,
+    inner: P,
+    parts: Vec<Vec<Coord>>,
+    buffered: usize,
+    in_multi: bool,
+}
+
+impl<P: GeomProcessor> LineMergeProcessor<P> {
+    pub fn new(inner: P) -> Self {
+        LineMergeProcessor {
+            max_buffered_coords: None,
+            inner,
+            parts: Vec::new(),
+            buffered: 0,
+            in_multi: false,
+        }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+}
+
+fn same_xy(a: Option<&Coord>, b: Option<&Coord>) -> bool {
+    matches!((a, b), (Some(a), Some(b)) if (a.0, a.1) == (b.0, b.1))
+}
+
+/// Join parts with matching endpoints.
+fn merge(parts: Vec<Vec<Coord>>) -> Vec<Vec<Coord>> {
+    let mut parts: Vec<Option<Vec<Coord>>> = parts.into_iter().map(Some).collect();
+    let mut merged = Vec::new();
+    for i in 0..parts.len() {
+        let Some(mut line) = parts[i].take() else {
+            continue;
+        };
+        loop {
+            let mut joined = false;
+            for part in parts.iter_mut().skip(i + 1) {
+                let Some(candidate) = part else {
+                    continue;
+                };
+                if same_xy(line.last(), candidate.first()) {
+                    line.extend_from_slice(&candidate[1..]);
+                } else if same_xy(line.last(), candidate.last()) {
+                    line.extend(candidate.iter().rev().skip(1));
+                } else if same_xy(line.first(), candidate.last()) {
+                    candidate.extend_from_slice(&line[1..]);
+                    line = std::mem::take(candidate);
+                } else if same_xy(line.first(), candidate.first()) {
+                    candidate.reverse();
+                    candidate.extend_from_slice(&line[1..]);
+                    line = std::mem::take(candidate);
+                } else {
+                    continue;
+                }
+                *part = None;
+                joined = true;
+            }
+            if !joined {
+                break;
+            }
+        }
+        merged.push(line);
+    }
+    merged
+}
+
+impl<P: GeomProcessor> GeomProcessor for LineMergeProcessor<P> {
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        self.coordinate(x, y, None, None, None, None, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        if let (true, Some(part)) = (self.in_multi, self.parts.last_mut()) {
+            super::check_buffer_limit(self.buffered, self.max_buffered_coords)?;
+            self.buffered += 1;
+            part.push((x, y, z, m));
+            Ok(())
+        } else if self.inner.multi_dim() {
+            self.inner.coordinate(x, y, z, m, t, tm, idx)
+        } else {
+            self.inner.xy(x, y, idx)
+        }
+    }
+    fn linestring_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        if !self.in_multi {
+            return self.inner.linestring_begin(tagged, size, idx);
+        }
+        self.parts.push(Vec::with_capacity(size));
+        Ok(())
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        if self.in_multi {
+            Ok(())
+        } else {
+            self.inner.linestring_end(tagged, idx)
+        }
+    }
+    fn multilinestring_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.in_multi = true;
+        self.parts.clear();
+        self.buffered = 0;
+        Ok(())
+    }
+    fn multilinestring_end(&mut self, idx: usize) -> Result<()> {
+        self.in_multi = false;
+        let lines = merge(std::mem::take(&mut self.parts));
+        if let [line] = &lines[..] {
+            return super::emit_line(&mut self.inner, line, true, idx);
+        }
+        self.inner.multilinestring_begin(lines.len(), idx)?;
+        for (i, line) in lines.iter().enumerate() {
+            super::emit_line(&mut self.inner, line, false, i)?;
+        }
+        self.inner.multilinestring_end(idx)
+    }
+    forward_geom_processor!(inner;
+        dimensions, srid, empty_point, point_begin, point_end,
+        multipoint_begin, multipoint_end, polygon_begin, polygon_end,
+        multipolygon_begin, multipolygon_end, geometrycollection_begin, geometrycollection_end,
+        circularstring_begin, circularstring_end, compoundcurve_begin, compoundcurve_end,
+        curvepolygon_begin, curvepolygon_end, multicurve_begin, multicurve_end,
+        multisurface_begin, multisurface_end, triangle_begin, triangle_end,
+        polyhedralsurface_begin, polyhedralsurface_end, tin_begin, tin_end,
+    );
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+
+    fn line_merge(wkt: &str) -> String {
+        process_wkt!(wkt, LineMergeProcessor::new)
+    }
+
+    #[test]
+    fn merge_segments() {
+        assert_eq!(
+            line_merge("MULTILINESTRING((0 0,1 1),(1 1,2 2))"),
+            "LINESTRING(0 0,1 1,2 2)"
+        );
+        assert_eq!(
+            line_merge("MULTILINESTRING((1 1,2 2),(3 3,2 2),(5 5,6 6),(0 0,1 1))"),
+            "MULTILINESTRING((0 0,1 1,2 2,3 3),(5 5,6 6))"
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use super::Coord;
+use crate::algorithm::ring_signed_area;
+use crate::error::Result;
+use crate::GeomProcessor;
+
+type Ring = Vec<Coord>;
+
+/// Drop polygon rings with an area below a minimum.
+///
+/// Polygons are buffered and forwarded without interior rings below `min_area`.
+/// Polygons with an exterior ring below `min_area` are removed from multipolygons,
+/// other polygons are replaced by an empty polygon. Ring areas are planar (x/y).
+pub struct MinAreaProcessor<P: GeomProcessor> {
+    pub min_area: f64,
+    /// Maximal number of buffered coordinates, larger polygons are rejected
+    pub max_buffered_coords: Option<usize>,
+    inner: P,
+    buffered: usize,
+    polygons: Vec<Vec<Ring>>,
+    in_polygon: bool,
+    in_multipolygon: bool,
+}
+
+impl<P: GeomProcessor> MinAreaProcessor<P> {
+    pub fn new(inner: P, min_area: f64) -> Self {
+        MinAreaProcessor {
+            min_area,
+            max_buffered_coords: None,
+            inner,
+            buffered: 0,
+            polygons: Vec::new(),
+            in_polygon: false,
+            in_multipolygon: false,
+        }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    /// Remove small rings, returns `None` if the exterior ring is too small.
+    fn clean(&self, rings: Vec<Ring>) -> Option<Vec<Ring>> {
+        let mut rings = rings.into_iter();
+        let exterior = rings.next()?;
+        if ring_area(&exterior) < self.min_area {
+            return None;
+        }
+        Some(
+            std::iter::once(exterior)
+                .chain(rings.filter(|ring| ring_area(ring) >= self.min_area))
+                .collect(),
+        )
+    }
+}
+
+fn ring_area(ring: &[Coord]) -> f64 {
+    let xy: Vec<[f64; 2]> = ring.iter().map(|c| [c.0, c.1]).collect();
+    ring_signed_area(&xy).abs()
+}
+
+impl<P: GeomProcessor> GeomProcessor for MinAreaProcessor<P> {
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        self.coordinate(x, y, None, None, None, None, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        if !self.in_polygon {
+            return if self.inner.multi_dim() {
+                self.inner.coordinate(x, y, z, m, t, tm, idx)
+            } else {
+                self.inner.xy(x, y, idx)
+            };
+        }
+        super::check_buffer_limit(self.buffered, self.max_buffered_coords)?;
+        if let Some(ring) = self.polygons.last_mut().and_then(|rings| rings.last_mut()) {
+            self.buffered += 1;
+            ring.push((x, y, z, m));
+        }
+        Ok(())
+    }
+    fn linestring_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        if !self.in_polygon {
+            return self.inner.linestring_begin(tagged, size, idx);
+        }
+        if let Some(rings) = self.polygons.last_mut() {
+            rings.push(Vec::with_capacity(size));
+        }
+        Ok(())
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        if !self.in_polygon {
+            return self.inner.linestring_end(tagged, idx);
+        }
+        Ok(())
+    }
+    fn polygon_begin(&mut self, _tagged: bool, size: usize, _idx: usize) -> Result<()> {
+        self.in_polygon = true;
+        if !self.in_multipolygon {
+            self.buffered = 0;
+        }
+        self.polygons.push(Vec::with_capacity(size));
+        Ok(())
+    }
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.in_polygon = false;
+        if self.in_multipolygon {
+            return Ok(());
+        }
+        self.buffered = 0;
+        let rings = self.polygons.pop().unwrap_or_default();
+        let rings = self.clean(rings).unwrap_or_default();
+        super::emit_polygon(&mut self.inner, &rings, tagged, idx)
+    }
+    fn multipolygon_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.in_multipolygon = true;
+        self.polygons.clear();
+        self.buffered = 0;
+        Ok(())
+    }
+    fn multipolygon_end(&mut self, idx: usize) -> Result<()> {
+        self.in_multipolygon = false;
+        self.buffered = 0;
+        let polygons: Vec<_> = std::mem::take(&mut self.polygons)
+            .into_iter()
+            .filter_map(|rings| self.clean(rings))
+            .collect();
+        self.inner.multipolygon_begin(polygons.len(), idx)?;
+        for (i, rings) in polygons.iter().enumerate() {
+            super::emit_polygon(&mut self.inner, rings, false, i)?;
+        }
+        self.inner.multipolygon_end(idx)
+    }
+    forward_geom_processor!(inner;
+        dimensions, srid, empty_point, point_begin, point_end,
+        multipoint_begin, multipoint_end,
+        multilinestring_begin, multilinestring_end, geometrycollection_begin, geometrycollection_end,
+        circularstring_begin, circularstring_end, compoundcurve_begin, compoundcurve_end,
+        curvepolygon_begin, curvepolygon_end, multicurve_begin, multicurve_end,
+        multisurface_begin, multisurface_end, triangle_begin, triangle_end,
+        polyhedralsurface_begin, polyhedralsurface_end, tin_begin, tin_end,
+    );
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+
+    fn min_area(wkt: &str) -> String {
+        process_wkt!(wkt, |w| MinAreaProcessor::new(w, 0.001))
+    }
+
+    #[test]
+    fn drop_sliver_hole() {
+        assert_eq!(
+            min_area("POLYGON((0 0,10 0,10 10,0 10,0 0),(1 1,1.01 1,1.01 1.01,1 1.01,1 1),(2 2,3 2,3 3,2 2))"),
+            "POLYGON((0 0,10 0,10 10,0 10,0 0),(2 2,3 2,3 3,2 2))"
+        );
+    }
+
+    #[test]
+    fn drop_small_polygons() {
+        assert_eq!(
+            min_area("MULTIPOLYGON(((0 0,0.01 0,0.01 0.01,0 0)),((0 0,1 0,1 1,0 0)))"),
+            "MULTIPOLYGON(((0 0,1 0,1 1,0 0)))"
+        );
+        assert_eq!(
+            min_area("POLYGON((0 0,0.01 0,0.01 0.01,0 0))"),
+            "POLYGON EMPTY"
+        );
+    }
+}
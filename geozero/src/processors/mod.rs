@@ -0,0 +1,443 @@
+//! Geometry processors for transforming and analyzing geometries.
+//!
+//! Most processors in this module wrap an inner [GeomProcessor](crate::GeomProcessor)
+//! and forward the (transformed) geometry to it.
+
+/// Implement `GeomProcessor` methods by forwarding them to an inner processor field.
+macro_rules! forward_geom_processor {
+    ($inner:ident; $($method:ident),* $(,)?) => {
+        $( forward_geom_processor!(@method $inner $method); )*
+    };
+    (@method $inner:ident dimensions) => {
+        fn dimensions(&self) -> $crate::CoordDimensions {
+            self.$inner.dimensions()
+        }
+    };
+    (@method $inner:ident srid) => {
+        fn srid(&mut self, srid: Option<i32>) -> $crate::error::Result<()> {
+            self.$inner.srid(srid)
+        }
+    };
+    (@method $inner:ident xy) => {
+        fn xy(&mut self, x: f64, y: f64, idx: usize) -> $crate::error::Result<()> {
+            self.$inner.xy(x, y, idx)
+        }
+    };
+    (@method $inner:ident coordinate) => {
+        fn coordinate(
+            &mut self,
+            x: f64,
+            y: f64,
+            z: Option<f64>,
+            m: Option<f64>,
+            t: Option<f64>,
+            tm: Option<u64>,
+            idx: usize,
+        ) -> $crate::error::Result<()> {
+            self.$inner.coordinate(x, y, z, m, t, tm, idx)
+        }
+    };
+    (@method $inner:ident empty_point) => {
+        fn empty_point(&mut self, idx: usize) -> $crate::error::Result<()> {
+            self.$inner.empty_point(idx)
+        }
+    };
+    (@method $inner:ident point_begin) => {
+        fn point_begin(&mut self, idx: usize) -> $crate::error::Result<()> {
+            self.$inner.point_begin(idx)
+        }
+    };
+    (@method $inner:ident point_end) => {
+        fn point_end(&mut self, idx: usize) -> $crate::error::Result<()> {
+            self.$inner.point_end(idx)
+        }
+    };
+    (@method $inner:ident linestring_begin) => {
+        fn linestring_begin(&mut self, tagged: bool, size: usize, idx: usize) -> $crate::error::Result<()> {
+            self.$inner.linestring_begin(tagged, size, idx)
+        }
+    };
+    (@method $inner:ident linestring_end) => {
+        fn linestring_end(&mut self, tagged: bool, idx: usize) -> $crate::error::Result<()> {
+            self.$inner.linestring_end(tagged, idx)
+        }
+    };
+    (@method $inner:ident polygon_begin) => {
+        fn polygon_begin(&mut self, tagged: bool, size: usize, idx: usize) -> $crate::error::Result<()> {
+            self.$inner.polygon_begin(tagged, size, idx)
+        }
+    };
+    (@method $inner:ident polygon_end) => {
+        fn polygon_end(&mut self, tagged: bool, idx: usize) -> $crate::error::Result<()> {
+            self.$inner.polygon_end(tagged, idx)
+        }
+    };
+    (@method $inner:ident triangle_begin) => {
+        fn triangle_begin(&mut self, tagged: bool, size: usize, idx: usize) -> $crate::error::Result<()> {
+            self.$inner.triangle_begin(tagged, size, idx)
+        }
+    };
+    (@method $inner:ident triangle_end) => {
+        fn triangle_end(&mut self, tagged: bool, idx: usize) -> $crate::error::Result<()> {
+            self.$inner.triangle_end(tagged, idx)
+        }
+    };
+    (@method $inner:ident multipoint_begin) => {
+        forward_geom_processor!(@begin $inner multipoint_begin);
+    };
+    (@method $inner:ident multilinestring_begin) => {
+        forward_geom_processor!(@begin $inner multilinestring_begin);
+    };
+    (@method $inner:ident multipolygon_begin) => {
+        forward_geom_processor!(@begin $inner multipolygon_begin);
+    };
+    (@method $inner:ident geometrycollection_begin) => {
+        forward_geom_processor!(@begin $inner geometrycollection_begin);
+    };
+    (@method $inner:ident circularstring_begin) => {
+        forward_geom_processor!(@begin $inner circularstring_begin);
+    };
+    (@method $inner:ident compoundcurve_begin) => {
+        forward_geom_processor!(@begin $inner compoundcurve_begin);
+    };
+    (@method $inner:ident curvepolygon_begin) => {
+        forward_geom_processor!(@begin $inner curvepolygon_begin);
+    };
+    (@method $inner:ident multicurve_begin) => {
+        forward_geom_processor!(@begin $inner multicurve_begin);
+    };
+    (@method $inner:ident multisurface_begin) => {
+        forward_geom_processor!(@begin $inner multisurface_begin);
+    };
+    (@method $inner:ident polyhedralsurface_begin) => {
+        forward_geom_processor!(@begin $inner polyhedralsurface_begin);
+    };
+    (@method $inner:ident tin_begin) => {
+        forward_geom_processor!(@begin $inner tin_begin);
+    };
+    // Remaining `*_end(idx)` methods
+    (@method $inner:ident $method:ident) => {
+        forward_geom_processor!(@end $inner $method);
+    };
+    (@begin $inner:ident $method:ident) => {
+        fn $method(&mut self, size: usize, idx: usize) -> $crate::error::Result<()> {
+            self.$inner.$method(size, idx)
+        }
+    };
+    (@end $inner:ident $method:ident) => {
+        fn $method(&mut self, idx: usize) -> $crate::error::Result<()> {
+            self.$inner.$method(idx)
+        }
+    };
+}
+
+/// Implement all `*_begin` and `*_end` methods of `GeomProcessor` by calling
+/// `self.$begin(idx)` and `self.$end(idx)`, for processors tracking geometry nesting.
+macro_rules! nesting_geom_processor {
+    ($begin:ident, $end:ident) => {
+        fn point_begin(&mut self, idx: usize) -> $crate::error::Result<()> {
+            self.$begin(idx)
+        }
+        fn point_end(&mut self, idx: usize) -> $crate::error::Result<()> {
+            self.$end(idx)
+        }
+        fn linestring_begin(
+            &mut self,
+            _tagged: bool,
+            _size: usize,
+            idx: usize,
+        ) -> $crate::error::Result<()> {
+            self.$begin(idx)
+        }
+        fn linestring_end(&mut self, _tagged: bool, idx: usize) -> $crate::error::Result<()> {
+            self.$end(idx)
+        }
+        fn polygon_begin(
+            &mut self,
+            _tagged: bool,
+            _size: usize,
+            idx: usize,
+        ) -> $crate::error::Result<()> {
+            self.$begin(idx)
+        }
+        fn polygon_end(&mut self, _tagged: bool, idx: usize) -> $crate::error::Result<()> {
+            self.$end(idx)
+        }
+        fn triangle_begin(
+            &mut self,
+            _tagged: bool,
+            _size: usize,
+            idx: usize,
+        ) -> $crate::error::Result<()> {
+            self.$begin(idx)
+        }
+        fn triangle_end(&mut self, _tagged: bool, idx: usize) -> $crate::error::Result<()> {
+            self.$end(idx)
+        }
+        fn multipoint_begin(&mut self, _size: usize, idx: usize) -> $crate::error::Result<()> {
+            self.$begin(idx)
+        }
+        fn multipoint_end(&mut self, idx: usize) -> $crate::error::Result<()> {
+            self.$end(idx)
+        }
+        fn multilinestring_begin(&mut self, _size: usize, idx: usize) -> $crate::error::Result<()> {
+            self.$begin(idx)
+        }
+        fn multilinestring_end(&mut self, idx: usize) -> $crate::error::Result<()> {
+            self.$end(idx)
+        }
+        fn multipolygon_begin(&mut self, _size: usize, idx: usize) -> $crate::error::Result<()> {
+            self.$begin(idx)
+        }
+        fn multipolygon_end(&mut self, idx: usize) -> $crate::error::Result<()> {
+            self.$end(idx)
+        }
+        fn geometrycollection_begin(
+            &mut self,
+            _size: usize,
+            idx: usize,
+        ) -> $crate::error::Result<()> {
+            self.$begin(idx)
+        }
+        fn geometrycollection_end(&mut self, idx: usize) -> $crate::error::Result<()> {
+            self.$end(idx)
+        }
+        fn circularstring_begin(&mut self, _size: usize, idx: usize) -> $crate::error::Result<()> {
+            self.$begin(idx)
+        }
+        fn circularstring_end(&mut self, idx: usize) -> $crate::error::Result<()> {
+            self.$end(idx)
+        }
+        fn compoundcurve_begin(&mut self, _size: usize, idx: usize) -> $crate::error::Result<()> {
+            self.$begin(idx)
+        }
+        fn compoundcurve_end(&mut self, idx: usize) -> $crate::error::Result<()> {
+            self.$end(idx)
+        }
+        fn curvepolygon_begin(&mut self, _size: usize, idx: usize) -> $crate::error::Result<()> {
+            self.$begin(idx)
+        }
+        fn curvepolygon_end(&mut self, idx: usize) -> $crate::error::Result<()> {
+            self.$end(idx)
+        }
+        fn multicurve_begin(&mut self, _size: usize, idx: usize) -> $crate::error::Result<()> {
+            self.$begin(idx)
+        }
+        fn multicurve_end(&mut self, idx: usize) -> $crate::error::Result<()> {
+            self.$end(idx)
+        }
+        fn multisurface_begin(&mut self, _size: usize, idx: usize) -> $crate::error::Result<()> {
+            self.$begin(idx)
+        }
+        fn multisurface_end(&mut self, idx: usize) -> $crate::error::Result<()> {
+            self.$end(idx)
+        }
+        fn polyhedralsurface_begin(
+            &mut self,
+            _size: usize,
+            idx: usize,
+        ) -> $crate::error::Result<()> {
+            self.$begin(idx)
+        }
+        fn polyhedralsurface_end(&mut self, idx: usize) -> $crate::error::Result<()> {
+            self.$end(idx)
+        }
+        fn tin_begin(&mut self, _size: usize, idx: usize) -> $crate::error::Result<()> {
+            self.$begin(idx)
+        }
+        fn tin_end(&mut self, idx: usize) -> $crate::error::Result<()> {
+            self.$end(idx)
+        }
+    };
+}
+
+/// Process `$wkt` with the processor built by the `$new` closure around a `WktWriter`
+/// and return the written WKT. `$finish` is called with the processor after processing.
+///
+/// A macro, since the built processor borrows the output buffer of the caller.
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+macro_rules! process_wkt {
+    ($wkt:expr, $new:expr) => {
+        process_wkt!($wkt, $new, |_| Ok::<(), $crate::error::GeozeroError>(()))
+    };
+    ($wkt:expr, $new:expr, $finish:expr) => {{
+        let mut out: Vec<u8> = Vec::new();
+        let mut processor = ($new)($crate::wkt::WktWriter::new(&mut out));
+        $crate::GeozeroGeometry::process_geom(&$crate::wkt::WktStr($wkt), &mut processor).unwrap();
+        ($finish)(&mut processor).unwrap();
+        drop(processor);
+        String::from_utf8(out).unwrap()
+    }};
+}
+
+/// Fail if a buffering processor would exceed its limit of buffered coordinates.
+fn check_buffer_limit(buffered: usize, limit: Option<usize>) -> crate::error::Result<()> {
+    match limit {
+        Some(limit) if buffered >= limit => Err(crate::error::GeozeroError::Geometry(format!(
+            "geometry exceeds the limit of {limit} buffered coordinates"
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Coordinate buffered by processors, with optional Z and M values.
+type Coord = (f64, f64, Option<f64>, Option<f64>);
+
+/// Forward a buffered linestring or ring, with Z/M only if requested by `inner`.
+fn emit_line<P: crate::GeomProcessor>(
+    inner: &mut P,
+    line: &[Coord],
+    tagged: bool,
+    idx: usize,
+) -> crate::error::Result<()> {
+    let multi_dim = inner.multi_dim();
+    inner.linestring_begin(tagged, line.len(), idx)?;
+    for (i, &(x, y, z, m)) in line.iter().enumerate() {
+        if multi_dim {
+            inner.coordinate(x, y, z, m, None, None, i)?;
+        } else {
+            inner.xy(x, y, i)?;
+        }
+    }
+    inner.linestring_end(tagged, idx)
+}
+
+/// Forward a buffered polygon.
+fn emit_polygon<P: crate::GeomProcessor>(
+    inner: &mut P,
+    rings: &[Vec<Coord>],
+    tagged: bool,
+    idx: usize,
+) -> crate::error::Result<()> {
+    inner.polygon_begin(tagged, rings.len(), idx)?;
+    for (i, ring) in rings.iter().enumerate() {
+        emit_line(inner, ring, false, i)?;
+    }
+    inner.polygon_end(tagged, idx)
+}
+
+/// Vertex centroid of top-level geometries, ignoring NaN coordinates (e.g. WKB `POINT EMPTY`).
+#[derive(Default)]
+struct VertexCentroid {
+    depth: usize,
+    sum: (f64, f64),
+    count: usize,
+}
+
+impl VertexCentroid {
+    fn begin(&mut self) {
+        self.depth += 1;
+    }
+
+    /// Leave a geometry, returns the centroid (`None` if empty) at the end of a top-level geometry.
+    fn end(&mut self) -> Option<Option<(f64, f64)>> {
+        self.depth -= 1;
+        if self.depth > 0 {
+            return None;
+        }
+        let n = self.count as f64;
+        let centroid = (self.count > 0).then(|| (self.sum.0 / n, self.sum.1 / n));
+        self.sum = (0.0, 0.0);
+        self.count = 0;
+        Some(centroid)
+    }
+
+    fn add(&mut self, x: f64, y: f64) {
+        if x.is_nan() || y.is_nan() {
+            return;
+        }
+        self.sum.0 += x;
+        self.sum.1 += y;
+        self.count += 1;
+    }
+
+    fn is_top_level(&self) -> bool {
+        self.depth == 0
+    }
+}
+
+/// Interpolate an optional Z or M value, `None` if missing at one end.
+fn lerp(a: Option<f64>, b: Option<f64>, f: f64) -> Option<f64> {
+    Some(a? + (b? - a?) * f)
+}
+
+mod antimeridian;
+mod axis_order;
+mod bounds_check;
+mod cancel;
+mod centerline;
+mod clean;
+mod convex_hull;
+mod coord_filter;
+mod coord_sink;
+mod decimate;
+mod densify;
+mod dispatch;
+mod dropped_dims;
+mod envelope_poly;
+mod explode;
+mod f32_coords;
+#[cfg(feature = "with-geohash")]
+mod geohash;
+mod hash;
+mod hilbert;
+mod index_entry;
+mod line_merge;
+mod min_area;
+mod obb;
+mod points_along;
+mod polygon_close;
+mod quantize;
+mod ring_normalize;
+mod simple_line;
+mod snap;
+mod snap_dedup;
+mod stats;
+mod tee;
+mod trace;
+mod vertex_count;
+mod vertex_importance;
+mod winding;
+mod z_extent;
+mod z_transform;
+
+pub use antimeridian::*;
+pub use axis_order::*;
+pub use bounds_check::*;
+pub use cancel::*;
+pub use centerline::*;
+pub use clean::*;
+pub use convex_hull::*;
+pub use coord_filter::*;
+pub use coord_sink::*;
+pub use decimate::*;
+pub use densify::*;
+pub use dispatch::*;
+pub use dropped_dims::*;
+pub use envelope_poly::*;
+pub use explode::*;
+pub use f32_coords::*;
+#[cfg(feature = "with-geohash")]
+pub use geohash::*;
+pub use hash::*;
+pub use hilbert::*;
+pub use index_entry::*;
+pub use line_merge::*;
+pub use min_area::*;
+pub use obb::*;
+pub use points_along::*;
+pub use polygon_close::*;
+pub use quantize::*;
+pub use ring_normalize::*;
+pub use simple_line::*;
+pub use snap::*;
+pub use snap_dedup::*;
+pub use stats::*;
+pub use tee::*;
+pub use trace::*;
+pub use vertex_count::*;
+pub use vertex_importance::*;
+pub use winding::*;
+pub use z_extent::*;
+pub use z_transform::*;
@@ -0,0 +1,194 @@
+use super::convex_hull::convex_hull;
+use crate::error::Result;
+use crate::GeomProcessor;
+use std::f64::consts::PI;
+
+/// Unit vector or value range
+type Pair = (f64, f64);
+
+/// Oriented rectangle computed by [ObbProcessor].
+#[derive(Clone, PartialEq, Debug)]
+pub struct OrientedRect {
+    /// Corners in counter-clockwise order
+    pub corners: [(f64, f64); 4],
+    /// Direction of the longer side in radians against the x axis, in `[0, PI)`
+    pub angle: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// Compute the oriented minimum bounding rectangle of all processed coordinates.
+///
+/// Useful for estimating the orientation of elongated features like buildings or fields.
+/// The minimum area rectangle has a side collinear with an edge of the convex hull
+/// (rotating calipers), so each hull edge direction is tested.
+/// Coordinates are accumulated until [finish](ObbProcessor::finish) is called,
+/// which forwards the rectangle to the inner processor as polygon.
+/// NaN coordinates (e.g. WKB `POINT EMPTY`) are ignored.
+pub struct ObbProcessor<P: GeomProcessor> {
+    /// Maximal number of buffered coordinates, more coordinates are rejected
+    pub max_buffered_coords: Option<usize>,
+    inner: P,
+    points: Vec<(f64, f64)>,
+}
+
+impl<P: GeomProcessor> ObbProcessor<P> {
+    pub fn new(inner: P) -> Self {
+        ObbProcessor {
+            max_buffered_coords: None,
+            inner,
+            points: Vec::new(),
+        }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    /// Minimum area rectangle of the accumulated coordinates, `None` without coordinates.
+    pub fn rect(&self) -> Option<OrientedRect> {
+        let hull = convex_hull(&self.points);
+        // (area, direction, u range, v range)
+        let mut best: Option<(f64, Pair, Pair, Pair)> = None;
+        for (i, a) in hull.iter().enumerate() {
+            let b = hull[(i + 1) % hull.len()];
+            let len = (b.0 - a.0).hypot(b.1 - a.1);
+            let dir = if len > 0.0 {
+                ((b.0 - a.0) / len, (b.1 - a.1) / len)
+            } else {
+                (1.0, 0.0)
+            };
+            let mut u = (f64::INFINITY, f64::NEG_INFINITY);
+            let mut v = (f64::INFINITY, f64::NEG_INFINITY);
+            for p in &hull {
+                let pu = p.0 * dir.0 + p.1 * dir.1;
+                let pv = -p.0 * dir.1 + p.1 * dir.0;
+                u = (u.0.min(pu), u.1.max(pu));
+                v = (v.0.min(pv), v.1.max(pv));
+            }
+            let area = (u.1 - u.0) * (v.1 - v.0);
+            if best.is_none_or(|best| area < best.0) {
+                best = Some((area, dir, u, v));
+            }
+        }
+        let (_, dir, u, v) = best?;
+        let corner = |u: f64, v: f64| (u * dir.0 - v * dir.1, u * dir.1 + v * dir.0);
+        let (width, height) = (u.1 - u.0, v.1 - v.0);
+        let mut angle = dir.1.atan2(dir.0);
+        if height > width {
+            angle += PI / 2.0;
+        }
+        Some(OrientedRect {
+            corners: [
+                corner(u.0, v.0),
+                corner(u.1, v.0),
+                corner(u.1, v.1),
+                corner(u.0, v.1),
+            ],
+            angle: angle.rem_euclid(PI),
+            width: width.max(height),
+            height: width.min(height),
+        })
+    }
+
+    /// Forward the rectangle of the accumulated coordinates and start over.
+    ///
+    /// Without coordinates, an empty polygon is forwarded.
+    pub fn finish(&mut self) -> Result<()> {
+        let rect = self.rect();
+        self.points.clear();
+        let Some(rect) = rect else {
+            self.inner.polygon_begin(true, 0, 0)?;
+            return self.inner.polygon_end(true, 0);
+        };
+        self.inner.polygon_begin(true, 1, 0)?;
+        self.inner.linestring_begin(false, 5, 0)?;
+        for (i, &(x, y)) in rect.corners.iter().chain(&rect.corners[..1]).enumerate() {
+            self.inner.xy(x, y, i)?;
+        }
+        self.inner.linestring_end(false, 0)?;
+        self.inner.polygon_end(true, 0)
+    }
+}
+
+impl<P: GeomProcessor> GeomProcessor for ObbProcessor<P> {
+    fn srid(&mut self, srid: Option<i32>) -> Result<()> {
+        self.inner.srid(srid)
+    }
+    fn xy(&mut self, x: f64, y: f64, _idx: usize) -> Result<()> {
+        // WKB encodes POINT EMPTY as NaN coordinates
+        if x.is_nan() || y.is_nan() {
+            return Ok(());
+        }
+        super::check_buffer_limit(self.points.len(), self.max_buffered_coords)?;
+        self.points.push((x, y));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+    use crate::wkt::{WktStr, WktWriter};
+    use crate::{GeozeroGeometry, ProcessorSink};
+
+    #[test]
+    fn rotated_rectangle() {
+        // 4 x 1 rectangle rotated by 30 degrees, with an inner point
+        let (sin, cos) = 30f64.to_radians().sin_cos();
+        let rotate = |x: f64, y: f64| format!("{} {}", x * cos - y * sin, x * sin + y * cos);
+        let wkt = format!(
+            "MULTIPOINT({},{},{},{},{})",
+            rotate(0.0, 0.0),
+            rotate(4.0, 0.0),
+            rotate(4.0, 1.0),
+            rotate(0.0, 1.0),
+            rotate(2.0, 0.5)
+        );
+        let mut out: Vec<u8> = Vec::new();
+        let mut processor = ObbProcessor::new(WktWriter::new(&mut out));
+        WktStr(&wkt).process_geom(&mut processor).unwrap();
+        let rect = processor.rect().unwrap();
+        assert!((rect.angle - 30f64.to_radians()).abs() < 1e-9);
+        assert!((rect.width - 4.0).abs() < 1e-9);
+        assert!((rect.height - 1.0).abs() < 1e-9);
+        processor.finish().unwrap();
+        let wkt = String::from_utf8(out).unwrap();
+        assert!(wkt.starts_with("POLYGON(("));
+        assert_eq!(wkt.matches(',').count(), 4);
+    }
+
+    #[test]
+    #[cfg(feature = "with-wkb")]
+    fn wkb_empty_point() {
+        use crate::wkb::process_wkb_geom;
+
+        let mut processor = ObbProcessor::new(ProcessorSink);
+        WktStr("MULTIPOINT(0 0,4 0,4 1,0 1)")
+            .process_geom(&mut processor)
+            .unwrap();
+        // POINT EMPTY
+        let wkb = hex::decode("0101000000000000000000F87F000000000000F87F").unwrap();
+        process_wkb_geom(&mut wkb.as_slice(), &mut processor).unwrap();
+        let rect = processor.rect().unwrap();
+        assert_eq!((rect.width, rect.height, rect.angle), (4.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn buffer_limit() {
+        let mut processor = ObbProcessor::new(ProcessorSink);
+        processor.max_buffered_coords = Some(3);
+        assert!(WktStr("MULTIPOINT(0 0,2 0,0 2)")
+            .process_geom(&mut processor)
+            .is_ok());
+        processor.finish().unwrap();
+        assert_eq!(
+            WktStr("MULTIPOINT(0 0,2 0,0 2,2 2)")
+                .process_geom(&mut processor)
+                .unwrap_err()
+                .to_string(),
+            "processing geometry `geometry exceeds the limit of 3 buffered coordinates`"
+        );
+    }
+}
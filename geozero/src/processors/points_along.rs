@@ -0,0 +1,213 @@
+use super::Coord;
+use crate::error::Result;
+use crate::GeomProcessor;
+
+/// Replace linestrings with points at regular intervals, e.g. for placing labels along a route.
+///
+/// Each LineString becomes a MultiPoint with points every `distance` along the line,
+/// starting with the first vertex. Z and M values are interpolated linearly. The lines
+/// of a MultiLineString are processed separately and their points are combined into one
+/// MultiPoint. Other geometries are forwarded unchanged.
+pub struct PointsAlongProcessor<P: GeomProcessor> {
+    /// Distance between points, `0` emits only the start (and end) point
+    pub distance: f64,
+    /// Add the last vertex if it is not at a multiple of `distance`
+    pub include_end: bool,
+    /// Maximal number of buffered coordinates, larger linestrings are rejected
+    pub max_buffered_coords: Option<usize>,
+    inner: P,
+    line: Vec<Coord>,
+    points: Vec<Coord>,
+    buffering: bool,
+    in_multi: bool,
+}
+
+impl<P: GeomProcessor> PointsAlongProcessor<P> {
+    pub fn new(inner: P, distance: f64) -> Self {
+        PointsAlongProcessor {
+            distance,
+            include_end: false,
+            max_buffered_coords: None,
+            inner,
+            line: Vec::new(),
+            points: Vec::new(),
+            buffering: false,
+            in_multi: false,
+        }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    /// Append the points along the buffered line.
+    fn interpolate(&mut self) {
+        let Some(&start) = self.line.first() else {
+            return;
+        };
+        self.points.push(start);
+        let mut at_end = self.line.len() == 1;
+        if self.distance > 0.0 {
+            let mut k = 1;
+            let mut offset = 0.0;
+            for w in self.line.windows(2) {
+                let (a, b) = (w[0], w[1]);
+                let len = (b.0 - a.0).hypot(b.1 - a.1);
+                if len == 0.0 {
+                    continue;
+                }
+                at_end = false;
+                let mut target = k as f64 * self.distance;
+                while target <= offset + len {
+                    let f = (target - offset) / len;
+                    self.points.push((
+                        a.0 + (b.0 - a.0) * f,
+                        a.1 + (b.1 - a.1) * f,
+                        super::lerp(a.2, b.2, f),
+                        super::lerp(a.3, b.3, f),
+                    ));
+                    at_end = f == 1.0;
+                    k += 1;
+                    target = k as f64 * self.distance;
+                }
+                offset += len;
+            }
+        }
+        if self.include_end && !at_end {
+            if let Some(&end) = self.line.last() {
+                self.points.push(end);
+            }
+        }
+    }
+
+    fn emit(&mut self, idx: usize) -> Result<()> {
+        let points = std::mem::take(&mut self.points);
+        let multi_dim = self.inner.multi_dim();
+        self.inner.multipoint_begin(points.len(), idx)?;
+        for (i, &(x, y, z, m)) in points.iter().enumerate() {
+            if multi_dim {
+                self.inner.coordinate(x, y, z, m, None, None, i)?;
+            } else {
+                self.inner.xy(x, y, i)?;
+            }
+        }
+        self.inner.multipoint_end(idx)
+    }
+}
+
+impl<P: GeomProcessor> GeomProcessor for PointsAlongProcessor<P> {
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        self.coordinate(x, y, None, None, None, None, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        if self.buffering {
+            super::check_buffer_limit(self.line.len(), self.max_buffered_coords)?;
+            self.line.push((x, y, z, m));
+            Ok(())
+        } else if self.inner.multi_dim() {
+            self.inner.coordinate(x, y, z, m, t, tm, idx)
+        } else {
+            self.inner.xy(x, y, idx)
+        }
+    }
+    fn linestring_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        if !tagged && !self.in_multi {
+            // polygon ring or curve segment
+            return self.inner.linestring_begin(tagged, size, idx);
+        }
+        self.buffering = true;
+        self.line.clear();
+        Ok(())
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        if !self.buffering {
+            return self.inner.linestring_end(tagged, idx);
+        }
+        self.buffering = false;
+        self.interpolate();
+        if self.in_multi {
+            Ok(())
+        } else {
+            self.emit(idx)
+        }
+    }
+    fn multilinestring_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.in_multi = true;
+        self.points.clear();
+        Ok(())
+    }
+    fn multilinestring_end(&mut self, idx: usize) -> Result<()> {
+        self.in_multi = false;
+        self.emit(idx)
+    }
+    forward_geom_processor!(inner;
+        dimensions, srid, empty_point, point_begin, point_end,
+        multipoint_begin, multipoint_end, polygon_begin, polygon_end,
+        multipolygon_begin, multipolygon_end, geometrycollection_begin, geometrycollection_end,
+        circularstring_begin, circularstring_end, compoundcurve_begin, compoundcurve_end,
+        curvepolygon_begin, curvepolygon_end, multicurve_begin, multicurve_end,
+        multisurface_begin, multisurface_end, triangle_begin, triangle_end,
+        polyhedralsurface_begin, polyhedralsurface_end, tin_begin, tin_end,
+    );
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+    use crate::wkt::WktStr;
+    use crate::{GeozeroGeometry, ProcessorSink};
+
+    fn points_along(wkt: &str, distance: f64, include_end: bool) -> String {
+        process_wkt!(wkt, |w| {
+            let mut processor = PointsAlongProcessor::new(w, distance);
+            processor.include_end = include_end;
+            processor
+        })
+    }
+
+    #[test]
+    fn regular_points() {
+        assert_eq!(
+            points_along("LINESTRING(0 0,10 0)", 2.5, false),
+            "MULTIPOINT(0 0,2.5 0,5 0,7.5 0,10 0)"
+        );
+        assert_eq!(
+            points_along("LINESTRING(0 0,10 0)", 2.5, true),
+            "MULTIPOINT(0 0,2.5 0,5 0,7.5 0,10 0)"
+        );
+        assert_eq!(
+            points_along("LINESTRING(0 0,3 0,3 3)", 2.5, true),
+            "MULTIPOINT(0 0,2.5 0,3 2,3 3)"
+        );
+        assert_eq!(
+            points_along("MULTILINESTRING((0 0,0 3),(5 5,5 4))", 2.5, false),
+            "MULTIPOINT(0 0,0 2.5,5 5)"
+        );
+    }
+
+    #[test]
+    fn buffer_limit() {
+        let mut processor = PointsAlongProcessor::new(ProcessorSink, 1.0);
+        processor.max_buffered_coords = Some(2);
+        assert!(WktStr("MULTILINESTRING((0 0,0 3),(5 5,5 4))")
+            .process_geom(&mut processor)
+            .is_ok());
+        assert_eq!(
+            WktStr("LINESTRING(0 0,3 0,3 3)")
+                .process_geom(&mut processor)
+                .unwrap_err()
+                .to_string(),
+            "processing geometry `geometry exceeds the limit of 2 buffered coordinates`"
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use super::Coord;
+use crate::error::Result;
+use crate::GeomProcessor;
+
+/// Close open polygon and triangle rings by appending the first vertex.
+///
+/// Rings are buffered, so the ring size passed to the inner processor includes
+/// the added vertex, e.g. for writing correct WKB ring counts. Rings are closed
+/// if first and last vertex differ in x/y, linestrings are forwarded unchanged.
+pub struct PolygonCloseProcessor<P: GeomProcessor> {
+    /// Maximal number of buffered coordinates, larger rings are rejected
+    pub max_buffered_coords: Option<usize>,
+    inner: P,
+    buffer: Vec<Coord>,
+    buffering: bool,
+    in_polygon: bool,
+}
+
+impl<P: GeomProcessor> PolygonCloseProcessor<P> {
+    pub fn new(inner: P) -> Self {
+        PolygonCloseProcessor {
+            max_buffered_coords: None,
+            inner,
+            buffer: Vec::new(),
+            buffering: false,
+            in_polygon: false,
+        }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+}
+
+impl<P: GeomProcessor> GeomProcessor for PolygonCloseProcessor<P> {
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        self.coordinate(x, y, None, None, None, None, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        if self.buffering {
+            super::check_buffer_limit(self.buffer.len(), self.max_buffered_coords)?;
+            self.buffer.push((x, y, z, m));
+            Ok(())
+        } else if self.inner.multi_dim() {
+            self.inner.coordinate(x, y, z, m, t, tm, idx)
+        } else {
+            self.inner.xy(x, y, idx)
+        }
+    }
+    fn linestring_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        if tagged || !self.in_polygon {
+            return self.inner.linestring_begin(tagged, size, idx);
+        }
+        self.buffering = true;
+        self.buffer.clear();
+        Ok(())
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        if !self.buffering {
+            return self.inner.linestring_end(tagged, idx);
+        }
+        self.buffering = false;
+        let mut ring = std::mem::take(&mut self.buffer);
+        if let (Some(first), Some(last)) = (ring.first(), ring.last()) {
+            if (first.0, first.1) != (last.0, last.1) {
+                ring.push(*first);
+            }
+        }
+        super::emit_line(&mut self.inner, &ring, tagged, idx)?;
+        self.buffer = ring;
+        Ok(())
+    }
+    fn polygon_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.in_polygon = true;
+        self.inner.polygon_begin(tagged, size, idx)
+    }
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.in_polygon = false;
+        self.inner.polygon_end(tagged, idx)
+    }
+    fn triangle_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.in_polygon = true;
+        self.inner.triangle_begin(tagged, size, idx)
+    }
+    fn triangle_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.in_polygon = false;
+        self.inner.triangle_end(tagged, idx)
+    }
+    forward_geom_processor!(inner;
+        dimensions, srid, empty_point, point_begin, point_end,
+        multipoint_begin, multipoint_end,
+        multilinestring_begin, multilinestring_end,
+        multipolygon_begin, multipolygon_end, geometrycollection_begin, geometrycollection_end,
+        circularstring_begin, circularstring_end, compoundcurve_begin, compoundcurve_end,
+        curvepolygon_begin, curvepolygon_end, multicurve_begin, multicurve_end,
+        multisurface_begin, multisurface_end,
+        polyhedralsurface_begin, polyhedralsurface_end, tin_begin, tin_end,
+    );
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkb")]
+mod test {
+    use super::*;
+    use crate::wkb::{Wkb, WkbDialect, WkbWriter};
+    use crate::wkt::WktStr;
+    use crate::{GeozeroGeometry, ToWkt};
+
+    #[test]
+    fn wkb_roundtrip() {
+        let mut wkb: Vec<u8> = Vec::new();
+        let mut processor = PolygonCloseProcessor::new(WkbWriter::new(&mut wkb, WkbDialect::Wkb));
+        WktStr("MULTIPOLYGON(((0 0,4 0,4 4,0 4)),((5 5,6 5,6 6,5 5)))")
+            .process_geom(&mut processor)
+            .unwrap();
+        assert_eq!(
+            Wkb(wkb.clone()).to_wkt().unwrap(),
+            "MULTIPOLYGON(((0 0,4 0,4 4,0 4,0 0)),((5 5,6 5,6 6,5 5)))"
+        );
+        // vertex count of the first ring, after the multipolygon and polygon headers and counts
+        assert_eq!(wkb[18..22], 5u32.to_le_bytes());
+    }
+}
@@ -0,0 +1,142 @@
+use crate::error::Result;
+use crate::GeomProcessor;
+
+/// TopoJSON quantization transform.
+///
+/// Maps coordinates to integer positions on a grid of `n` x `n` cells covering
+/// the bounding box: `qx = round((x - translate[0]) / scale[0])`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QuantizeTransform {
+    pub scale: [f64; 2],
+    pub translate: [f64; 2],
+}
+
+impl QuantizeTransform {
+    /// Transform for bounds `[minx, miny, maxx, maxy]` and quantization `n` (at least 2).
+    pub fn from_bounds(bounds: [f64; 4], n: u32) -> Self {
+        let [minx, miny, maxx, maxy] = bounds;
+        let cells = n.saturating_sub(1).max(1) as f64;
+        let step = |min: f64, max: f64| {
+            if max > min {
+                (max - min) / cells
+            } else {
+                1.0
+            }
+        };
+        QuantizeTransform {
+            scale: [step(minx, maxx), step(miny, maxy)],
+            translate: [minx, miny],
+        }
+    }
+
+    /// Quantize a position. The error of the dequantized position is at most `scale / 2`.
+    pub fn quantize(&self, x: f64, y: f64) -> [i64; 2] {
+        [
+            ((x - self.translate[0]) / self.scale[0]).round() as i64,
+            ((y - self.translate[1]) / self.scale[1]).round() as i64,
+        ]
+    }
+
+    pub fn dequantize(&self, pos: [i64; 2]) -> (f64, f64) {
+        (
+            pos[0] as f64 * self.scale[0] + self.translate[0],
+            pos[1] as f64 * self.scale[1] + self.translate[1],
+        )
+    }
+}
+
+/// Collect quantized integer coordinates of processed geometries.
+///
+/// Each linestring and ring yields one position array, as do the points of a
+/// point or multipoint geometry. Positions are absolute, not delta-encoded.
+pub struct QuantizeProcessor {
+    pub transform: QuantizeTransform,
+    /// Quantized position arrays
+    pub arrays: Vec<Vec<[i64; 2]>>,
+    in_multipoint: bool,
+}
+
+impl QuantizeProcessor {
+    pub fn new(transform: QuantizeTransform) -> Self {
+        QuantizeProcessor {
+            transform,
+            arrays: Vec::new(),
+            in_multipoint: false,
+        }
+    }
+}
+
+impl GeomProcessor for QuantizeProcessor {
+    fn xy(&mut self, x: f64, y: f64, _idx: usize) -> Result<()> {
+        let pos = self.transform.quantize(x, y);
+        if let Some(array) = self.arrays.last_mut() {
+            array.push(pos);
+        }
+        Ok(())
+    }
+    fn point_begin(&mut self, _idx: usize) -> Result<()> {
+        if !self.in_multipoint {
+            self.arrays.push(Vec::new());
+        }
+        Ok(())
+    }
+    fn multipoint_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
+        self.in_multipoint = true;
+        self.arrays.push(Vec::with_capacity(size));
+        Ok(())
+    }
+    fn multipoint_end(&mut self, _idx: usize) -> Result<()> {
+        self.in_multipoint = false;
+        Ok(())
+    }
+    fn linestring_begin(&mut self, _tagged: bool, size: usize, _idx: usize) -> Result<()> {
+        self.arrays.push(Vec::with_capacity(size));
+        Ok(())
+    }
+    fn circularstring_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
+        self.arrays.push(Vec::with_capacity(size));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let transform = QuantizeTransform::from_bounds([-10.0, 40.0, 25.5, 60.0], 10_000);
+        for i in 0..1000 {
+            let x = -10.0 + 35.5 * (i as f64 * 0.618).fract();
+            let y = 40.0 + 20.0 * (i as f64 * 0.377).fract();
+            let (dx, dy) = transform.dequantize(transform.quantize(x, y));
+            assert!((dx - x).abs() <= transform.scale[0] / 2.0 + 1e-12);
+            assert!((dy - y).abs() <= transform.scale[1] / 2.0 + 1e-12);
+        }
+        assert_eq!(transform.quantize(25.5, 60.0), [9999, 9999]);
+    }
+
+    #[test]
+    #[cfg(feature = "with-wkt")]
+    fn quantized_arrays() {
+        use crate::wkt::WktStr;
+        use crate::GeozeroGeometry;
+
+        let transform = QuantizeTransform::from_bounds([0.0, 0.0, 10.0, 10.0], 11);
+        let mut processor = QuantizeProcessor::new(transform);
+        WktStr("POLYGON((0 0,10 0,10 10,0 0),(2.2 2.6,4 2,4 4,2.2 2.6))")
+            .process_geom(&mut processor)
+            .unwrap();
+        WktStr("MULTIPOINT(1 1,7.4 3)")
+            .process_geom(&mut processor)
+            .unwrap();
+        assert_eq!(
+            processor.arrays,
+            vec![
+                vec![[0, 0], [10, 0], [10, 10], [0, 0]],
+                vec![[2, 3], [4, 2], [4, 4], [2, 3]],
+                vec![[1, 1], [7, 3]],
+            ]
+        );
+    }
+}
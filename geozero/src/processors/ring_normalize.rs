@@ -0,0 +1,178 @@
+use super::Coord;
+use crate::error::Result;
+use crate::GeomProcessor;
+use std::cmp::Ordering;
+
+/// Rotate and orient closed polygon rings into a canonical form.
+///
+/// Each closed ring starts at its lexicographically smallest vertex (by x, then y) and
+/// continues towards the smaller of its two neighbours, so rings with the same vertices
+/// but a different start vertex or direction are forwarded identically.
+/// `RingNormalizeProcessor::new(GeomHasher::new())` computes a stable fingerprint for deduplication
+/// ignoring ring rotation (see [GeomHasher](super::GeomHasher)).
+/// The ring orientation is not preserved. Open rings are forwarded unchanged.
+pub struct RingNormalizeProcessor<P: GeomProcessor> {
+    /// Maximal number of buffered coordinates, larger rings are rejected
+    pub max_buffered_coords: Option<usize>,
+    inner: P,
+    buffer: Vec<Coord>,
+    buffering: bool,
+    in_polygon: bool,
+}
+
+impl<P: GeomProcessor> RingNormalizeProcessor<P> {
+    pub fn new(inner: P) -> Self {
+        RingNormalizeProcessor {
+            max_buffered_coords: None,
+            inner,
+            buffer: Vec::new(),
+            buffering: false,
+            in_polygon: false,
+        }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+}
+
+fn cmp_xy(a: &Coord, b: &Coord) -> Ordering {
+    a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1))
+}
+
+/// Canonical form of a closed ring.
+fn normalize(ring: &[Coord]) -> Vec<Coord> {
+    let closed = match (ring.first(), ring.last()) {
+        (Some(first), Some(last)) => ring.len() > 3 && cmp_xy(first, last).is_eq(),
+        _ => false,
+    };
+    if !closed {
+        return ring.to_vec();
+    }
+    let vertices = &ring[..ring.len() - 1];
+    let n = vertices.len();
+    let Some(min) = vertices.iter().min_by(|a, b| cmp_xy(a, b)) else {
+        return ring.to_vec();
+    };
+    // candidates starting at each minimal vertex in both directions
+    let mut best: Option<Vec<Coord>> = None;
+    for start in (0..n).filter(|&i| cmp_xy(&vertices[i], min).is_eq()) {
+        let forward = (0..n).map(|i| vertices[(start + i) % n]);
+        let backward = (0..n).map(|i| vertices[(start + n - i) % n]);
+        for candidate in [forward.collect::<Vec<_>>(), backward.collect()] {
+            let smaller = best.as_ref().is_none_or(|best| {
+                candidate
+                    .iter()
+                    .zip(best)
+                    .map(|(a, b)| cmp_xy(a, b))
+                    .find(|ord| ord.is_ne())
+                    .is_some_and(Ordering::is_lt)
+            });
+            if smaller {
+                best = Some(candidate);
+            }
+        }
+    }
+    let mut ring = best.unwrap_or_default();
+    ring.push(ring[0]);
+    ring
+}
+
+impl<P: GeomProcessor> GeomProcessor for RingNormalizeProcessor<P> {
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        self.coordinate(x, y, None, None, None, None, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        if self.buffering {
+            super::check_buffer_limit(self.buffer.len(), self.max_buffered_coords)?;
+            self.buffer.push((x, y, z, m));
+            Ok(())
+        } else if self.inner.multi_dim() {
+            self.inner.coordinate(x, y, z, m, t, tm, idx)
+        } else {
+            self.inner.xy(x, y, idx)
+        }
+    }
+    fn linestring_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        if tagged || !self.in_polygon {
+            return self.inner.linestring_begin(tagged, size, idx);
+        }
+        self.buffering = true;
+        self.buffer.clear();
+        Ok(())
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        if !self.buffering {
+            return self.inner.linestring_end(tagged, idx);
+        }
+        self.buffering = false;
+        let ring = normalize(&self.buffer);
+        super::emit_line(&mut self.inner, &ring, tagged, idx)
+    }
+    fn polygon_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.in_polygon = true;
+        self.inner.polygon_begin(tagged, size, idx)
+    }
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.in_polygon = false;
+        self.inner.polygon_end(tagged, idx)
+    }
+    fn triangle_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.in_polygon = true;
+        self.inner.triangle_begin(tagged, size, idx)
+    }
+    fn triangle_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.in_polygon = false;
+        self.inner.triangle_end(tagged, idx)
+    }
+    forward_geom_processor!(inner;
+        dimensions, srid, empty_point, point_begin, point_end,
+        multipoint_begin, multipoint_end,
+        multilinestring_begin, multilinestring_end,
+        multipolygon_begin, multipolygon_end, geometrycollection_begin, geometrycollection_end,
+        circularstring_begin, circularstring_end, compoundcurve_begin, compoundcurve_end,
+        curvepolygon_begin, curvepolygon_end, multicurve_begin, multicurve_end,
+        multisurface_begin, multisurface_end,
+        polyhedralsurface_begin, polyhedralsurface_end, tin_begin, tin_end,
+    );
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+    use crate::processors::GeomHasher;
+    use crate::wkt::WktStr;
+    use crate::GeozeroGeometry;
+
+    fn fingerprint(wkt: &str) -> u64 {
+        let mut processor = RingNormalizeProcessor::new(GeomHasher::new());
+        WktStr(wkt).process_geom(&mut processor).unwrap();
+        processor.into_inner().finish()
+    }
+
+    #[test]
+    fn rotated_rings() {
+        let square = fingerprint("POLYGON((0 0,4 0,4 4,0 4,0 0))");
+        assert_eq!(square, fingerprint("POLYGON((4 4,0 4,0 0,4 0,4 4))"));
+        assert_eq!(square, fingerprint("POLYGON((4 0,0 0,0 4,4 4,4 0))"));
+        assert_ne!(square, fingerprint("POLYGON((0 0,4 0,4 5,0 4,0 0))"));
+
+        assert_eq!(
+            process_wkt!(
+                "POLYGON((4 4,0 4,0 0,4 0,4 4))",
+                RingNormalizeProcessor::new
+            ),
+            "POLYGON((0 0,0 4,4 4,4 0,0 0))"
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use crate::error::{GeozeroError, Result};
+use crate::GeomProcessor;
+
+/// Reject self-intersecting linestrings.
+///
+/// Linestrings (not polygon rings) are forwarded unchanged, an error naming the
+/// indices of the first pair of crossing or touching non-adjacent segments is returned
+/// at the end of a non-simple linestring. Closed linestrings may touch at the closing point.
+/// The check compares all segment pairs (O(n²)).
+pub struct SimpleLineProcessor<P: GeomProcessor> {
+    /// Maximal number of buffered coordinates, larger linestrings are rejected
+    pub max_buffered_coords: Option<usize>,
+    inner: P,
+    coords: Vec<(f64, f64)>,
+    polygon_depth: usize,
+}
+
+impl<P: GeomProcessor> SimpleLineProcessor<P> {
+    pub fn new(inner: P) -> Self {
+        SimpleLineProcessor {
+            max_buffered_coords: None,
+            inner,
+            coords: Vec::new(),
+            polygon_depth: 0,
+        }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    fn push(&mut self, x: f64, y: f64) -> Result<()> {
+        if self.polygon_depth == 0 {
+            super::check_buffer_limit(self.coords.len(), self.max_buffered_coords)?;
+            self.coords.push((x, y));
+        }
+        Ok(())
+    }
+
+    fn check(&self) -> Result<()> {
+        let segments: Vec<_> = self.coords.windows(2).map(|w| (w[0], w[1])).collect();
+        let closed = segments.len() > 2 && self.coords.first() == self.coords.last();
+        for (i, a) in segments.iter().enumerate() {
+            for (j, b) in segments.iter().enumerate().skip(i + 2) {
+                if closed && i == 0 && j == segments.len() - 1 {
+                    continue;
+                }
+                if intersects(*a, *b) {
+                    return Err(GeozeroError::Geometry(format!(
+                        "linestring self-intersects at segments {i} and {j}"
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+type Segment = ((f64, f64), (f64, f64));
+
+fn orientation(p: (f64, f64), q: (f64, f64), r: (f64, f64)) -> f64 {
+    (q.0 - p.0) * (r.1 - p.1) - (q.1 - p.1) * (r.0 - p.0)
+}
+
+/// Whether `r` lies within the bounding box of segment `p`-`q`.
+fn on_segment(p: (f64, f64), q: (f64, f64), r: (f64, f64)) -> bool {
+    r.0 >= p.0.min(q.0) && r.0 <= p.0.max(q.0) && r.1 >= p.1.min(q.1) && r.1 <= p.1.max(q.1)
+}
+
+fn intersects((p1, p2): Segment, (q1, q2): Segment) -> bool {
+    let d1 = orientation(q1, q2, p1);
+    let d2 = orientation(q1, q2, p2);
+    let d3 = orientation(p1, p2, q1);
+    let d4 = orientation(p1, p2, q2);
+    if ((d1 > 0.0 && d2 < 0.0) || (d1 < 0.0 && d2 > 0.0))
+        && ((d3 > 0.0 && d4 < 0.0) || (d3 < 0.0 && d4 > 0.0))
+    {
+        return true;
+    }
+    (d1 == 0.0 && on_segment(q1, q2, p1))
+        || (d2 == 0.0 && on_segment(q1, q2, p2))
+        || (d3 == 0.0 && on_segment(p1, p2, q1))
+        || (d4 == 0.0 && on_segment(p1, p2, q2))
+}
+
+impl<P: GeomProcessor> GeomProcessor for SimpleLineProcessor<P> {
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        self.push(x, y)?;
+        self.inner.xy(x, y, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        self.push(x, y)?;
+        self.inner.coordinate(x, y, z, m, t, tm, idx)
+    }
+    fn linestring_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.coords.clear();
+        self.inner.linestring_begin(tagged, size, idx)
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        if self.polygon_depth == 0 {
+            self.check()?;
+        }
+        self.inner.linestring_end(tagged, idx)
+    }
+    fn polygon_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.polygon_depth += 1;
+        self.inner.polygon_begin(tagged, size, idx)
+    }
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.polygon_depth -= 1;
+        self.inner.polygon_end(tagged, idx)
+    }
+    fn triangle_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.polygon_depth += 1;
+        self.inner.triangle_begin(tagged, size, idx)
+    }
+    fn triangle_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.polygon_depth -= 1;
+        self.inner.triangle_end(tagged, idx)
+    }
+    forward_geom_processor!(inner;
+        dimensions, srid, empty_point, point_begin, point_end,
+        multipoint_begin, multipoint_end,
+        multilinestring_begin, multilinestring_end,
+        multipolygon_begin, multipolygon_end, geometrycollection_begin, geometrycollection_end,
+        circularstring_begin, circularstring_end, compoundcurve_begin, compoundcurve_end,
+        curvepolygon_begin, curvepolygon_end, multicurve_begin, multicurve_end,
+        multisurface_begin, multisurface_end,
+        polyhedralsurface_begin, polyhedralsurface_end, tin_begin, tin_end,
+    );
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+    use crate::wkt::WktStr;
+    use crate::{GeozeroGeometry, ProcessorSink};
+
+    fn check(wkt: &str) -> Result<()> {
+        WktStr(wkt).process_geom(&mut SimpleLineProcessor::new(ProcessorSink))
+    }
+
+    #[test]
+    fn bowtie() {
+        assert_eq!(
+            check("LINESTRING(0 0,2 2,2 0,0 2)")
+                .unwrap_err()
+                .to_string(),
+            "processing geometry `linestring self-intersects at segments 0 and 2`"
+        );
+        assert!(check("LINESTRING(0 0,2 2,2 0,0 2,0 0)").is_err());
+    }
+
+    #[test]
+    fn simple_lines() {
+        assert!(check("LINESTRING(0 0,1 1,2 0,3 1)").is_ok());
+        // closed linestring touching at its start point
+        assert!(check("LINESTRING(0 0,2 0,2 2,0 0)").is_ok());
+        // rings of polygons are not checked
+        assert!(check("POLYGON((0 0,2 2,2 0,0 2,0 0))").is_ok());
+    }
+
+    #[test]
+    fn buffer_limit() {
+        let mut processor = SimpleLineProcessor::new(ProcessorSink);
+        processor.max_buffered_coords = Some(3);
+        assert_eq!(
+            WktStr("LINESTRING(0 0,1 1,2 0,3 1)")
+                .process_geom(&mut processor)
+                .unwrap_err()
+                .to_string(),
+            "processing geometry `geometry exceeds the limit of 3 buffered coordinates`"
+        );
+        // rings of polygons are not buffered
+        assert!(WktStr("POLYGON((0 0,2 0,2 2,0 0))")
+            .process_geom(&mut processor)
+            .is_ok());
+    }
+}
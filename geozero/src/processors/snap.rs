@@ -0,0 +1,100 @@
+use crate::error::Result;
+use crate::GeomProcessor;
+
+/// Snap coordinates to a grid, e.g. before writing into a database with limited precision.
+///
+/// Each axis has its own grid size, a size of `0` keeps the axis unchanged.
+/// M values are not snapped.
+pub struct SnapProcessor<P: GeomProcessor> {
+    /// Grid size of x axis
+    pub grid_x: f64,
+    /// Grid size of y axis
+    pub grid_y: f64,
+    /// Grid size of z axis
+    pub grid_z: f64,
+    inner: P,
+}
+
+impl<P: GeomProcessor> SnapProcessor<P> {
+    /// Snap all axes to the same grid size.
+    pub fn new(inner: P, grid: f64) -> Self {
+        SnapProcessor {
+            grid_x: grid,
+            grid_y: grid,
+            grid_z: grid,
+            inner,
+        }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+}
+
+pub(super) fn snap(value: f64, grid: f64) -> f64 {
+    if grid > 0.0 {
+        (value / grid).round() * grid
+    } else {
+        value
+    }
+}
+
+impl<P: GeomProcessor> GeomProcessor for SnapProcessor<P> {
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        self.inner
+            .xy(snap(x, self.grid_x), snap(y, self.grid_y), idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        self.inner.coordinate(
+            snap(x, self.grid_x),
+            snap(y, self.grid_y),
+            z.map(|z| snap(z, self.grid_z)),
+            m,
+            t,
+            tm,
+            idx,
+        )
+    }
+    forward_geom_processor!(inner;
+        dimensions, srid, empty_point, point_begin, point_end,
+        multipoint_begin, multipoint_end, linestring_begin, linestring_end,
+        multilinestring_begin, multilinestring_end, polygon_begin, polygon_end,
+        multipolygon_begin, multipolygon_end, geometrycollection_begin, geometrycollection_end,
+        circularstring_begin, circularstring_end, compoundcurve_begin, compoundcurve_end,
+        curvepolygon_begin, curvepolygon_end, multicurve_begin, multicurve_end,
+        multisurface_begin, multisurface_end, triangle_begin, triangle_end,
+        polyhedralsurface_begin, polyhedralsurface_end, tin_begin, tin_end,
+    );
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+    use crate::wkt::WktWriter;
+    use crate::CoordDimensions;
+
+    #[test]
+    fn per_axis_grid() {
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = WktWriter::new(&mut out);
+        writer.dims = CoordDimensions::xyz();
+        let mut processor = SnapProcessor::new(writer, 0.25);
+        processor.grid_z = 2.0;
+        processor.point_begin(0).unwrap();
+        processor
+            .coordinate(1.3, -5.6, Some(10.9), None, None, None, 0)
+            .unwrap();
+        processor.point_end(0).unwrap();
+        assert_eq!(std::str::from_utf8(&out).unwrap(), "POINT(1.25 -5.5 10)");
+    }
+}
@@ -0,0 +1,134 @@
+use super::snap::snap;
+use super::Coord;
+use crate::error::Result;
+use crate::GeomProcessor;
+
+/// Snap coordinates to a grid and remove the resulting duplicate vertices in one pass.
+///
+/// Coordinates are snapped like with [SnapProcessor](super::SnapProcessor). Linestrings
+/// and rings are buffered and consecutive vertices collapsing to the same grid position
+/// in x/y are merged. Rings stay closed, but may become degenerate, e.g. with
+/// [CleanProcessor](super::CleanProcessor) they can be removed afterwards.
+pub struct SnapDedupProcessor<P: GeomProcessor> {
+    /// Grid size of x axis
+    pub grid_x: f64,
+    /// Grid size of y axis
+    pub grid_y: f64,
+    /// Grid size of z axis
+    pub grid_z: f64,
+    /// Maximal number of buffered coordinates, larger linestrings are rejected
+    pub max_buffered_coords: Option<usize>,
+    inner: P,
+    buffer: Vec<Coord>,
+    buffering: bool,
+    in_polygon: bool,
+}
+
+impl<P: GeomProcessor> SnapDedupProcessor<P> {
+    /// Snap all axes to the same grid size.
+    pub fn new(inner: P, grid: f64) -> Self {
+        SnapDedupProcessor {
+            grid_x: grid,
+            grid_y: grid,
+            grid_z: grid,
+            max_buffered_coords: None,
+            inner,
+            buffer: Vec::new(),
+            buffering: false,
+            in_polygon: false,
+        }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+}
+
+impl<P: GeomProcessor> GeomProcessor for SnapDedupProcessor<P> {
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        self.coordinate(x, y, None, None, None, None, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        let (x, y) = (snap(x, self.grid_x), snap(y, self.grid_y));
+        let z = z.map(|z| snap(z, self.grid_z));
+        if self.buffering {
+            if self.buffer.last().is_some_and(|c| (c.0, c.1) == (x, y)) {
+                return Ok(());
+            }
+            super::check_buffer_limit(self.buffer.len(), self.max_buffered_coords)?;
+            self.buffer.push((x, y, z, m));
+            Ok(())
+        } else if self.inner.multi_dim() {
+            self.inner.coordinate(x, y, z, m, t, tm, idx)
+        } else {
+            self.inner.xy(x, y, idx)
+        }
+    }
+    fn linestring_begin(&mut self, _tagged: bool, _size: usize, _idx: usize) -> Result<()> {
+        self.buffering = true;
+        self.buffer.clear();
+        Ok(())
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.buffering = false;
+        if self.in_polygon && !tagged {
+            // a collapsed closing vertex keeps its position, so only open input rings get closed
+            if let (Some(&first), Some(last)) = (self.buffer.first(), self.buffer.last()) {
+                if self.buffer.len() > 1 && (first.0, first.1) != (last.0, last.1) {
+                    self.buffer.push(first);
+                }
+            }
+        }
+        super::emit_line(&mut self.inner, &self.buffer, tagged, idx)
+    }
+    fn polygon_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.in_polygon = true;
+        self.inner.polygon_begin(tagged, size, idx)
+    }
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.in_polygon = false;
+        self.inner.polygon_end(tagged, idx)
+    }
+    forward_geom_processor!(inner;
+        dimensions, srid, empty_point, point_begin, point_end,
+        multipoint_begin, multipoint_end,
+        multilinestring_begin, multilinestring_end,
+        multipolygon_begin, multipolygon_end, geometrycollection_begin, geometrycollection_end,
+        circularstring_begin, circularstring_end, compoundcurve_begin, compoundcurve_end,
+        curvepolygon_begin, curvepolygon_end, multicurve_begin, multicurve_end,
+        multisurface_begin, multisurface_end, triangle_begin, triangle_end,
+        polyhedralsurface_begin, polyhedralsurface_end, tin_begin, tin_end,
+    );
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+
+    fn snap_dedup(wkt: &str, grid: f64) -> String {
+        process_wkt!(wkt, |w| SnapDedupProcessor::new(w, grid))
+    }
+
+    #[test]
+    fn collapsed_vertices() {
+        assert_eq!(
+            snap_dedup("LINESTRING(0 0,0.9 1.1,1.2 0.8,2 2)", 1.0),
+            "LINESTRING(0 0,1 1,2 2)"
+        );
+        // last vertex before the closing vertex collapses with it
+        assert_eq!(
+            snap_dedup("POLYGON((0.1 0,4 0.2,3.9 4,0.2 0.1,0 0))", 1.0),
+            "POLYGON((0 0,4 0,4 4,0 0))"
+        );
+    }
+}
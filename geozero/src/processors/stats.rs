@@ -0,0 +1,200 @@
+use crate::error::Result;
+use crate::{CoordDimensions, GeomProcessor};
+use std::collections::BTreeMap;
+
+/// Count top-level geometries by type and dimensionality, e.g. `PointZ: 40, Point: 10`.
+///
+/// The dimensionality is derived from the Z and M values passed to coordinate callbacks,
+/// geometries without coordinates are counted as 2D.
+#[derive(Default)]
+pub struct GeomStats {
+    /// Number of geometries per type name with dimension suffix (`Z`, `M` or `ZM`)
+    pub counts: BTreeMap<String, usize>,
+    depth: usize,
+    geometry_type: &'static str,
+    has_z: bool,
+    has_m: bool,
+}
+
+impl GeomStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn begin(&mut self, geometry_type: &'static str) -> Result<()> {
+        if self.depth == 0 {
+            self.geometry_type = geometry_type;
+            self.has_z = false;
+            self.has_m = false;
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    fn end(&mut self) -> Result<()> {
+        self.depth -= 1;
+        if self.depth == 0 {
+            let suffix = match (self.has_z, self.has_m) {
+                (false, false) => "",
+                (true, false) => "Z",
+                (false, true) => "M",
+                (true, true) => "ZM",
+            };
+            *self
+                .counts
+                .entry(format!("{}{suffix}", self.geometry_type))
+                .or_default() += 1;
+        }
+        Ok(())
+    }
+}
+
+impl GeomProcessor for GeomStats {
+    fn dimensions(&self) -> CoordDimensions {
+        CoordDimensions::xyzm()
+    }
+    fn coordinate(
+        &mut self,
+        _x: f64,
+        _y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        _t: Option<f64>,
+        _tm: Option<u64>,
+        _idx: usize,
+    ) -> Result<()> {
+        self.has_z |= z.is_some();
+        self.has_m |= m.is_some();
+        Ok(())
+    }
+    fn empty_point(&mut self, _idx: usize) -> Result<()> {
+        self.begin("Point")?;
+        self.end()
+    }
+    fn point_begin(&mut self, _idx: usize) -> Result<()> {
+        self.begin("Point")
+    }
+    fn point_end(&mut self, _idx: usize) -> Result<()> {
+        self.end()
+    }
+    fn multipoint_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.begin("MultiPoint")
+    }
+    fn multipoint_end(&mut self, _idx: usize) -> Result<()> {
+        self.end()
+    }
+    fn linestring_begin(&mut self, _tagged: bool, _size: usize, _idx: usize) -> Result<()> {
+        self.begin("LineString")
+    }
+    fn linestring_end(&mut self, _tagged: bool, _idx: usize) -> Result<()> {
+        self.end()
+    }
+    fn multilinestring_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.begin("MultiLineString")
+    }
+    fn multilinestring_end(&mut self, _idx: usize) -> Result<()> {
+        self.end()
+    }
+    fn polygon_begin(&mut self, _tagged: bool, _size: usize, _idx: usize) -> Result<()> {
+        self.begin("Polygon")
+    }
+    fn polygon_end(&mut self, _tagged: bool, _idx: usize) -> Result<()> {
+        self.end()
+    }
+    fn multipolygon_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.begin("MultiPolygon")
+    }
+    fn multipolygon_end(&mut self, _idx: usize) -> Result<()> {
+        self.end()
+    }
+    fn geometrycollection_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.begin("GeometryCollection")
+    }
+    fn geometrycollection_end(&mut self, _idx: usize) -> Result<()> {
+        self.end()
+    }
+    fn circularstring_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.begin("CircularString")
+    }
+    fn circularstring_end(&mut self, _idx: usize) -> Result<()> {
+        self.end()
+    }
+    fn compoundcurve_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.begin("CompoundCurve")
+    }
+    fn compoundcurve_end(&mut self, _idx: usize) -> Result<()> {
+        self.end()
+    }
+    fn curvepolygon_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.begin("CurvePolygon")
+    }
+    fn curvepolygon_end(&mut self, _idx: usize) -> Result<()> {
+        self.end()
+    }
+    fn multicurve_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.begin("MultiCurve")
+    }
+    fn multicurve_end(&mut self, _idx: usize) -> Result<()> {
+        self.end()
+    }
+    fn multisurface_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.begin("MultiSurface")
+    }
+    fn multisurface_end(&mut self, _idx: usize) -> Result<()> {
+        self.end()
+    }
+    fn triangle_begin(&mut self, _tagged: bool, _size: usize, _idx: usize) -> Result<()> {
+        self.begin("Triangle")
+    }
+    fn triangle_end(&mut self, _tagged: bool, _idx: usize) -> Result<()> {
+        self.end()
+    }
+    fn polyhedralsurface_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.begin("PolyhedralSurface")
+    }
+    fn polyhedralsurface_end(&mut self, _idx: usize) -> Result<()> {
+        self.end()
+    }
+    fn tin_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.begin("Tin")
+    }
+    fn tin_end(&mut self, _idx: usize) -> Result<()> {
+        self.end()
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkb")]
+mod test {
+    use super::*;
+    use crate::wkb::Ewkb;
+    use crate::GeozeroGeometry;
+
+    #[test]
+    fn mixed_dimensions() {
+        let blobs = [
+            // SELECT 'POINT(10 -20)'::geometry
+            "0101000000000000000000244000000000000034C0",
+            // SELECT 'POINT(10 -20 100)'::geometry
+            "0101000080000000000000244000000000000034C00000000000005940",
+            // SELECT 'POINT(10 -20 100)'::geometry
+            "0101000080000000000000244000000000000034C00000000000005940",
+            // SELECT 'LINESTRING M(10 -20 1, 0 -0.5 2)'::geometry
+            "010200004002000000000000000000244000000000000034C0000000000000F03F0000000000000000000000000000E0BF0000000000000040",
+        ];
+        let mut stats = GeomStats::new();
+        for blob in blobs {
+            Ewkb(hex::decode(blob).unwrap())
+                .process_geom(&mut stats)
+                .unwrap();
+        }
+        assert_eq!(
+            stats.counts.into_iter().collect::<Vec<_>>(),
+            vec![
+                ("LineStringM".to_string(), 1),
+                ("Point".to_string(), 1),
+                ("PointZ".to_string(), 2),
+            ]
+        );
+    }
+}
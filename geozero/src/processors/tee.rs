@@ -0,0 +1,101 @@
+use crate::error::Result;
+use crate::{CoordDimensions, GeomProcessor};
+
+/// Forward all callbacks to two processors, e.g. for writing and analyzing a geometry in one pass.
+///
+/// The requested dimensions are the union of both processors. Each processor gets
+/// coordinates with [GeomProcessor::coordinate] or [GeomProcessor::xy] according to its own
+/// [GeomProcessor::multi_dim].
+pub struct TeeProcessor<P1: GeomProcessor, P2: GeomProcessor> {
+    pub first: P1,
+    pub second: P2,
+}
+
+impl<P1: GeomProcessor, P2: GeomProcessor> TeeProcessor<P1, P2> {
+    pub fn new(first: P1, second: P2) -> Self {
+        TeeProcessor { first, second }
+    }
+
+    pub fn into_inner(self) -> (P1, P2) {
+        (self.first, self.second)
+    }
+}
+
+macro_rules! tee {
+    ($($method:ident($($arg:ident: $ty:ty),*);)*) => {
+        $(
+            fn $method(&mut self, $($arg: $ty),*) -> Result<()> {
+                self.first.$method($($arg),*)?;
+                self.second.$method($($arg),*)
+            }
+        )*
+    };
+}
+
+impl<P1: GeomProcessor, P2: GeomProcessor> GeomProcessor for TeeProcessor<P1, P2> {
+    fn dimensions(&self) -> CoordDimensions {
+        let (first, second) = (self.first.dimensions(), self.second.dimensions());
+        CoordDimensions {
+            z: first.z || second.z,
+            m: first.m || second.m,
+            t: first.t || second.t,
+            tm: first.tm || second.tm,
+        }
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        if self.first.multi_dim() {
+            self.first.coordinate(x, y, z, m, t, tm, idx)?;
+        } else {
+            self.first.xy(x, y, idx)?;
+        }
+        if self.second.multi_dim() {
+            self.second.coordinate(x, y, z, m, t, tm, idx)
+        } else {
+            self.second.xy(x, y, idx)
+        }
+    }
+    tee! {
+        srid(srid: Option<i32>);
+        xy(x: f64, y: f64, idx: usize);
+        empty_point(idx: usize);
+        point_begin(idx: usize);
+        point_end(idx: usize);
+        multipoint_begin(size: usize, idx: usize);
+        multipoint_end(idx: usize);
+        linestring_begin(tagged: bool, size: usize, idx: usize);
+        linestring_end(tagged: bool, idx: usize);
+        multilinestring_begin(size: usize, idx: usize);
+        multilinestring_end(idx: usize);
+        polygon_begin(tagged: bool, size: usize, idx: usize);
+        polygon_end(tagged: bool, idx: usize);
+        multipolygon_begin(size: usize, idx: usize);
+        multipolygon_end(idx: usize);
+        geometrycollection_begin(size: usize, idx: usize);
+        geometrycollection_end(idx: usize);
+        circularstring_begin(size: usize, idx: usize);
+        circularstring_end(idx: usize);
+        compoundcurve_begin(size: usize, idx: usize);
+        compoundcurve_end(idx: usize);
+        curvepolygon_begin(size: usize, idx: usize);
+        curvepolygon_end(idx: usize);
+        multicurve_begin(size: usize, idx: usize);
+        multicurve_end(idx: usize);
+        multisurface_begin(size: usize, idx: usize);
+        multisurface_end(idx: usize);
+        triangle_begin(tagged: bool, size: usize, idx: usize);
+        triangle_end(tagged: bool, idx: usize);
+        polyhedralsurface_begin(size: usize, idx: usize);
+        polyhedralsurface_end(idx: usize);
+        tin_begin(size: usize, idx: usize);
+        tin_end(idx: usize);
+    }
+}
@@ -0,0 +1,155 @@
+use crate::error::Result;
+use crate::GeomProcessor;
+
+/// Trace the position of each coordinate within its geometry, for debugging coordinate order.
+///
+/// For every coordinate, the callback gets a path reconstructed from the begin/end
+/// callbacks, e.g. `poly[0].ring[1].coord[3] = (1 2)`. Untagged linestrings of polygons
+/// and triangles are named `ring`. All callbacks are forwarded to the inner processor unchanged.
+///
+/// Use `|trace| log::debug!("{trace}")` as callback for logging.
+pub struct TraceProcessor<P: GeomProcessor, F: FnMut(&str)> {
+    inner: P,
+    callback: F,
+    path: Vec<(&'static str, usize)>,
+}
+
+impl<P: GeomProcessor, F: FnMut(&str)> TraceProcessor<P, F> {
+    pub fn new(inner: P, callback: F) -> Self {
+        TraceProcessor {
+            inner,
+            callback,
+            path: Vec::new(),
+        }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    fn trace(&mut self, x: f64, y: f64, idx: usize) {
+        let mut trace = String::new();
+        for (name, idx) in &self.path {
+            trace.push_str(&format!("{name}[{idx}]."));
+        }
+        trace.push_str(&format!("coord[{idx}] = ({x} {y})"));
+        (self.callback)(&trace);
+    }
+
+    fn linestring_name(&self, tagged: bool) -> &'static str {
+        match self.path.last() {
+            Some(("poly" | "triangle", _)) if !tagged => "ring",
+            _ => "line",
+        }
+    }
+}
+
+macro_rules! trace_nesting {
+    ($($begin:ident, $end:ident => $name:literal;)*) => {
+        $(
+            fn $begin(&mut self, size: usize, idx: usize) -> Result<()> {
+                self.path.push(($name, idx));
+                self.inner.$begin(size, idx)
+            }
+            fn $end(&mut self, idx: usize) -> Result<()> {
+                self.path.pop();
+                self.inner.$end(idx)
+            }
+        )*
+    };
+}
+
+impl<P: GeomProcessor, F: FnMut(&str)> GeomProcessor for TraceProcessor<P, F> {
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        self.trace(x, y, idx);
+        self.inner.xy(x, y, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        self.trace(x, y, idx);
+        self.inner.coordinate(x, y, z, m, t, tm, idx)
+    }
+    fn point_begin(&mut self, idx: usize) -> Result<()> {
+        self.path.push(("point", idx));
+        self.inner.point_begin(idx)
+    }
+    fn point_end(&mut self, idx: usize) -> Result<()> {
+        self.path.pop();
+        self.inner.point_end(idx)
+    }
+    fn linestring_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.path.push((self.linestring_name(tagged), idx));
+        self.inner.linestring_begin(tagged, size, idx)
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.path.pop();
+        self.inner.linestring_end(tagged, idx)
+    }
+    fn polygon_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.path.push(("poly", idx));
+        self.inner.polygon_begin(tagged, size, idx)
+    }
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.path.pop();
+        self.inner.polygon_end(tagged, idx)
+    }
+    fn triangle_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.path.push(("triangle", idx));
+        self.inner.triangle_begin(tagged, size, idx)
+    }
+    fn triangle_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.path.pop();
+        self.inner.triangle_end(tagged, idx)
+    }
+    trace_nesting! {
+        multipoint_begin, multipoint_end => "multipoint";
+        multilinestring_begin, multilinestring_end => "multiline";
+        multipolygon_begin, multipolygon_end => "multipoly";
+        geometrycollection_begin, geometrycollection_end => "collection";
+        circularstring_begin, circularstring_end => "circularstring";
+        compoundcurve_begin, compoundcurve_end => "compoundcurve";
+        curvepolygon_begin, curvepolygon_end => "curvepoly";
+        multicurve_begin, multicurve_end => "multicurve";
+        multisurface_begin, multisurface_end => "multisurface";
+        polyhedralsurface_begin, polyhedralsurface_end => "polyhedralsurface";
+        tin_begin, tin_end => "tin";
+    }
+    forward_geom_processor!(inner; dimensions, srid, empty_point);
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+    use crate::wkt::{WktStr, WktWriter};
+    use crate::GeozeroGeometry;
+
+    #[test]
+    fn polygon_trace() {
+        let mut traces = Vec::new();
+        let mut out: Vec<u8> = Vec::new();
+        let mut processor = TraceProcessor::new(WktWriter::new(&mut out), |trace: &str| {
+            traces.push(trace.to_string())
+        });
+        WktStr("POLYGON((0 0,4 0,0 4,0 0),(1 1,2 1,1 2,1 1))")
+            .process_geom(&mut processor)
+            .unwrap();
+        drop(processor);
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            "POLYGON((0 0,4 0,0 4,0 0),(1 1,2 1,1 2,1 1))"
+        );
+        assert_eq!(traces.len(), 8);
+        assert_eq!(traces[0], "poly[0].ring[0].coord[0] = (0 0)");
+        assert_eq!(traces[2], "poly[0].ring[0].coord[2] = (0 4)");
+        assert_eq!(traces[7], "poly[0].ring[1].coord[3] = (1 1)");
+    }
+}
@@ -0,0 +1,87 @@
+use crate::error::Result;
+use crate::GeomProcessor;
+use std::collections::HashSet;
+
+/// Total and distinct vertex count of the last processed geometry, e.g. for data quality scoring.
+///
+/// Vertices are distinct if they differ in x/y. Note that closing vertices of rings
+/// are duplicates of the first vertex.
+#[derive(Default)]
+pub struct VertexCount {
+    /// Number of vertices
+    pub total: usize,
+    /// Number of distinct vertices
+    pub distinct: usize,
+    seen: HashSet<(u64, u64)>,
+    depth: usize,
+}
+
+impl VertexCount {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Share of duplicate vertices, `0` without vertices
+    pub fn duplicate_ratio(&self) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            (self.total - self.distinct) as f64 / self.total as f64
+        }
+    }
+
+    fn begin(&mut self, _idx: usize) -> Result<()> {
+        if self.depth == 0 {
+            self.total = 0;
+            self.distinct = 0;
+            self.seen.clear();
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    fn end(&mut self, _idx: usize) -> Result<()> {
+        self.depth -= 1;
+        Ok(())
+    }
+}
+
+impl GeomProcessor for VertexCount {
+    fn xy(&mut self, x: f64, y: f64, _idx: usize) -> Result<()> {
+        self.total += 1;
+        // Normalize -0.0
+        if self.seen.insert(((x + 0.0).to_bits(), (y + 0.0).to_bits())) {
+            self.distinct += 1;
+        }
+        Ok(())
+    }
+    fn empty_point(&mut self, idx: usize) -> Result<()> {
+        if self.depth == 0 {
+            self.begin(idx)?;
+            self.end(idx)?;
+        }
+        Ok(())
+    }
+    nesting_geom_processor!(begin, end);
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+    use crate::wkt::WktStr;
+    use crate::GeozeroGeometry;
+
+    #[test]
+    fn duplicates() {
+        let mut count = VertexCount::new();
+        WktStr("MULTILINESTRING((0 0,1 1,1 1,2 2),(2 2,0 0,3 3))")
+            .process_geom(&mut count)
+            .unwrap();
+        assert_eq!((count.total, count.distinct), (7, 4));
+        assert!((count.duplicate_ratio() - 3.0 / 7.0).abs() < 1e-12);
+
+        WktStr("POINT(1 1)").process_geom(&mut count).unwrap();
+        assert_eq!((count.total, count.distinct), (1, 1));
+    }
+}
@@ -0,0 +1,115 @@
+use crate::error::Result;
+use crate::GeomProcessor;
+
+/// Douglas-Peucker importance of the vertices of a line.
+///
+/// The importance of a vertex is the tolerance at which Douglas-Peucker simplification
+/// would remove it, so simplifying with tolerance `t` keeps the vertices with importance
+/// `> t`. Endpoints have infinite importance, collinear vertices zero importance.
+/// The importance of a vertex is never higher than the importance of the vertex which
+/// splits the line part it lies in.
+pub fn vertex_importance(coords: &[(f64, f64)]) -> Vec<f64> {
+    let mut importance = vec![0.0; coords.len()];
+    if let Some(last) = importance.len().checked_sub(1) {
+        importance[0] = f64::INFINITY;
+        importance[last] = f64::INFINITY;
+    }
+    // (first, last, importance of the splitting vertex)
+    let mut parts = vec![(0, coords.len().saturating_sub(1), f64::INFINITY)];
+    while let Some((first, last, limit)) = parts.pop() {
+        if last <= first + 1 {
+            continue;
+        }
+        let (a, b) = (coords[first], coords[last]);
+        let (mut max_dist, mut split) = (0.0, first + 1);
+        for (i, &p) in coords.iter().enumerate().take(last).skip(first + 1) {
+            let dist = segment_distance(p, a, b);
+            if dist > max_dist {
+                max_dist = dist;
+                split = i;
+            }
+        }
+        let value = max_dist.min(limit);
+        importance[split] = value;
+        parts.push((first, split, value));
+        parts.push((split, last, value));
+    }
+    importance
+}
+
+/// Distance of `p` to the line segment `a`-`b`.
+fn segment_distance(p: (f64, f64), a: (f64, f64), b: (f64, f64)) -> f64 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let len2 = dx * dx + dy * dy;
+    let t = if len2 == 0.0 {
+        0.0
+    } else {
+        (((p.0 - a.0) * dx + (p.1 - a.1) * dy) / len2).clamp(0.0, 1.0)
+    };
+    (p.0 - a.0 - t * dx).hypot(p.1 - a.1 - t * dy)
+}
+
+/// Collect the Douglas-Peucker vertex importance of linestrings and rings, e.g. for level of detail rendering.
+///
+/// For each processed linestring, ring or curve segment, the importance of its vertices
+/// according to [vertex_importance] is appended to `importance`. A client can then
+/// progressively drop the least important vertices without simplifying with multiple
+/// tolerances.
+#[derive(Default)]
+pub struct VertexImportanceProcessor {
+    /// Maximal number of buffered coordinates, larger linestrings are rejected
+    pub max_buffered_coords: Option<usize>,
+    /// Vertex importance of each processed linestring
+    pub importance: Vec<Vec<f64>>,
+    line: Vec<(f64, f64)>,
+    buffering: bool,
+}
+
+impl VertexImportanceProcessor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl GeomProcessor for VertexImportanceProcessor {
+    fn xy(&mut self, x: f64, y: f64, _idx: usize) -> Result<()> {
+        if self.buffering {
+            super::check_buffer_limit(self.line.len(), self.max_buffered_coords)?;
+            self.line.push((x, y));
+        }
+        Ok(())
+    }
+    fn linestring_begin(&mut self, _tagged: bool, _size: usize, _idx: usize) -> Result<()> {
+        self.buffering = true;
+        self.line.clear();
+        Ok(())
+    }
+    fn linestring_end(&mut self, _tagged: bool, _idx: usize) -> Result<()> {
+        self.buffering = false;
+        self.importance.push(vertex_importance(&self.line));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+    use crate::wkt::WktStr;
+    use crate::GeozeroGeometry;
+
+    #[test]
+    fn importance() {
+        let mut processor = VertexImportanceProcessor::new();
+        WktStr("MULTILINESTRING((0 0,1 0,2 0,3 2,4 0),(0 0,1 1))")
+            .process_geom(&mut processor)
+            .unwrap();
+        let inf = f64::INFINITY;
+        // distance of (2 0) to (0 0)-(3 2)
+        let dist = 4.0 / 13f64.sqrt();
+        assert_eq!(
+            processor.importance,
+            vec![vec![inf, 0.0, dist, 2.0, inf], vec![inf, inf]]
+        );
+    }
+}
@@ -0,0 +1,121 @@
+use super::Coord;
+use crate::algorithm::ring_signed_area;
+use crate::error::Result;
+use crate::GeomProcessor;
+
+/// Orient polygon rings, e.g. according to the right-hand rule of GeoJSON (RFC 7946).
+///
+/// Rings are buffered and reversed if needed, so exterior rings are counterclockwise and
+/// interior rings clockwise, or the other way round with `exterior_ccw = false`
+/// (e.g. Esri shapefiles). Orientation is defined for a y-up coordinate system.
+pub struct WindingProcessor<P: GeomProcessor> {
+    /// Orient exterior rings counterclockwise and interior rings clockwise
+    pub exterior_ccw: bool,
+    /// Maximal number of buffered coordinates, larger rings are rejected
+    pub max_buffered_coords: Option<usize>,
+    inner: P,
+    buffer: Vec<Coord>,
+    buffering: bool,
+    in_polygon: bool,
+}
+
+impl<P: GeomProcessor> WindingProcessor<P> {
+    /// Processor orienting rings according to the right-hand rule.
+    pub fn new(inner: P) -> Self {
+        WindingProcessor {
+            exterior_ccw: true,
+            max_buffered_coords: None,
+            inner,
+            buffer: Vec::new(),
+            buffering: false,
+            in_polygon: false,
+        }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+}
+
+impl<P: GeomProcessor> GeomProcessor for WindingProcessor<P> {
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        self.coordinate(x, y, None, None, None, None, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        if self.buffering {
+            super::check_buffer_limit(self.buffer.len(), self.max_buffered_coords)?;
+            self.buffer.push((x, y, z, m));
+            Ok(())
+        } else if self.inner.multi_dim() {
+            self.inner.coordinate(x, y, z, m, t, tm, idx)
+        } else {
+            self.inner.xy(x, y, idx)
+        }
+    }
+    fn linestring_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        if tagged || !self.in_polygon {
+            return self.inner.linestring_begin(tagged, size, idx);
+        }
+        self.buffering = true;
+        self.buffer.clear();
+        Ok(())
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        if !self.buffering {
+            return self.inner.linestring_end(tagged, idx);
+        }
+        self.buffering = false;
+        let ring: Vec<[f64; 2]> = self.buffer.iter().map(|c| [c.0, c.1]).collect();
+        let area = ring_signed_area(&ring);
+        // ring index 0 is the exterior ring
+        let want_ccw = (idx == 0) == self.exterior_ccw;
+        if area != 0.0 && (area > 0.0) != want_ccw {
+            self.buffer.reverse();
+        }
+        super::emit_line(&mut self.inner, &self.buffer, tagged, idx)
+    }
+    fn polygon_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.in_polygon = true;
+        self.inner.polygon_begin(tagged, size, idx)
+    }
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.in_polygon = false;
+        self.inner.polygon_end(tagged, idx)
+    }
+    forward_geom_processor!(inner;
+        dimensions, srid, empty_point, point_begin, point_end,
+        multipoint_begin, multipoint_end,
+        multilinestring_begin, multilinestring_end,
+        multipolygon_begin, multipolygon_end, geometrycollection_begin, geometrycollection_end,
+        circularstring_begin, circularstring_end, compoundcurve_begin, compoundcurve_end,
+        curvepolygon_begin, curvepolygon_end, multicurve_begin, multicurve_end,
+        multisurface_begin, multisurface_end, triangle_begin, triangle_end,
+        polyhedralsurface_begin, polyhedralsurface_end, tin_begin, tin_end,
+    );
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+
+    #[test]
+    fn right_hand_rule() {
+        assert_eq!(
+            process_wkt!(
+                "POLYGON((0 0,0 4,4 4,4 0,0 0),(1 1,2 1,2 2,1 1))",
+                WindingProcessor::new
+            ),
+            "POLYGON((0 0,4 0,4 4,0 4,0 0),(1 1,2 2,2 1,1 1))"
+        );
+    }
+}
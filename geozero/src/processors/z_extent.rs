@@ -0,0 +1,110 @@
+use crate::error::Result;
+use crate::{CoordDimensions, GeomProcessor};
+
+/// Z range of the last processed geometry, e.g. for detecting "fake 3D" geometries.
+///
+/// Geometries with declared Z but constant Z values (e.g. all `0`) can be
+/// stored as 2D geometries without loss of information.
+#[derive(Default)]
+pub struct ZExtent {
+    /// Coordinates have Z values according to the geometry header
+    pub has_declared_z: bool,
+    /// Minimal and maximal Z value, `None` without (non-NaN) Z values
+    pub z_range: Option<(f64, f64)>,
+    depth: usize,
+}
+
+impl ZExtent {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Z values are not all equal
+    pub fn z_varies(&self) -> bool {
+        self.z_range.is_some_and(|(min, max)| min != max)
+    }
+
+    fn begin(&mut self, _idx: usize) -> Result<()> {
+        if self.depth == 0 {
+            self.has_declared_z = false;
+            self.z_range = None;
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    fn end(&mut self, _idx: usize) -> Result<()> {
+        self.depth -= 1;
+        Ok(())
+    }
+}
+
+impl GeomProcessor for ZExtent {
+    fn dimensions(&self) -> CoordDimensions {
+        CoordDimensions::xyz()
+    }
+    fn coordinate(
+        &mut self,
+        _x: f64,
+        _y: f64,
+        z: Option<f64>,
+        _m: Option<f64>,
+        _t: Option<f64>,
+        _tm: Option<u64>,
+        _idx: usize,
+    ) -> Result<()> {
+        let Some(z) = z else {
+            return Ok(());
+        };
+        self.has_declared_z = true;
+        if !z.is_nan() {
+            self.z_range = Some(match self.z_range {
+                Some((min, max)) => (min.min(z), max.max(z)),
+                None => (z, z),
+            });
+        }
+        Ok(())
+    }
+    fn empty_point(&mut self, idx: usize) -> Result<()> {
+        if self.depth == 0 {
+            self.begin(idx)?;
+            self.end(idx)?;
+        }
+        Ok(())
+    }
+    nesting_geom_processor!(begin, end);
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkb")]
+mod test {
+    use super::*;
+    use crate::wkb::Ewkb;
+    use crate::GeozeroGeometry;
+
+    #[test]
+    fn fake_3d() {
+        let mut extent = ZExtent::new();
+        // SELECT 'LINESTRING(10 -20 5, 0 -0.5 5)'::geometry
+        Ewkb(hex::decode("010200008002000000000000000000244000000000000034C000000000000014400000000000000000000000000000E0BF0000000000001440").unwrap())
+            .process_geom(&mut extent)
+            .unwrap();
+        assert!(extent.has_declared_z);
+        assert_eq!(extent.z_range, Some((5.0, 5.0)));
+        assert!(!extent.z_varies());
+
+        // SELECT 'LINESTRING(10 -20 5, 0 -0.5 7)'::geometry
+        Ewkb(hex::decode("010200008002000000000000000000244000000000000034C000000000000014400000000000000000000000000000E0BF0000000000001C40").unwrap())
+            .process_geom(&mut extent)
+            .unwrap();
+        assert_eq!(extent.z_range, Some((5.0, 7.0)));
+        assert!(extent.z_varies());
+
+        // SELECT 'POINT(10 -20)'::geometry
+        Ewkb(hex::decode("0101000000000000000000244000000000000034C0").unwrap())
+            .process_geom(&mut extent)
+            .unwrap();
+        assert!(!extent.has_declared_z);
+        assert_eq!(extent.z_range, None);
+    }
+}
@@ -0,0 +1,75 @@
+use crate::error::Result;
+use crate::GeomProcessor;
+
+/// Transform z ordinates, e.g. for vertical datum shifts or unit conversions.
+///
+/// The closure gets `x, y, z` and returns the new z value, x/y and M values are unchanged.
+/// Coordinates without z are forwarded as they are.
+pub struct ZTransformProcessor<P: GeomProcessor, F: Fn(f64, f64, f64) -> f64> {
+    inner: P,
+    transform: F,
+}
+
+impl<P: GeomProcessor, F: Fn(f64, f64, f64) -> f64> ZTransformProcessor<P, F> {
+    pub fn new(inner: P, transform: F) -> Self {
+        ZTransformProcessor { inner, transform }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+}
+
+impl<P: GeomProcessor, F: Fn(f64, f64, f64) -> f64> GeomProcessor for ZTransformProcessor<P, F> {
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        let z = z.map(|z| (self.transform)(x, y, z));
+        self.inner.coordinate(x, y, z, m, t, tm, idx)
+    }
+    forward_geom_processor!(inner;
+        dimensions, srid, xy, empty_point, point_begin, point_end,
+        multipoint_begin, multipoint_end, linestring_begin, linestring_end,
+        multilinestring_begin, multilinestring_end, polygon_begin, polygon_end,
+        multipolygon_begin, multipolygon_end, geometrycollection_begin, geometrycollection_end,
+        circularstring_begin, circularstring_end, compoundcurve_begin, compoundcurve_end,
+        curvepolygon_begin, curvepolygon_end, multicurve_begin, multicurve_end,
+        multisurface_begin, multisurface_end, triangle_begin, triangle_end,
+        polyhedralsurface_begin, polyhedralsurface_end, tin_begin, tin_end,
+    );
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+    use crate::wkt::WktWriter;
+    use crate::CoordDimensions;
+
+    #[test]
+    fn feet_to_meters() {
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = WktWriter::new(&mut out);
+        writer.dims = CoordDimensions::xyzm();
+        let mut processor = ZTransformProcessor::new(writer, |_x, _y, z| z * 0.3048);
+        processor.linestring_begin(true, 2, 0).unwrap();
+        processor
+            .coordinate(1.0, 2.0, Some(1000.0), Some(7.0), None, None, 0)
+            .unwrap();
+        processor
+            .coordinate(3.0, 4.0, Some(-50.0), None, None, None, 1)
+            .unwrap();
+        processor.linestring_end(true, 0).unwrap();
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            "LINESTRING(1 2 304.8 7,3 4 -15.24)"
+        );
+    }
+}
@@ -0,0 +1,89 @@
+use std::io::{self, Read};
+
+/// [Read] implementation over an iterator of byte chunks.
+///
+/// Allows processing geometries received in arbitrary pieces, e.g. `bytes::Bytes`
+/// from a channel, without assembling a contiguous buffer:
+///
+/// ```
+/// use geozero::wkb::{process_ewkb_geom, ChunkedReader};
+/// use geozero::wkt::WktWriter;
+///
+/// let chunks = [vec![1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36], vec![64, 0, 0, 0, 0, 0, 0, 52, 192]];
+/// let mut wkt: Vec<u8> = Vec::new();
+/// process_ewkb_geom(&mut ChunkedReader::new(chunks), &mut WktWriter::new(&mut wkt)).unwrap();
+/// assert_eq!(wkt, b"POINT(10 -20)");
+/// ```
+pub struct ChunkedReader<I: Iterator> {
+    chunks: I,
+    current: Option<I::Item>,
+    pos: usize,
+}
+
+impl<I, B> ChunkedReader<I>
+where
+    I: Iterator<Item = B>,
+    B: AsRef<[u8]>,
+{
+    pub fn new<T: IntoIterator<IntoIter = I>>(chunks: T) -> Self {
+        ChunkedReader {
+            chunks: chunks.into_iter(),
+            current: None,
+            pos: 0,
+        }
+    }
+}
+
+impl<I, B> Read for ChunkedReader<I>
+where
+    I: Iterator<Item = B>,
+    B: AsRef<[u8]>,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        loop {
+            if let Some(chunk) = &self.current {
+                let remaining = &chunk.as_ref()[self.pos..];
+                if !remaining.is_empty() {
+                    let n = remaining.len().min(buf.len());
+                    buf[..n].copy_from_slice(&remaining[..n]);
+                    self.pos += n;
+                    return Ok(n);
+                }
+            }
+            // current chunk exhausted (or empty), continue with the next one
+            match self.chunks.next() {
+                Some(chunk) => {
+                    self.current = Some(chunk);
+                    self.pos = 0;
+                }
+                None => return Ok(0),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+    use crate::wkb::process_ewkb_geom;
+    use crate::wkt::WktWriter;
+
+    #[test]
+    fn three_byte_chunks() {
+        // SELECT 'SRID=4326;LINESTRING (10 -20, 0 -0.5)'::geometry
+        let ewkb = hex::decode("0102000020E610000002000000000000000000244000000000000034C00000000000000000000000000000E0BF").unwrap();
+        let mut wkt_data: Vec<u8> = Vec::new();
+        let mut reader = ChunkedReader::new(ewkb.chunks(3));
+        process_ewkb_geom(&mut reader, &mut WktWriter::new(&mut wkt_data)).unwrap();
+        assert_eq!(
+            std::str::from_utf8(&wkt_data).unwrap(),
+            "LINESTRING(10 -20,0 -0.5)"
+        );
+        let mut rest = Vec::new();
+        assert_eq!(reader.read_to_end(&mut rest).unwrap(), 0);
+    }
+}
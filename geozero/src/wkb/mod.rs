@@ -10,10 +10,16 @@
 //! let wkb = Ewkb(vec![1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 64, 0, 0, 0, 0, 0, 0, 52, 192]);
 //! assert_eq!(wkb.to_wkt().unwrap(), "POINT(10 -20)");
 //! ```
+//!
+//! The readers are generic over [std::io::Read], so byte slices (`&mut &[u8]`) can be
+//! processed directly. A `no_std` build is not supported, since reader errors wrap
+//! [std::io::Error] (see [GeozeroError::IoError](crate::error::GeozeroError::IoError)).
+pub(crate) mod chunked_reader;
 pub(crate) mod wkb_common;
 pub(crate) mod wkb_reader;
 pub(crate) mod wkb_writer;
 
+pub use chunked_reader::*;
 pub use wkb_common::*;
 pub use wkb_reader::*;
 pub use wkb_writer::*;
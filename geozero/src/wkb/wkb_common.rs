@@ -37,7 +37,7 @@ pub trait FromWkb {
 }
 
 /// WKB dialect.
-#[derive(PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub enum WkbDialect {
     Wkb,
     Ewkb,
@@ -194,7 +194,18 @@ impl WKBGeometryType {
     }
 }
 
-pub(crate) enum WKBByteOrder {
+/// WKB byte order
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WKBByteOrder {
     Xdr = 0, // Big Endian
     Ndr = 1, // Little Endian
 }
+
+impl WKBByteOrder {
+    pub(crate) fn endian(self) -> scroll::Endian {
+        match self {
+            WKBByteOrder::Xdr => scroll::BE,
+            WKBByteOrder::Ndr => scroll::LE,
+        }
+    }
+}
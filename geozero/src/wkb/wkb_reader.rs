@@ -1,8 +1,12 @@
+use crate::algorithm::extend_bbox;
 use crate::error::{GeozeroError, Result};
-use crate::wkb::{WKBByteOrder, WKBGeometryType, WkbDialect};
-use crate::{GeomProcessor, GeozeroGeometry};
+use crate::processors::TeeProcessor;
+use crate::wkb::{WKBByteOrder, WKBGeometryType, WkbDialect, WkbWriter};
+use crate::wkt::WktWriter;
+use crate::{AxisOrder, CoordDimensions, GeomProcessor, GeozeroGeometry, ProcessorSink};
 use scroll::IOread;
-use std::io::Read;
+use std::collections::BTreeMap;
+use std::io::{BufRead, Read};
 
 #[cfg(feature = "with-postgis-diesel")]
 use crate::postgis::diesel::sql_types::{Geography, Geometry};
@@ -42,22 +46,389 @@ impl GeozeroGeometry for GpkgWkb {
     }
 }
 
+/// WKB reader options.
+#[derive(Clone, Debug)]
+pub struct WkbReadOptions {
+    /// Type flag announcing a bounding box in EWKB headers (non-standard, off by default).
+    ///
+    /// Some tools set a flag bit in the geometry type and write the bounding box
+    /// `minx, miny, maxx, maxy` as f64 values after the type (and SRID, if present).
+    /// When the flag is set, the box is consumed instead of being read as coordinates.
+    /// Setting `0x2000_0000` reinterprets the PostGIS SRID flag as bbox flag.
+    pub ewkb_bbox_flag: Option<u32>,
+    /// Read ordinates as fixed-point i64 values divided by this scale factor.
+    ///
+    /// Supports compact encodings storing coordinates as integers, e.g. with a scale
+    /// of `1e6` the value `10_500_000` is read as `10.5`. Headers are unchanged.
+    pub fixed_point_scale: Option<f64>,
+    /// Override the Z and M flags of the geometry headers.
+    ///
+    /// Escape hatch for data with wrong header flags, e.g. 3 ordinates per coordinate
+    /// without the Z flag set. Ordinates are read according to these dimensions.
+    pub force_dims: Option<CoordDimensions>,
+    /// Maximal nesting level of geometry collections (default: 100).
+    ///
+    /// Deeper nested collections are rejected with [`GeozeroError::GeometryFormat`],
+    /// protecting against stack overflows when decoding untrusted input.
+    pub max_depth: usize,
+    /// Pass M values as Z to the processor for geometries with M but without Z.
+    ///
+    /// Lets measures flow into Z-aware consumers without M support. This renames
+    /// the ordinate: the M value is reported as Z and no M value is passed on.
+    pub m_as_z: bool,
+    /// Report SRIDs of EWKB collection members with [GeomProcessor::srid].
+    ///
+    /// By default, only the SRID of the outermost geometry is reported and member
+    /// SRIDs are skipped. When enabled, `srid` is called before each member with an SRID.
+    pub member_srids: bool,
+    /// Fail on polygon and triangle rings with different first and last vertex (x/y).
+    ///
+    /// Rejects open rings instead of passing them on, the error names the ring and
+    /// polygon index. Processing stops at the first open ring.
+    pub require_closed_rings: bool,
+    /// Read MultiPoint coordinates without per-point headers (non-standard, off by default).
+    ///
+    /// Some legacy encoders write the point count followed by raw coordinates.
+    /// The coordinates are read with the dimensions and byte order of the MultiPoint.
+    pub bare_multipoint: bool,
+    /// Read geometry type `0` as empty geometry collection (non-standard, off by default).
+    ///
+    /// Some tools write type `0` without body for empty or unknown geometries.
+    /// By default, type `0` is rejected with [`GeozeroError::GeometryFormat`].
+    /// Other unknown type codes are rejected in any case.
+    pub unknown_as_empty: bool,
+    /// Axis order of the input coordinates (default: [AxisOrder::LonLat]).
+    ///
+    /// With [AxisOrder::LatLon], x and y are swapped on read, so that processors
+    /// get lon/lat coordinates, e.g. for data from lat/lon ordered WFS sources.
+    pub input_axis_order: AxisOrder,
+}
+
+impl Default for WkbReadOptions {
+    fn default() -> Self {
+        WkbReadOptions {
+            ewkb_bbox_flag: None,
+            fixed_point_scale: None,
+            force_dims: None,
+            max_depth: 100,
+            m_as_z: false,
+            member_srids: false,
+            require_closed_rings: false,
+            bare_multipoint: false,
+            unknown_as_empty: false,
+            input_axis_order: AxisOrder::LonLat,
+        }
+    }
+}
+
 /// Process WKB geometry.
 pub fn process_wkb_geom<R: Read, P: GeomProcessor>(raw: &mut R, processor: &mut P) -> Result<()> {
-    let info = read_wkb_header(raw)?;
-    process_wkb_geom_n(raw, &info, read_wkb_header, 0, processor)
+    process_wkb_type_geom(raw, processor, WkbDialect::Wkb)
 }
 
 /// Process EWKB geometry.
 pub fn process_ewkb_geom<R: Read, P: GeomProcessor>(raw: &mut R, processor: &mut P) -> Result<()> {
-    let info = read_ewkb_header(raw)?;
-    process_wkb_geom_n(raw, &info, read_ewkb_header, 0, processor)
+    process_wkb_type_geom(raw, processor, WkbDialect::Ewkb)
+}
+
+/// Process EWKB geometry in a byte buffer.
+///
+/// The geometry is read directly from the buffer, e.g. a `bytes::Bytes` received from the
+/// network can be passed as `&bytes` without copying.
+pub fn process_ewkb_geom_bytes<P: GeomProcessor>(data: &[u8], processor: &mut P) -> Result<()> {
+    process_ewkb_geom(&mut &data[..], processor)
+}
+
+/// Process hex encoded EWKB geometry, e.g. PostGIS text output like `0101000020E6100000...`.
+pub fn process_ewkb_hex<P: GeomProcessor>(hex: &str, processor: &mut P) -> Result<()> {
+    let data = decode_hex(hex.trim())?;
+    process_ewkb_geom(&mut data.as_slice(), processor)
+}
+
+/// Process hex encoded EWKB geometry in the string field `field` of a JSON object.
+///
+/// Convenience for JSON lines exports like `{"geom":"0101...","name":"x"}`,
+/// to be called for each line.
+pub fn process_jsonl_ewkb_hex<P: GeomProcessor>(
+    line: &str,
+    field: &str,
+    processor: &mut P,
+) -> Result<()> {
+    let value: serde_json::Value =
+        serde_json::from_str(line).map_err(|e| GeozeroError::Geometry(e.to_string()))?;
+    let hex = value
+        .get(field)
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| GeozeroError::Geometry(format!("missing string field `{field}`")))?;
+    process_ewkb_hex(hex, processor)
+}
+
+fn decode_hex(hex: &str) -> Result<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return Err(GeozeroError::Geometry(
+            "hex string with odd length".to_string(),
+        ));
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            hex.get(i..i + 2)
+                .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+                .ok_or_else(|| GeozeroError::Geometry(format!("invalid hex at offset {i}")))
+        })
+        .collect()
+}
+
+/// Process a PostGIS `geometry` field of a binary protocol result.
+///
+/// The binary representation of PostGIS geometries is EWKB, this is a convenience
+/// for raw query results (e.g. libpq with binary format) without a database driver integration.
+pub fn process_postgis_geometry<P: GeomProcessor>(field: &[u8], processor: &mut P) -> Result<()> {
+    process_ewkb_geom(&mut &field[..], processor)
+}
+
+/// Process a PostGIS `geography` field of a binary protocol result.
+///
+/// Geographies are EWKB like geometries, but always use WGS 84: SRID 4326 is
+/// reported if the field has no SRID.
+pub fn process_postgis_geography<P: GeomProcessor>(field: &[u8], processor: &mut P) -> Result<()> {
+    let opts = WkbReadOptions::default();
+    let mut raw = field;
+    let info = read_ewkb_header(&mut raw, &opts)?;
+    processor.srid(info.srid.or(Some(4326)))?;
+    process_wkb_geom_n(&mut raw, &info, read_ewkb_header, &opts, 0, processor)
+}
+
+/// Convert EWKB to EWKT as written by PostGIS `ST_AsEWKT`.
+///
+/// The SRID is written as `SRID=...;` prefix and XYM geometries get an `M` type suffix,
+/// e.g. `SRID=4326;POINTM(1 2 3)`. Like PostGIS, Z and ZM geometries have no dimension tag.
+pub fn ewkb_to_ewkt(data: &[u8]) -> Result<String> {
+    let opts = WkbReadOptions::default();
+    let mut raw = data;
+    let info = read_ewkb_header(&mut raw, &opts)?;
+    let mut out: Vec<u8> = Vec::new();
+    let mut writer = WktWriter::new(&mut out);
+    writer.ewkt = true;
+    writer.dims = CoordDimensions {
+        z: info.has_z,
+        m: info.has_m,
+        t: false,
+        tm: false,
+    };
+    writer.srid(info.srid)?;
+    process_wkb_geom_n(&mut raw, &info, read_ewkb_header, &opts, 0, &mut writer)?;
+    String::from_utf8(out).map_err(|e| GeozeroError::Geometry(e.to_string()))
+}
+
+/// Overview of an EWKB geometry, see [ewkb_summary].
+#[derive(Clone, PartialEq, Debug)]
+pub struct GeomSummary {
+    /// Geometry type without dimension flags
+    pub geom_type: WKBGeometryType,
+    pub srid: Option<i32>,
+    /// WKT with the dimensions of the geometry, without SRID
+    pub wkt: String,
+    /// Bounding box `[minx, miny, maxx, maxy]`, `None` for empty geometries
+    pub bbox: Option<[f64; 4]>,
+    /// Number of coordinates
+    pub coord_count: usize,
+}
+
+/// Bounding box and number of coordinates.
+#[derive(Default)]
+struct BoundsCounter {
+    bbox: Option<[f64; 4]>,
+    coord_count: usize,
+}
+
+impl GeomProcessor for BoundsCounter {
+    fn xy(&mut self, x: f64, y: f64, _idx: usize) -> Result<()> {
+        if extend_bbox(&mut self.bbox, x, y) {
+            self.coord_count += 1;
+        }
+        Ok(())
+    }
+}
+
+/// Summarize an EWKB geometry with type, SRID, WKT, bounding box and number of coordinates.
+///
+/// The geometry is decoded once, e.g. for import validation or dashboards.
+pub fn ewkb_summary(data: &[u8]) -> Result<GeomSummary> {
+    let opts = WkbReadOptions::default();
+    let mut raw = data;
+    let info = read_ewkb_header(&mut raw, &opts)?;
+    let mut wkt: Vec<u8> = Vec::new();
+    let mut writer = WktWriter::new(&mut wkt);
+    writer.dims = CoordDimensions {
+        z: info.has_z,
+        m: info.has_m,
+        t: false,
+        tm: false,
+    };
+    let mut processor = TeeProcessor::new(writer, BoundsCounter::default());
+    process_wkb_geom_n(&mut raw, &info, read_ewkb_header, &opts, 0, &mut processor)?;
+    let (_, bounds) = processor.into_inner();
+    Ok(GeomSummary {
+        geom_type: info.base_type,
+        srid: info.srid,
+        wkt: String::from_utf8(wkt).map_err(|e| GeozeroError::Geometry(e.to_string()))?,
+        bbox: bounds.bbox,
+        coord_count: bounds.coord_count,
+    })
+}
+
+/// Process EWKB geometry contained in a buffer of known length.
+///
+/// Returns an error if bytes remain unconsumed after a complete geometry.
+pub fn process_ewkb_geom_exact<P: GeomProcessor>(data: &[u8], processor: &mut P) -> Result<()> {
+    let mut raw = data;
+    process_ewkb_geom(&mut raw, processor)?;
+    if !raw.is_empty() {
+        return Err(GeozeroError::Geometry(format!(
+            "{} trailing bytes after EWKB geometry",
+            raw.len()
+        )));
+    }
+    Ok(())
+}
+
+/// Process EWKB geometry at the start of a buffer, returning the number of bytes consumed.
+///
+/// `&data[..n]` contains the original bytes of the geometry, e.g. for storing them unchanged.
+pub fn process_ewkb_geom_from_slice<P: GeomProcessor>(
+    data: &[u8],
+    processor: &mut P,
+) -> Result<usize> {
+    process_ewkb_geom_at(data, 0, processor)
+}
+
+/// Process EWKB geometry starting at `offset` of a larger buffer.
+///
+/// Returns the absolute offset after the geometry, e.g. the start of the next record.
+pub fn process_ewkb_geom_at<P: GeomProcessor>(
+    data: &[u8],
+    offset: usize,
+    processor: &mut P,
+) -> Result<usize> {
+    let mut raw = data.get(offset..).ok_or_else(|| {
+        GeozeroError::Geometry(format!(
+            "offset {offset} exceeds buffer length {}",
+            data.len()
+        ))
+    })?;
+    process_ewkb_geom(&mut raw, processor)?;
+    Ok(data.len() - raw.len())
 }
 
+/// Process gzip-compressed EWKB geometry.
+///
+/// The reader is wrapped in a [flate2::read::GzDecoder], which is the recommended
+/// way for reading compressed archives. Concatenated geometries can be read by
+/// wrapping the decoder manually and calling [process_ewkb_geom] repeatedly.
+#[cfg(feature = "with-flate2")]
+pub fn process_ewkb_gz<R: Read, P: GeomProcessor>(raw: R, processor: &mut P) -> Result<()> {
+    process_ewkb_geom(&mut flate2::read::GzDecoder::new(raw), processor)
+}
+
+/// Process EWKB geometry, leaving the processor untouched on malformed input.
+///
+/// The blob is validated before any processor callback is made, so a malformed or
+/// truncated blob emits no partial geometry and the processor can be used for
+/// the next blob. Errors returned by the processor itself are passed through
+/// without such a guarantee.
+pub fn try_process_ewkb_geom<P: GeomProcessor>(data: &[u8], processor: &mut P) -> Result<()> {
+    WkbValidateProcessor::new(WkbDialect::Ewkb).validate(data)?;
+    process_ewkb_geom(&mut &data[..], processor)
+}
+
+/// Validate WKB blobs, e.g. before appending them to a GeoArrow WKB array.
+///
+/// The blobs are parsed with the WKB reader without collecting coordinates.
+/// Validation fails for malformed and truncated blobs and for trailing bytes.
+pub struct WkbValidateProcessor {
+    pub dialect: WkbDialect,
+}
+
+impl WkbValidateProcessor {
+    pub fn new(dialect: WkbDialect) -> Self {
+        WkbValidateProcessor { dialect }
+    }
+
+    /// Check that `data` contains exactly one well-formed geometry.
+    pub fn validate(&mut self, data: &[u8]) -> Result<()> {
+        let mut raw = data;
+        process_wkb_type_geom(&mut raw, self, self.dialect)?;
+        if !raw.is_empty() {
+            return Err(GeozeroError::Geometry(format!(
+                "{} trailing bytes after WKB geometry",
+                raw.len()
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl GeomProcessor for WkbValidateProcessor {}
+
 /// Process GPKG geometry.
 pub fn process_gpkg_geom<R: Read, P: GeomProcessor>(raw: &mut R, processor: &mut P) -> Result<()> {
-    let info = read_gpkg_header(raw)?;
-    process_wkb_geom_n(raw, &info, read_wkb_header, 0, processor)
+    process_wkb_type_geom(raw, processor, WkbDialect::Geopackage)
+}
+
+/// Geometry metadata from a WKB header.
+#[derive(Clone, Debug)]
+pub struct WkbHeader {
+    /// Geometry type without dimension flags
+    pub geometry_type: WKBGeometryType,
+    pub srid: Option<i32>,
+    /// Envelope (GPKG), empty if not present
+    pub envelope: Vec<f64>,
+    pub dims: CoordDimensions,
+}
+
+impl From<WkbInfo> for WkbHeader {
+    fn from(info: WkbInfo) -> Self {
+        WkbHeader {
+            geometry_type: info.base_type,
+            srid: info.srid,
+            envelope: info.envelope,
+            dims: CoordDimensions {
+                z: info.has_z,
+                m: info.has_m,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Read the headers of GeoPackage geometry blobs.
+///
+/// Headers are parsed lazily, the geometry bodies are not decoded.
+pub fn gpkg_headers<'a, I>(blobs: I) -> impl Iterator<Item = Result<WkbHeader>>
+where
+    I: IntoIterator<Item = &'a [u8]>,
+{
+    fn read_header(mut blob: &[u8]) -> Result<WkbHeader> {
+        read_gpkg_header(&mut blob, &WkbReadOptions::default()).map(WkbHeader::from)
+    }
+    blobs.into_iter().map(read_header)
+}
+
+/// Count the SRS ids of GeoPackage geometry blobs.
+///
+/// Only the headers of the blobs are read. The keys of the returned map are the
+/// distinct SRS ids, the values the number of blobs using them.
+pub fn gpkg_srid_histogram<'a>(
+    blobs: impl IntoIterator<Item = &'a [u8]>,
+) -> Result<BTreeMap<i32, usize>> {
+    let mut histogram = BTreeMap::new();
+    for header in gpkg_headers(blobs) {
+        if let Some(srid) = header?.srid {
+            *histogram.entry(srid).or_insert(0) += 1;
+        }
+    }
+    Ok(histogram)
 }
 
 /// Process WKB type geometry..
@@ -66,10 +437,176 @@ pub fn process_wkb_type_geom<R: Read, P: GeomProcessor>(
     processor: &mut P,
     dialect: WkbDialect,
 ) -> Result<()> {
-    match dialect {
-        WkbDialect::Wkb => process_wkb_geom(raw, processor),
-        WkbDialect::Ewkb => process_ewkb_geom(raw, processor),
-        WkbDialect::Geopackage => process_gpkg_geom(raw, processor),
+    process_wkb_type_geom_with_options(raw, processor, dialect, &WkbReadOptions::default())
+}
+
+/// Process WKB type geometry with reader options.
+pub fn process_wkb_type_geom_with_options<R: Read, P: GeomProcessor>(
+    raw: &mut R,
+    processor: &mut P,
+    dialect: WkbDialect,
+    opts: &WkbReadOptions,
+) -> Result<()> {
+    let info = read_dialect_header(raw, &dialect, opts)?;
+    // also without SRID, so that processors don't keep the SRID of a previous geometry
+    processor.srid(info.srid)?;
+    process_wkb_geom_n(
+        raw,
+        &info,
+        member_header_reader(&dialect),
+        opts,
+        0,
+        processor,
+    )
+}
+
+/// Process WKB geometry of unknown dialect.
+///
+/// The dialect is detected from the first bytes: GeoPackage geometries start with `GP`,
+/// EWKB geometries have one of the high flag bits set in the geometry type.
+/// Everything else is read as OGC WKB, including ISO type codes for Z/M geometries.
+pub fn process_auto_geom<R: BufRead, P: GeomProcessor>(
+    raw: &mut R,
+    processor: &mut P,
+) -> Result<()> {
+    let dialect = detect_dialect(raw.fill_buf()?);
+    process_wkb_type_geom(raw, processor, dialect)
+}
+
+fn detect_dialect(buf: &[u8]) -> WkbDialect {
+    if buf.starts_with(b"GP") {
+        return WkbDialect::Geopackage;
+    }
+    let Some(header) = buf.get(..5) else {
+        return WkbDialect::Wkb;
+    };
+    let type_bytes = [header[1], header[2], header[3], header[4]];
+    let type_id = if header[0] == WKBByteOrder::Xdr as u8 {
+        u32::from_be_bytes(type_bytes)
+    } else {
+        u32::from_le_bytes(type_bytes)
+    };
+    if type_id & 0xE000_0000 != 0 {
+        WkbDialect::Ewkb
+    } else {
+        WkbDialect::Wkb
+    }
+}
+
+/// Read the first coordinate of a geometry.
+///
+/// Parsing stops right after the first non-empty coordinate, the rest of the
+/// geometry is not decoded. Returns `None` for empty geometries.
+pub fn first_coord<R: Read>(raw: &mut R, dialect: WkbDialect) -> Result<Option<(f64, f64)>> {
+    let mut first = FirstCoord(None);
+    match process_wkb_type_geom(raw, &mut first, dialect) {
+        Err(GeozeroError::Cancelled) if first.0.is_some() => Ok(first.0),
+        result => result.map(|_| first.0),
+    }
+}
+
+/// Captures the first coordinate and aborts processing.
+struct FirstCoord(Option<(f64, f64)>);
+
+impl GeomProcessor for FirstCoord {
+    fn xy(&mut self, x: f64, y: f64, _idx: usize) -> Result<()> {
+        if x.is_nan() && y.is_nan() {
+            // empty point
+            return Ok(());
+        }
+        self.0 = Some((x, y));
+        Err(GeozeroError::Cancelled)
+    }
+}
+
+/// Rewrite a WKB or EWKB geometry in the `target` byte order.
+///
+/// The geometry is fully decoded and re-encoded, keeping dialect, SRID and dimensions.
+/// Mixed-endian input (e.g. members with a different byte order) is normalized as well.
+pub fn normalize_wkb_endian(data: &[u8], target: WKBByteOrder) -> Result<Vec<u8>> {
+    let dialect = detect_dialect(data);
+    if dialect == WkbDialect::Geopackage {
+        return Err(GeozeroError::GeometryFormat);
+    }
+    let opts = WkbReadOptions::default();
+    let mut raw = data;
+    let info = read_dialect_header(&mut raw, &dialect, &opts)?;
+    let read_header = member_header_reader(&dialect);
+    let mut out = Vec::new();
+    let mut writer = WkbWriter::new(&mut out, dialect);
+    writer.endian = target;
+    writer.dims = CoordDimensions {
+        z: info.has_z,
+        m: info.has_m,
+        ..Default::default()
+    };
+    writer.srid = info.srid;
+    process_wkb_geom_n(&mut raw, &info, read_header, &opts, 0, &mut writer)?;
+    Ok(out)
+}
+
+/// Iterate over the members of a GeometryCollection without decoding them.
+///
+/// Yields the geometry type and the raw bytes of each top-level member.
+/// The member slices can be processed with the reader of the same dialect
+/// (OGC WKB for GeoPackage collections).
+pub fn wkb_collection_members<'a>(
+    data: &'a [u8],
+    dialect: WkbDialect,
+) -> Result<WkbCollectionMembers<'a>> {
+    let mut raw = data;
+    let info = read_dialect_header(&mut raw, &dialect, &WkbReadOptions::default())?;
+    if info.base_type != WKBGeometryType::GeometryCollection {
+        return Err(GeozeroError::GeometryFormat);
+    }
+    let remaining = raw.ioread_with::<u32>(info.endian)? as usize;
+    Ok(WkbCollectionMembers {
+        raw,
+        remaining,
+        read_header: member_header_reader(&dialect),
+    })
+}
+
+/// Iterator over the members of a WKB GeometryCollection.
+pub struct WkbCollectionMembers<'a> {
+    raw: &'a [u8],
+    remaining: usize,
+    read_header: ReadHeader<&'a [u8]>,
+}
+
+impl<'a> Iterator for WkbCollectionMembers<'a> {
+    type Item = Result<(WKBGeometryType, &'a [u8])>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let start = self.raw;
+        let mut raw = self.raw;
+        // Walk the member structure to find its end
+        let opts = WkbReadOptions::default();
+        let member = (self.read_header)(&mut raw, &opts).and_then(|info| {
+            process_wkb_geom_n(
+                &mut raw,
+                &info,
+                self.read_header,
+                &opts,
+                0,
+                &mut ProcessorSink,
+            )?;
+            Ok(info.base_type)
+        });
+        match member {
+            Ok(geom_type) => {
+                self.raw = raw;
+                Some(Ok((geom_type, &start[..start.len() - raw.len()])))
+            }
+            Err(e) => {
+                self.remaining = 0;
+                Some(Err(e))
+            }
+        }
     }
 }
 
@@ -77,16 +614,47 @@ pub fn process_wkb_type_geom<R: Read, P: GeomProcessor>(
 pub(crate) struct WkbInfo {
     endian: scroll::Endian,
     base_type: WKBGeometryType,
+    /// Type code without dimension flags, distinguishes type `0` from unknown codes
+    type_code: u32,
     has_z: bool,
     has_m: bool,
-    #[allow(dead_code)]
     srid: Option<i32>,
-    #[allow(dead_code)]
     envelope: Vec<f64>,
 }
 
+/// Header reader used for nested geometries.
+pub(crate) type ReadHeader<R> = fn(&mut R, &WkbReadOptions) -> Result<WkbInfo>;
+
+/// Header of the outermost geometry.
+fn read_dialect_header<R: Read>(
+    raw: &mut R,
+    dialect: &WkbDialect,
+    opts: &WkbReadOptions,
+) -> Result<WkbInfo> {
+    match dialect {
+        WkbDialect::Wkb => read_wkb_header(raw, opts),
+        WkbDialect::Ewkb => read_ewkb_header(raw, opts),
+        WkbDialect::Geopackage => read_gpkg_header(raw, opts),
+    }
+}
+
+/// Header reader for geometries nested in a geometry of the given dialect.
+fn member_header_reader<R: Read>(dialect: &WkbDialect) -> ReadHeader<R> {
+    match dialect {
+        WkbDialect::Wkb | WkbDialect::Geopackage => read_wkb_header,
+        WkbDialect::Ewkb => read_ewkb_header,
+    }
+}
+
+fn forced_dims(has_z: bool, has_m: bool, opts: &WkbReadOptions) -> (bool, bool) {
+    match opts.force_dims {
+        Some(dims) => (dims.z, dims.m),
+        None => (has_z, has_m),
+    }
+}
+
 /// OGC WKB header.
-pub(crate) fn read_wkb_header<R: Read>(raw: &mut R) -> Result<WkbInfo> {
+pub(crate) fn read_wkb_header<R: Read>(raw: &mut R, opts: &WkbReadOptions) -> Result<WkbInfo> {
     let byte_order = raw.ioread::<u8>()?;
     let endian = if byte_order == WKBByteOrder::Xdr as u8 {
         scroll::BE
@@ -94,14 +662,17 @@ pub(crate) fn read_wkb_header<R: Read>(raw: &mut R) -> Result<WkbInfo> {
         scroll::LE
     };
     let type_id = raw.ioread_with::<u32>(endian)?;
-    let base_type = WKBGeometryType::from_u32(type_id % 1000);
+    let type_code = type_id % 1000;
+    let base_type = WKBGeometryType::from_u32(type_code);
     let type_id_dim = type_id / 1000;
     let has_z = type_id_dim == 1 || type_id_dim == 3;
     let has_m = type_id_dim == 2 || type_id_dim == 3;
+    let (has_z, has_m) = forced_dims(has_z, has_m, opts);
 
     let info = WkbInfo {
         endian,
         base_type,
+        type_code,
         has_z,
         has_m,
         srid: None,
@@ -111,7 +682,7 @@ pub(crate) fn read_wkb_header<R: Read>(raw: &mut R) -> Result<WkbInfo> {
 }
 
 /// EWKB header according to https://git.osgeo.org/gitea/postgis/postgis/src/branch/master/doc/ZMSgeoms.txt
-fn read_ewkb_header<R: Read>(raw: &mut R) -> Result<WkbInfo> {
+fn read_ewkb_header<R: Read>(raw: &mut R, opts: &WkbReadOptions) -> Result<WkbInfo> {
     let byte_order = raw.ioread::<u8>()?;
     let endian = if byte_order == WKBByteOrder::Xdr as u8 {
         scroll::BE
@@ -120,29 +691,55 @@ fn read_ewkb_header<R: Read>(raw: &mut R) -> Result<WkbInfo> {
     };
 
     let type_id = raw.ioread_with::<u32>(endian)?;
-    let base_type = WKBGeometryType::from_u32(type_id & 0xFF);
-    let has_z = type_id & 0x8000_0000 == 0x8000_0000;
-    let has_m = type_id & 0x4000_0000 == 0x4000_0000;
+    // GDAL writes ISO type codes (e.g. 1001 for PointZ) combined with EWKB flags
+    let iso_type = type_id & 0x0FFF_FFFF;
+    let (base_type, iso_z, iso_m) = if iso_type >= 1000 {
+        let type_id_dim = iso_type / 1000;
+        (
+            WKBGeometryType::from_u32(iso_type % 1000),
+            type_id_dim == 1 || type_id_dim == 3,
+            type_id_dim == 2 || type_id_dim == 3,
+        )
+    } else {
+        (WKBGeometryType::from_u32(type_id & 0xFF), false, false)
+    };
+    let type_code = iso_type % 1000;
+    let has_z = iso_z || type_id & 0x8000_0000 == 0x8000_0000;
+    let has_m = iso_m || type_id & 0x4000_0000 == 0x4000_0000;
+    let (has_z, has_m) = forced_dims(has_z, has_m, opts);
+
+    let has_bbox = opts
+        .ewkb_bbox_flag
+        .is_some_and(|flag| type_id & flag == flag);
 
-    let srid = if type_id & 0x2000_0000 == 0x2000_0000 {
+    let srid = if type_id & 0x2000_0000 == 0x2000_0000 && opts.ewkb_bbox_flag != Some(0x2000_0000) {
         Some(raw.ioread_with::<i32>(endian)?)
     } else {
         None
     };
 
+    let envelope = if has_bbox {
+        (0..4)
+            .map(|_| raw.ioread_with::<f64>(endian))
+            .collect::<std::result::Result<Vec<f64>, _>>()?
+    } else {
+        Vec::new()
+    };
+
     let info = WkbInfo {
         endian,
         base_type,
+        type_code,
         has_z,
         has_m,
         srid,
-        envelope: Vec::new(),
+        envelope,
     };
     Ok(info)
 }
 
 /// GPKG geometry header according to http://www.geopackage.org/spec/#gpb_format
-fn read_gpkg_header<R: Read>(raw: &mut R) -> Result<WkbInfo> {
+fn read_gpkg_header<R: Read>(raw: &mut R, opts: &WkbReadOptions) -> Result<WkbInfo> {
     let magic = [raw.ioread::<u8>()?, raw.ioread::<u8>()?];
     if &magic != b"GP" {
         return Err(GeozeroError::GeometryFormat);
@@ -170,11 +767,12 @@ fn read_gpkg_header<R: Read>(raw: &mut R) -> Result<WkbInfo> {
         .collect();
     let envelope = envelope?;
 
-    let ogc_info = read_wkb_header(raw)?;
+    let ogc_info = read_wkb_header(raw, opts)?;
 
     let info = WkbInfo {
         endian,
         base_type: ogc_info.base_type,
+        type_code: ogc_info.type_code,
         has_z: ogc_info.has_z,
         has_m: ogc_info.has_m,
         srid: Some(srid),
@@ -188,14 +786,15 @@ fn read_gpkg_header<R: Read>(raw: &mut R) -> Result<WkbInfo> {
 pub(crate) fn process_wkb_geom_n<R: Read, P: GeomProcessor>(
     raw: &mut R,
     info: &WkbInfo,
-    read_header: fn(&mut R) -> Result<WkbInfo>,
+    read_header: ReadHeader<R>,
+    opts: &WkbReadOptions,
     idx: usize,
     processor: &mut P,
 ) -> Result<()> {
     match info.base_type {
         WKBGeometryType::Point => {
             processor.point_begin(idx)?;
-            process_coord(raw, info, processor.multi_dim(), 0, processor)?;
+            process_coord(raw, info, opts, processor.multi_dim(), 0, processor)?;
             processor.point_end(idx)
         }
         WKBGeometryType::MultiPoint => {
@@ -203,22 +802,26 @@ pub(crate) fn process_wkb_geom_n<R: Read, P: GeomProcessor>(
             processor.multipoint_begin(n_pts, idx)?;
             let multi = processor.multi_dim();
             for i in 0..n_pts {
-                let info = read_header(raw)?;
-                process_coord(raw, &info, multi, i, processor)?;
+                if opts.bare_multipoint {
+                    process_coord(raw, info, opts, multi, i, processor)?;
+                } else {
+                    let info = read_header(raw, opts)?;
+                    process_coord(raw, &info, opts, multi, i, processor)?;
+                }
             }
             processor.multipoint_end(idx)
         }
-        WKBGeometryType::LineString => process_linestring(raw, info, true, idx, processor),
-        WKBGeometryType::CircularString => process_circularstring(raw, info, idx, processor),
+        WKBGeometryType::LineString => process_linestring(raw, info, opts, true, idx, processor),
+        WKBGeometryType::CircularString => process_circularstring(raw, info, opts, idx, processor),
         WKBGeometryType::CompoundCurve => {
-            process_compoundcurve(raw, info, read_header, idx, processor)
+            process_compoundcurve(raw, info, read_header, opts, idx, processor)
         }
         WKBGeometryType::MultiLineString => {
             let n_lines = raw.ioread_with::<u32>(info.endian)? as usize;
             processor.multilinestring_begin(n_lines, idx)?;
             for i in 0..n_lines {
-                let info = read_header(raw)?;
-                process_linestring(raw, &info, false, i, processor)?;
+                let info = read_header(raw, opts)?;
+                process_linestring(raw, &info, opts, false, i, processor)?;
             }
             processor.multilinestring_end(idx)
         }
@@ -226,21 +829,21 @@ pub(crate) fn process_wkb_geom_n<R: Read, P: GeomProcessor>(
             let n_curves = raw.ioread_with::<u32>(info.endian)? as usize;
             processor.multicurve_begin(n_curves, idx)?;
             for i in 0..n_curves {
-                process_curve(raw, read_header, i, processor)?;
+                process_curve(raw, read_header, opts, i, processor)?;
             }
             processor.multicurve_end(idx)
         }
-        WKBGeometryType::Polygon => process_polygon(raw, info, true, idx, processor),
-        WKBGeometryType::Triangle => process_triangle(raw, info, true, idx, processor),
+        WKBGeometryType::Polygon => process_polygon(raw, info, opts, true, idx, processor),
+        WKBGeometryType::Triangle => process_triangle(raw, info, opts, true, idx, processor),
         WKBGeometryType::CurvePolygon => {
-            process_curvepolygon(raw, info, read_header, idx, processor)
+            process_curvepolygon(raw, info, read_header, opts, idx, processor)
         }
         WKBGeometryType::MultiPolygon => {
             let n_polys = raw.ioread_with::<u32>(info.endian)? as usize;
             processor.multipolygon_begin(n_polys, idx)?;
             for i in 0..n_polys {
-                let info = read_header(raw)?;
-                process_polygon(raw, &info, false, i, processor)?;
+                let info = read_header(raw, opts)?;
+                process_polygon(raw, &info, opts, false, i, processor)?;
             }
             processor.multipolygon_end(idx)
         }
@@ -248,8 +851,8 @@ pub(crate) fn process_wkb_geom_n<R: Read, P: GeomProcessor>(
             let n_polys = raw.ioread_with::<u32>(info.endian)? as usize;
             processor.polyhedralsurface_begin(n_polys, idx)?;
             for i in 0..n_polys {
-                let info = read_header(raw)?;
-                process_polygon(raw, &info, false, i, processor)?;
+                let info = read_header(raw, opts)?;
+                process_polygon(raw, &info, opts, false, i, processor)?;
             }
             processor.polyhedralsurface_end(idx)
         }
@@ -257,8 +860,8 @@ pub(crate) fn process_wkb_geom_n<R: Read, P: GeomProcessor>(
             let n_triangles = raw.ioread_with::<u32>(info.endian)? as usize;
             processor.tin_begin(n_triangles, idx)?;
             for i in 0..n_triangles {
-                let info = read_header(raw)?;
-                process_triangle(raw, &info, false, i, processor)?;
+                let info = read_header(raw, opts)?;
+                process_triangle(raw, &info, opts, false, i, processor)?;
             }
             processor.tin_end(idx)
         }
@@ -266,13 +869,13 @@ pub(crate) fn process_wkb_geom_n<R: Read, P: GeomProcessor>(
             let n_polys = raw.ioread_with::<u32>(info.endian)? as usize;
             processor.multisurface_begin(n_polys, idx)?;
             for i in 0..n_polys {
-                let info = read_header(raw)?;
+                let info = read_header(raw, opts)?;
                 match info.base_type {
                     WKBGeometryType::CurvePolygon => {
-                        process_curvepolygon(raw, &info, read_header, i, processor)?;
+                        process_curvepolygon(raw, &info, read_header, opts, i, processor)?;
                     }
                     WKBGeometryType::Polygon => {
-                        process_polygon(raw, &info, false, i, processor)?;
+                        process_polygon(raw, &info, opts, false, i, processor)?;
                     }
                     _ => return Err(GeozeroError::GeometryFormat),
                 }
@@ -281,47 +884,84 @@ pub(crate) fn process_wkb_geom_n<R: Read, P: GeomProcessor>(
         }
 
         WKBGeometryType::GeometryCollection => {
+            if opts.max_depth == 0 {
+                return Err(GeozeroError::GeometryFormat);
+            }
+            let member_opts = WkbReadOptions {
+                max_depth: opts.max_depth - 1,
+                ..opts.clone()
+            };
             let n_geoms = raw.ioread_with::<u32>(info.endian)? as usize;
             processor.geometrycollection_begin(n_geoms, idx)?;
             for i in 0..n_geoms {
-                let info = read_header(raw)?;
-                process_wkb_geom_n(raw, &info, read_header, i, processor)?;
+                let info = read_header(raw, &member_opts)?;
+                if opts.member_srids && info.srid.is_some() {
+                    processor.srid(info.srid)?;
+                }
+                process_wkb_geom_n(raw, &info, read_header, &member_opts, i, processor)?;
             }
             processor.geometrycollection_end(idx)
         }
+        WKBGeometryType::Unknown if opts.unknown_as_empty && info.type_code == 0 => {
+            processor.geometrycollection_begin(0, idx)?;
+            processor.geometrycollection_end(idx)
+        }
+        // abstract types without encoding defined by OGC
+        WKBGeometryType::Curve | WKBGeometryType::Surface => {
+            Err(GeozeroError::UnsupportedGeometryType {
+                type_id: info.base_type.clone() as u32,
+                name: format!("{:?}", info.base_type),
+            })
+        }
         _ => Err(GeozeroError::GeometryFormat),
     }
 }
 
+fn read_ordinate<R: Read>(raw: &mut R, info: &WkbInfo, opts: &WkbReadOptions) -> Result<f64> {
+    match opts.fixed_point_scale {
+        Some(scale) => Ok(raw.ioread_with::<i64>(info.endian)? as f64 / scale),
+        None => Ok(raw.ioread_with::<f64>(info.endian)?),
+    }
+}
+
 fn process_coord<R: Read, P: GeomProcessor>(
     raw: &mut R,
     info: &WkbInfo,
+    opts: &WkbReadOptions,
     multi_dim: bool,
     idx: usize,
     processor: &mut P,
-) -> Result<()> {
-    let x = raw.ioread_with::<f64>(info.endian)?;
-    let y = raw.ioread_with::<f64>(info.endian)?;
+) -> Result<(f64, f64)> {
+    let x = read_ordinate(raw, info, opts)?;
+    let y = read_ordinate(raw, info, opts)?;
+    let (x, y) = opts.input_axis_order.order(x, y);
     let z = if info.has_z {
-        Some(raw.ioread_with::<f64>(info.endian)?)
+        Some(read_ordinate(raw, info, opts)?)
     } else {
         None
     };
     let m = if info.has_m {
-        Some(raw.ioread_with::<f64>(info.endian)?)
+        Some(read_ordinate(raw, info, opts)?)
     } else {
         None
     };
+    let (z, m) = if opts.m_as_z && z.is_none() {
+        (m, None)
+    } else {
+        (z, m)
+    };
     if multi_dim {
-        processor.coordinate(x, y, z, m, None, None, idx)
+        processor.coordinate(x, y, z, m, None, None, idx)?;
     } else {
-        processor.xy(x, y, idx)
+        processor.xy(x, y, idx)?;
     }
+    Ok((x, y))
 }
 
 fn process_linestring<R: Read, P: GeomProcessor>(
     raw: &mut R,
     info: &WkbInfo,
+    opts: &WkbReadOptions,
     tagged: bool,
     idx: usize,
     processor: &mut P,
@@ -329,15 +969,73 @@ fn process_linestring<R: Read, P: GeomProcessor>(
     let length = raw.ioread_with::<u32>(info.endian)? as usize;
     processor.linestring_begin(tagged, length, idx)?;
     let multi = processor.multi_dim();
-    for i in 0..length {
-        process_coord(raw, info, multi, i, processor)?;
+    if !multi && !info.has_z && !info.has_m && opts.fixed_point_scale.is_none() {
+        process_xy_block(raw, info.endian, opts.input_axis_order, length, processor)?;
+    } else {
+        for i in 0..length {
+            process_coord(raw, info, opts, multi, i, processor)?;
+        }
     }
     processor.linestring_end(tagged, idx)
 }
 
+/// Fast path for 2D coordinates: read blocks of xy pairs instead of single ordinates.
+///
+/// Callbacks are the same as with single reads, but a processor error (e.g. cancelling)
+/// leaves the reader positioned after the current block.
+fn process_xy_block<R: Read, P: GeomProcessor>(
+    raw: &mut R,
+    endian: scroll::Endian,
+    axis_order: AxisOrder,
+    length: usize,
+    processor: &mut P,
+) -> Result<()> {
+    const BLOCK_COORDS: usize = 256;
+    let mut buf = [0u8; BLOCK_COORDS * 16];
+    let read_f64 = |bytes: &[u8]| {
+        let bytes: [u8; 8] = bytes.try_into().unwrap();
+        if endian == scroll::BE {
+            f64::from_be_bytes(bytes)
+        } else {
+            f64::from_le_bytes(bytes)
+        }
+    };
+    let mut i = 0;
+    while i < length {
+        let n = (length - i).min(BLOCK_COORDS);
+        let block = &mut buf[..n * 16];
+        let filled = read_block(raw, block)?;
+        // coordinates before the end of truncated input are processed like with single reads
+        for xy in block[..filled].chunks_exact(16) {
+            let (x, y) = axis_order.order(read_f64(&xy[..8]), read_f64(&xy[8..]));
+            processor.xy(x, y, i)?;
+            i += 1;
+        }
+        if filled < block.len() {
+            return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into());
+        }
+    }
+    Ok(())
+}
+
+/// Fill `block` as far as possible, returning the number of bytes read.
+fn read_block<R: Read>(raw: &mut R, block: &mut [u8]) -> Result<usize> {
+    let mut filled = 0;
+    while filled < block.len() {
+        match raw.read(&mut block[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok(filled)
+}
+
 fn process_circularstring<R: Read, P: GeomProcessor>(
     raw: &mut R,
     info: &WkbInfo,
+    opts: &WkbReadOptions,
     idx: usize,
     processor: &mut P,
 ) -> Result<()> {
@@ -345,7 +1043,7 @@ fn process_circularstring<R: Read, P: GeomProcessor>(
     processor.circularstring_begin(length, idx)?;
     let multi = processor.multi_dim();
     for i in 0..length {
-        process_coord(raw, info, multi, i, processor)?;
+        process_coord(raw, info, opts, multi, i, processor)?;
     }
     processor.circularstring_end(idx)
 }
@@ -353,6 +1051,7 @@ fn process_circularstring<R: Read, P: GeomProcessor>(
 fn process_polygon<R: Read, P: GeomProcessor>(
     raw: &mut R,
     info: &WkbInfo,
+    opts: &WkbReadOptions,
     tagged: bool,
     idx: usize,
     processor: &mut P,
@@ -360,14 +1059,44 @@ fn process_polygon<R: Read, P: GeomProcessor>(
     let ring_count = raw.ioread_with::<u32>(info.endian)? as usize;
     processor.polygon_begin(tagged, ring_count, idx)?;
     for i in 0..ring_count {
-        process_linestring(raw, info, false, i, processor)?;
+        process_ring(raw, info, opts, idx, i, processor)?;
     }
     processor.polygon_end(tagged, idx)
 }
 
+fn process_ring<R: Read, P: GeomProcessor>(
+    raw: &mut R,
+    info: &WkbInfo,
+    opts: &WkbReadOptions,
+    polygon_idx: usize,
+    idx: usize,
+    processor: &mut P,
+) -> Result<()> {
+    if !opts.require_closed_rings {
+        return process_linestring(raw, info, opts, false, idx, processor);
+    }
+    let length = raw.ioread_with::<u32>(info.endian)? as usize;
+    processor.linestring_begin(false, length, idx)?;
+    let multi = processor.multi_dim();
+    let mut ends = None;
+    for i in 0..length {
+        let xy = process_coord(raw, info, opts, multi, i, processor)?;
+        ends = Some((ends.map_or(xy, |(first, _)| first), xy));
+    }
+    if let Some((first, last)) = ends {
+        if first != last {
+            return Err(GeozeroError::Geometry(format!(
+                "ring {idx} of polygon {polygon_idx} is not closed"
+            )));
+        }
+    }
+    processor.linestring_end(false, idx)
+}
+
 fn process_triangle<R: Read, P: GeomProcessor>(
     raw: &mut R,
     info: &WkbInfo,
+    opts: &WkbReadOptions,
     tagged: bool,
     idx: usize,
     processor: &mut P,
@@ -375,7 +1104,7 @@ fn process_triangle<R: Read, P: GeomProcessor>(
     let ring_count = raw.ioread_with::<u32>(info.endian)? as usize;
     processor.triangle_begin(tagged, ring_count, idx)?;
     for i in 0..ring_count {
-        process_linestring(raw, info, false, i, processor)?;
+        process_ring(raw, info, opts, idx, i, processor)?;
     }
     processor.triangle_end(tagged, idx)
 }
@@ -383,20 +1112,21 @@ fn process_triangle<R: Read, P: GeomProcessor>(
 fn process_compoundcurve<R: Read, P: GeomProcessor>(
     raw: &mut R,
     info: &WkbInfo,
-    read_header: fn(&mut R) -> Result<WkbInfo>,
+    read_header: ReadHeader<R>,
+    opts: &WkbReadOptions,
     idx: usize,
     processor: &mut P,
 ) -> Result<()> {
     let n_strings = raw.ioread_with::<u32>(info.endian)? as usize;
     processor.compoundcurve_begin(n_strings, idx)?;
     for i in 0..n_strings {
-        let info = read_header(raw)?;
+        let info = read_header(raw, opts)?;
         match info.base_type {
             WKBGeometryType::CircularString => {
-                process_circularstring(raw, &info, i, processor)?;
+                process_circularstring(raw, &info, opts, i, processor)?;
             }
             WKBGeometryType::LineString => {
-                process_linestring(raw, &info, false, i, processor)?;
+                process_linestring(raw, &info, opts, false, i, processor)?;
             }
             _ => return Err(GeozeroError::GeometryFormat),
         }
@@ -406,16 +1136,17 @@ fn process_compoundcurve<R: Read, P: GeomProcessor>(
 
 fn process_curve<R: Read, P: GeomProcessor>(
     raw: &mut R,
-    read_header: fn(&mut R) -> Result<WkbInfo>,
+    read_header: ReadHeader<R>,
+    opts: &WkbReadOptions,
     idx: usize,
     processor: &mut P,
 ) -> Result<()> {
-    let info = read_header(raw)?;
+    let info = read_header(raw, opts)?;
     match info.base_type {
-        WKBGeometryType::CircularString => process_circularstring(raw, &info, idx, processor),
-        WKBGeometryType::LineString => process_linestring(raw, &info, false, idx, processor),
+        WKBGeometryType::CircularString => process_circularstring(raw, &info, opts, idx, processor),
+        WKBGeometryType::LineString => process_linestring(raw, &info, opts, false, idx, processor),
         WKBGeometryType::CompoundCurve => {
-            process_compoundcurve(raw, &info, read_header, idx, processor)
+            process_compoundcurve(raw, &info, read_header, opts, idx, processor)
         }
         _ => Err(GeozeroError::GeometryFormat),
     }
@@ -424,14 +1155,15 @@ fn process_curve<R: Read, P: GeomProcessor>(
 fn process_curvepolygon<R: Read, P: GeomProcessor>(
     raw: &mut R,
     info: &WkbInfo,
-    read_header: fn(&mut R) -> Result<WkbInfo>,
+    read_header: ReadHeader<R>,
+    opts: &WkbReadOptions,
     idx: usize,
     processor: &mut P,
 ) -> Result<()> {
     let ring_count = raw.ioread_with::<u32>(info.endian)? as usize;
     processor.curvepolygon_begin(ring_count, idx)?;
     for i in 0..ring_count {
-        process_curve(raw, read_header, i, processor)?;
+        process_curve(raw, read_header, opts, i, processor)?;
     }
     processor.curvepolygon_end(idx)
 }
@@ -451,7 +1183,7 @@ mod test {
         )
         .unwrap();
         // Read header
-        let info = read_ewkb_header(&mut ewkb.as_slice()).unwrap();
+        let info = read_ewkb_header(&mut ewkb.as_slice(), &WkbReadOptions::default()).unwrap();
         assert_eq!(info.srid, None);
         assert!(info.has_z);
         assert!(info.has_m);
@@ -478,7 +1210,7 @@ mod test {
         let ewkb = hex::decode("01040000A0E6100000020000000101000080000000000000244000000000000034C0000000000000594001010000800000000000000000000000000000E0BF0000000000405940").unwrap();
 
         // Read header
-        let info = read_ewkb_header(&mut ewkb.as_slice()).unwrap();
+        let info = read_ewkb_header(&mut ewkb.as_slice(), &WkbReadOptions::default()).unwrap();
         assert_eq!(info.base_type, WKBGeometryType::MultiPoint);
         assert_eq!(info.srid, Some(4326));
         assert!(info.has_z);
@@ -592,6 +1324,31 @@ mod test {
         );
     }
 
+    #[test]
+    fn iso_wkb_surfaces_z() {
+        let wkb_to_wkt = |wkb_str: &str, dims: CoordDimensions| {
+            let wkb = hex::decode(wkb_str).unwrap();
+            let mut wkt_data: Vec<u8> = Vec::new();
+            let mut writer = WktWriter::new(&mut wkt_data);
+            writer.dims = dims;
+            process_wkb_geom(&mut wkb.as_slice(), &mut writer).unwrap();
+            String::from_utf8(wkt_data).unwrap()
+        };
+
+        // SELECT ST_AsBinary('POLYHEDRALSURFACE Z(((0 0 0,0 1 0,1 1 0,0 0 0)),((0 0 0,0 0 2,1 0 2,0 0 0)))'::geometry)
+        assert_eq!(
+            wkb_to_wkt("01F70300000200000001EB03000001000000040000000000000000000000000000000000000000000000000000000000000000000000000000000000F03F0000000000000000000000000000F03F000000000000F03F000000000000000000000000000000000000000000000000000000000000000001EB0300000100000004000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000040000000000000F03F00000000000000000000000000000040000000000000000000000000000000000000000000000000", CoordDimensions::xyz()),
+            "POLYHEDRALSURFACE(((0 0 0,0 1 0,1 1 0,0 0 0)),((0 0 0,0 0 2,1 0 2,0 0 0)))"
+        );
+
+        // SELECT ST_AsBinary('TIN ZM(((0 0 1 5,0 1 2 6,1 1 3 7,0 0 1 5)))'::geometry)
+        let tin = "01C80B00000100000001C90B0000010000000400000000000000000000000000000000000000000000000000F03F00000000000014400000000000000000000000000000F03F00000000000000400000000000001840000000000000F03F000000000000F03F00000000000008400000000000001C4000000000000000000000000000000000000000000000F03F0000000000001440";
+        assert_eq!(
+            wkb_to_wkt(tin, CoordDimensions::xyzm()),
+            "TIN(((0 0 1 5,0 1 2 6,1 1 3 7,0 0 1 5)))"
+        );
+    }
+
     fn ewkb_to_wkt(ewkb_str: &str, with_z: bool) -> String {
         let ewkb = hex::decode(ewkb_str).unwrap();
         let mut wkt_data: Vec<u8> = Vec::new();
@@ -608,7 +1365,7 @@ mod test {
     fn gpkg_geometries() {
         // pt2d
         let wkb = hex::decode("47500003E61000009A9999999999F13F9A9999999999F13F9A9999999999F13F9A9999999999F13F01010000009A9999999999F13F9A9999999999F13F").unwrap();
-        let info = read_gpkg_header(&mut wkb.as_slice()).unwrap();
+        let info = read_gpkg_header(&mut wkb.as_slice(), &WkbReadOptions::default()).unwrap();
         assert_eq!(info.base_type, WKBGeometryType::Point);
         assert!(!info.has_z);
         assert!(!info.has_m);
@@ -620,7 +1377,7 @@ mod test {
 
         // mln3dzm
         let wkb = hex::decode("47500003E6100000000000000000244000000000000034400000000000002440000000000000344001BD0B00000100000001BA0B0000020000000000000000003440000000000000244000000000000008400000000000001440000000000000244000000000000034400000000000001C400000000000000040").unwrap();
-        let info = read_gpkg_header(&mut wkb.as_slice()).unwrap();
+        let info = read_gpkg_header(&mut wkb.as_slice(), &WkbReadOptions::default()).unwrap();
         assert_eq!(info.base_type, WKBGeometryType::MultiLineString);
         assert!(info.has_z);
         assert!(info.has_m);
@@ -635,7 +1392,7 @@ mod test {
 
         // gc2d
         let wkb = hex::decode("47500003e6100000000000000000f03f0000000000003640000000000000084000000000000036400107000000020000000101000000000000000000f03f00000000000008400103000000010000000400000000000000000035400000000000003540000000000000364000000000000035400000000000003540000000000000364000000000000035400000000000003540").unwrap();
-        let info = read_gpkg_header(&mut wkb.as_slice()).unwrap();
+        let info = read_gpkg_header(&mut wkb.as_slice(), &WkbReadOptions::default()).unwrap();
         assert_eq!(info.base_type, WKBGeometryType::GeometryCollection);
         assert_eq!(info.envelope, vec![1.0, 22.0, 3.0, 22.0]);
 
@@ -647,9 +1404,820 @@ mod test {
         );
     }
 
+    #[test]
+    fn gpkg_srids() {
+        let pt_4326 = hex::decode("47500003E61000009A9999999999F13F9A9999999999F13F9A9999999999F13F9A9999999999F13F01010000009A9999999999F13F9A9999999999F13F").unwrap();
+        let pt_3857 = hex::decode("47500003110F00009A9999999999F13F9A9999999999F13F9A9999999999F13F9A9999999999F13F01010000009A9999999999F13F9A9999999999F13F").unwrap();
+        let histogram =
+            gpkg_srid_histogram([pt_4326.as_slice(), pt_3857.as_slice(), pt_4326.as_slice()])
+                .unwrap();
+        assert_eq!(histogram, BTreeMap::from([(3857, 1), (4326, 2)]));
+
+        assert!(gpkg_srid_histogram([&pt_4326[1..]]).is_err());
+    }
+
+    #[test]
+    fn gpkg_header_iter() {
+        let pt_4326 = hex::decode("47500003E61000009A9999999999F13F9A9999999999F13F9A9999999999F13F9A9999999999F13F01010000009A9999999999F13F9A9999999999F13F").unwrap();
+        // LINESTRING Z header without envelope, body omitted
+        let line_z = hex::decode("47500001E610000001EA030000").unwrap();
+        let headers =
+            gpkg_headers([pt_4326.as_slice(), line_z.as_slice(), &line_z[1..]]).collect::<Vec<_>>();
+        assert_eq!(headers.len(), 3);
+
+        let header = headers[0].as_ref().unwrap();
+        assert_eq!(header.geometry_type, WKBGeometryType::Point);
+        assert_eq!(header.srid, Some(4326));
+        assert_eq!(header.envelope, vec![1.1, 1.1, 1.1, 1.1]);
+        assert!(!header.dims.z && !header.dims.m);
+
+        let header = headers[1].as_ref().unwrap();
+        assert_eq!(header.geometry_type, WKBGeometryType::LineString);
+        assert_eq!(header.srid, Some(4326));
+        assert!(header.envelope.is_empty());
+        assert!(header.dims.z && !header.dims.m);
+
+        assert!(headers[2].is_err());
+    }
+
+    #[test]
+    fn ewkt() {
+        let ewkt = |ewkb_str: &str| ewkb_to_ewkt(&hex::decode(ewkb_str).unwrap()).unwrap();
+        // SELECT ST_AsEWKT('SRID=4326;POINT Z (10 20 30)'::geometry)
+        assert_eq!(
+            ewkt("01010000A0E6100000000000000000244000000000000034400000000000003E40"),
+            "SRID=4326;POINT(10 20 30)"
+        );
+        // SELECT ST_AsEWKT('LINESTRING(10 -20,0 -0.5)'::geometry)
+        assert_eq!(
+            ewkt("010200000002000000000000000000244000000000000034C00000000000000000000000000000E0BF"),
+            "LINESTRING(10 -20,0 -0.5)"
+        );
+        // SELECT ST_AsEWKT('SRID=3857;POINT ZM (1 2 3 4)'::geometry)
+        assert_eq!(
+            ewkt("01010000E0110F0000000000000000F03F000000000000004000000000000008400000000000001040"),
+            "SRID=3857;POINT(1 2 3 4)"
+        );
+        // SELECT ST_AsEWKT('SRID=4326;GEOMETRYCOLLECTION M (LINESTRING M (1 2 3,4 5 6))'::geometry)
+        assert_eq!(
+            ewkt("0107000060E610000001000000010200004002000000000000000000F03F00000000000000400000000000000840000000000000104000000000000014400000000000001840"),
+            "SRID=4326;GEOMETRYCOLLECTIONM(LINESTRINGM(1 2 3,4 5 6))"
+        );
+    }
+
+    #[test]
+    fn postgis_geography() {
+        struct SridCollector(Vec<Option<i32>>);
+        impl GeomProcessor for SridCollector {
+            fn srid(&mut self, srid: Option<i32>) -> Result<()> {
+                self.0.push(srid);
+                Ok(())
+            }
+        }
+
+        // SELECT ST_AsBinary('POINT(10 -20)'::geography) (without SRID)
+        let wkb = hex::decode("0101000000000000000000244000000000000034C0").unwrap();
+        let mut srids = SridCollector(Vec::new());
+        process_postgis_geography(&wkb, &mut srids).unwrap();
+        process_postgis_geometry(&wkb, &mut srids).unwrap();
+        // SELECT 'SRID=4269;POINT(10 -20)'::geography
+        let ewkb = hex::decode("0101000020AD100000000000000000244000000000000034C0").unwrap();
+        process_postgis_geography(&ewkb, &mut srids).unwrap();
+        assert_eq!(srids.0, [Some(4326), None, Some(4269)]);
+
+        let mut wkt_data: Vec<u8> = Vec::new();
+        process_postgis_geography(&wkb, &mut WktWriter::new(&mut wkt_data)).unwrap();
+        assert_eq!(std::str::from_utf8(&wkt_data).unwrap(), "POINT(10 -20)");
+    }
+
+    #[test]
+    fn geom_at_offset() {
+        let mut record = vec![0xFF; 8];
+        // SELECT 'POINT(10 -20)'::geometry
+        record.extend(hex::decode("0101000000000000000000244000000000000034C0").unwrap());
+        record.extend([1, 2, 3]);
+        let mut wkt_data: Vec<u8> = Vec::new();
+        let end = process_ewkb_geom_at(&record, 8, &mut WktWriter::new(&mut wkt_data)).unwrap();
+        assert_eq!(end, 29);
+        assert_eq!(&record[end..], &[1, 2, 3]);
+        assert_eq!(std::str::from_utf8(&wkt_data).unwrap(), "POINT(10 -20)");
+        assert!(process_ewkb_geom_at(&record, 40, &mut ProcessorSink).is_err());
+    }
+
+    #[test]
+    fn geom_from_slice() {
+        // SELECT 'SRID=4326;LINESTRING (10 -20, 0 -0.5)'::geometry, followed by another record
+        let mut data = hex::decode("0102000020E610000002000000000000000000244000000000000034C00000000000000000000000000000E0BF").unwrap();
+        data.extend([1, 1, 0, 0, 0]);
+        let mut wkt_data: Vec<u8> = Vec::new();
+        let n = process_ewkb_geom_from_slice(&data, &mut WktWriter::new(&mut wkt_data)).unwrap();
+        assert_eq!(n, data.len() - 5);
+
+        let mut cached_wkt: Vec<u8> = Vec::new();
+        process_ewkb_geom_exact(&data[..n], &mut WktWriter::new(&mut cached_wkt)).unwrap();
+        assert_eq!(cached_wkt, wkt_data);
+        assert_eq!(
+            std::str::from_utf8(&wkt_data).unwrap(),
+            "LINESTRING(10 -20,0 -0.5)"
+        );
+    }
+
+    #[test]
+    fn try_process_batch() {
+        let good = hex::decode("0101000000000000000000244000000000000034C0").unwrap();
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = WktWriter::new(&mut out);
+        writer.record_terminator = Some("\n".to_string());
+        assert!(try_process_ewkb_geom(&good, &mut writer).is_ok());
+        assert!(try_process_ewkb_geom(&good[..good.len() - 4], &mut writer).is_err());
+        assert!(try_process_ewkb_geom(&good, &mut writer).is_ok());
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            "POINT(10 -20)\nPOINT(10 -20)\n"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "with-flate2")]
+    fn ewkb_gz() {
+        use std::io::Write;
+
+        // SELECT 'SRID=4326;POINT(10 -20)'::geometry
+        let ewkb = hex::decode("0101000020E6100000000000000000244000000000000034C0").unwrap();
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(&ewkb).unwrap();
+        let gz = encoder.finish().unwrap();
+
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = WktWriter::new(&mut out);
+        writer.ewkt = true;
+        process_ewkb_gz(gz.as_slice(), &mut writer).unwrap();
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            "SRID=4326;POINT(10 -20)"
+        );
+
+        assert!(process_ewkb_gz(ewkb.as_slice(), &mut WktWriter::new(&mut Vec::new())).is_err());
+    }
+
+    #[test]
+    fn input_axis_order() {
+        // LINESTRING(47.5 8.5,46 7) with lat/lon axis order
+        let wkb = hex::decode(
+            "0102000000020000000000000000c04740000000000000214000000000000047400000000000001c40",
+        )
+        .unwrap();
+        let opts = WkbReadOptions {
+            input_axis_order: AxisOrder::LatLon,
+            ..Default::default()
+        };
+        let mut wkt_data: Vec<u8> = Vec::new();
+        let mut writer = WktWriter::new(&mut wkt_data);
+        process_wkb_type_geom_with_options(
+            &mut wkb.as_slice(),
+            &mut writer,
+            WkbDialect::Wkb,
+            &opts,
+        )
+        .unwrap();
+        assert_eq!(
+            std::str::from_utf8(&wkt_data).unwrap(),
+            "LINESTRING(8.5 47.5,7 46)"
+        );
+    }
+
+    #[test]
+    fn iso_ewkb_hybrid() {
+        // GDAL ISO WKB with SRID flag: SRID=4326;POINT Z (1 2 3)
+        let ewkb =
+            hex::decode("01e9030020e6100000000000000000f03f00000000000000400000000000000840")
+                .unwrap();
+        let info = read_ewkb_header(&mut ewkb.as_slice(), &WkbReadOptions::default()).unwrap();
+        assert_eq!(info.base_type, WKBGeometryType::Point);
+        assert!(info.has_z);
+        assert!(!info.has_m);
+        assert_eq!(info.srid, Some(4326));
+        assert_eq!(ewkb_to_ewkt(&ewkb).unwrap(), "SRID=4326;POINT(1 2 3)");
+
+        // SRID=4326;LINESTRING Z (1 2 3,4 5 6)
+        let ewkb = hex::decode("01ea030020e610000002000000000000000000f03f00000000000000400000000000000840000000000000104000000000000014400000000000001840").unwrap();
+        assert_eq!(
+            ewkb_to_ewkt(&ewkb).unwrap(),
+            "SRID=4326;LINESTRING(1 2 3,4 5 6)"
+        );
+    }
+
+    #[test]
+    fn summary() {
+        // SELECT 'SRID=4326;POLYGON((0 0 1,2 0 1,2 3 1,0 0 1))'::geometry
+        let ewkb = hex::decode("01030000A0E6100000010000000400000000000000000000000000000000000000000000000000F03F00000000000000400000000000000000000000000000F03F00000000000000400000000000000840000000000000F03F00000000000000000000000000000000000000000000F03F").unwrap();
+        assert_eq!(
+            ewkb_summary(&ewkb).unwrap(),
+            GeomSummary {
+                geom_type: WKBGeometryType::Polygon,
+                srid: Some(4326),
+                wkt: "POLYGON((0 0 1,2 0 1,2 3 1,0 0 1))".to_string(),
+                bbox: Some([0.0, 0.0, 2.0, 3.0]),
+                coord_count: 4,
+            }
+        );
+        // SELECT 'POINT EMPTY'::geometry
+        let ewkb = hex::decode("0101000000000000000000F87F000000000000F87F").unwrap();
+        let summary = ewkb_summary(&ewkb).unwrap();
+        assert_eq!((summary.bbox, summary.coord_count), (None, 0));
+    }
+
+    #[test]
+    fn from_bytes() {
+        // SELECT 'SRID=4326;POINT(10 -20)'::geometry
+        let data = bytes::Bytes::from(
+            hex::decode("0101000020E6100000000000000000244000000000000034C0").unwrap(),
+        );
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = WktWriter::new(&mut out);
+        process_ewkb_geom_bytes(&data, &mut writer).unwrap();
+        assert_eq!(std::str::from_utf8(&out).unwrap(), "POINT(10 -20)");
+    }
+
+    #[test]
+    fn jsonl_hex() {
+        let lines = [
+            r#"{"geom":"0101000020E6100000000000000000244000000000000034C0","name":"a"}"#,
+            r#"{"name":"b","geom":"01020000000200000000000000000000000000000000000000000000000000F03F000000000000F03F"}"#,
+        ];
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = WktWriter::new(&mut out);
+        writer.record_terminator = Some("\n".to_string());
+        for line in lines {
+            process_jsonl_ewkb_hex(line, "geom", &mut writer).unwrap();
+        }
+        assert!(process_jsonl_ewkb_hex(lines[0], "wkb", &mut writer).is_err());
+        assert!(process_ewkb_hex("010", &mut writer).is_err());
+        assert!(process_ewkb_hex("01zz", &mut writer).is_err());
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            "POINT(10 -20)\nLINESTRING(0 0,1 1)\n"
+        );
+    }
+
+    #[test]
+    fn unsupported_type() {
+        // Curve with Z, little endian
+        let wkb = hex::decode("01F5030000").unwrap();
+        let err = Wkb(wkb).to_wkt().unwrap_err();
+        assert!(matches!(
+            &err,
+            GeozeroError::UnsupportedGeometryType { type_id: 13, name } if name == "Curve"
+        ));
+        assert_eq!(err.to_string(), "unsupported geometry type `Curve` (13)");
+        // corrupt type code
+        let wkb = hex::decode("01E7030000").unwrap();
+        assert!(matches!(
+            Wkb(wkb).to_wkt(),
+            Err(GeozeroError::GeometryFormat)
+        ));
+    }
+
+    #[test]
+    fn unknown_as_empty() {
+        let read = |wkb: &[u8], opts: &WkbReadOptions| {
+            let mut wkt_data: Vec<u8> = Vec::new();
+            let mut raw = wkb;
+            let info = read_wkb_header(&mut raw, opts)?;
+            let mut writer = WktWriter::new(&mut wkt_data);
+            process_wkb_geom_n(&mut raw, &info, read_wkb_header, opts, 0, &mut writer)?;
+            Ok::<_, GeozeroError>(String::from_utf8(wkt_data).unwrap())
+        };
+        let wkb = [1, 0, 0, 0, 0];
+        assert!(matches!(
+            read(&wkb, &WkbReadOptions::default()),
+            Err(GeozeroError::GeometryFormat)
+        ));
+        let opts = WkbReadOptions {
+            unknown_as_empty: true,
+            ..Default::default()
+        };
+        assert_eq!(read(&wkb, &opts).unwrap(), "GEOMETRYCOLLECTION EMPTY");
+        // other unknown type codes are still rejected
+        assert!(matches!(
+            read(&[1, 99, 0, 0, 0], &opts),
+            Err(GeozeroError::GeometryFormat)
+        ));
+    }
+
+    #[test]
+    fn bare_multipoint() {
+        // MULTIPOINT Z(1 2 3,4 5 6) without point headers
+        let wkb = hex::decode("01EC03000002000000000000000000F03F00000000000000400000000000000840000000000000104000000000000014400000000000001840").unwrap();
+        let opts = WkbReadOptions {
+            bare_multipoint: true,
+            ..Default::default()
+        };
+        let mut wkt_data: Vec<u8> = Vec::new();
+        let mut writer = WktWriter::new(&mut wkt_data);
+        writer.dims = CoordDimensions::xyz();
+        let mut raw = wkb.as_slice();
+        let info = read_wkb_header(&mut raw, &opts).unwrap();
+        process_wkb_geom_n(&mut raw, &info, read_wkb_header, &opts, 0, &mut writer).unwrap();
+        assert!(raw.is_empty());
+        assert_eq!(
+            std::str::from_utf8(&wkt_data).unwrap(),
+            "MULTIPOINT(1 2 3,4 5 6)"
+        );
+    }
+
+    #[test]
+    fn require_closed_rings() {
+        let opts = WkbReadOptions {
+            require_closed_rings: true,
+            ..Default::default()
+        };
+        let read = |wkb_str: &str| {
+            let wkb = hex::decode(wkb_str).unwrap();
+            let mut raw = wkb.as_slice();
+            let info = read_wkb_header(&mut raw, &opts).unwrap();
+            process_wkb_geom_n(
+                &mut raw,
+                &info,
+                read_wkb_header,
+                &opts,
+                0,
+                &mut ProcessorSink,
+            )
+            .map_err(|e| e.to_string())
+        };
+        // POLYGON((0 0,4 0,4 4,0 0),(1 0.5,3 0.5,3 2,1 0.5))
+        assert!(read("010300000002000000040000000000000000000000000000000000000000000000000010400000000000000000000000000000104000000000000010400000000000000000000000000000000004000000000000000000F03F000000000000E03F0000000000000840000000000000E03F00000000000008400000000000000040000000000000F03F000000000000E03F").is_ok());
+        // MULTIPOLYGON(((0 0,4 0,4 4,0 0)),((0 0,4 0,4 4,0 0),(1 0.5,3 0.5,3 2)))
+        assert_eq!(
+            read("0106000000020000000103000000010000000400000000000000000000000000000000000000000000000000104000000000000000000000000000001040000000000000104000000000000000000000000000000000010300000002000000040000000000000000000000000000000000000000000000000010400000000000000000000000000000104000000000000010400000000000000000000000000000000003000000000000000000F03F000000000000E03F0000000000000840000000000000E03F00000000000008400000000000000040").unwrap_err(),
+            "processing geometry `ring 1 of polygon 1 is not closed`"
+        );
+    }
+
+    #[test]
+    fn validate() {
+        // SELECT 'POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))'::geometry
+        let wkb = hex::decode("010300000001000000050000000000000000000000000000000000000000000000000000400000000000000000000000000000004000000000000000400000000000000000000000000000004000000000000000000000000000000000").unwrap();
+        let mut validator = WkbValidateProcessor::new(WkbDialect::Wkb);
+        assert!(validator.validate(&wkb).is_ok());
+        assert!(validator.validate(&wkb[..wkb.len() - 8]).is_err());
+        assert!(validator
+            .validate(&[wkb.as_slice(), &[0]].concat())
+            .is_err());
+        // unknown geometry type
+        assert!(validator.validate(&[1, 99, 0, 0, 0]).is_err());
+    }
+
+    #[test]
+    fn ewkb_exact() {
+        // SELECT 'POINT(10 -20)'::geometry
+        let ewkb = hex::decode("0101000000000000000000244000000000000034C0").unwrap();
+        let mut wkt_data: Vec<u8> = Vec::new();
+        assert!(process_ewkb_geom_exact(&ewkb, &mut WktWriter::new(&mut wkt_data)).is_ok());
+        assert_eq!(std::str::from_utf8(&wkt_data).unwrap(), "POINT(10 -20)");
+
+        let mut trailing = ewkb.clone();
+        trailing.extend_from_slice(&[0, 0]);
+        let err =
+            process_ewkb_geom_exact(&trailing, &mut WktWriter::new(&mut Vec::new())).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "processing geometry `2 trailing bytes after EWKB geometry`"
+        );
+
+        let err = process_ewkb_geom_exact(
+            &ewkb[..ewkb.len() - 1],
+            &mut WktWriter::new(&mut Vec::new()),
+        )
+        .unwrap_err();
+        assert_eq!(err.to_string(), "I/O error");
+    }
+
+    #[test]
+    fn ewkb_bbox_flag() {
+        // POINT(10 -20) with bbox flag 0x20000000 and bbox (10 -20, 10 -20)
+        let ewkb = hex::decode("0101000020000000000000244000000000000034C0000000000000244000000000000034C0000000000000244000000000000034C0").unwrap();
+        let opts = WkbReadOptions {
+            ewkb_bbox_flag: Some(0x2000_0000),
+            ..Default::default()
+        };
+
+        let info = read_ewkb_header(&mut ewkb.as_slice(), &opts).unwrap();
+        assert_eq!(info.srid, None);
+        assert_eq!(info.envelope, vec![10.0, -20.0, 10.0, -20.0]);
+
+        let mut wkt_data: Vec<u8> = Vec::new();
+        assert!(process_wkb_type_geom_with_options(
+            &mut ewkb.as_slice(),
+            &mut WktWriter::new(&mut wkt_data),
+            WkbDialect::Ewkb,
+            &opts
+        )
+        .is_ok());
+        assert_eq!(std::str::from_utf8(&wkt_data).unwrap(), "POINT(10 -20)");
+
+        // Without the option, the flag is read as SRID flag and coordinates are shifted
+        let mut wkt_data: Vec<u8> = Vec::new();
+        assert!(
+            process_ewkb_geom(&mut ewkb.as_slice(), &mut WktWriter::new(&mut wkt_data)).is_ok()
+        );
+        assert_ne!(std::str::from_utf8(&wkt_data).unwrap(), "POINT(10 -20)");
+    }
+
+    #[test]
+    fn auto_dialect() {
+        let auto_to_wkt = |hex_str: &str, with_z: bool| {
+            let wkb = hex::decode(hex_str).unwrap();
+            let mut wkt_data: Vec<u8> = Vec::new();
+            let mut writer = WktWriter::new(&mut wkt_data);
+            writer.dims.z = with_z;
+            process_auto_geom(&mut std::io::BufReader::new(wkb.as_slice()), &mut writer).unwrap();
+            (detect_dialect(&wkb), String::from_utf8(wkt_data).unwrap())
+        };
+
+        // OGC WKB POINT Z (ISO type code 1001)
+        assert_eq!(
+            auto_to_wkt(
+                "01E9030000000000000000244000000000000034C00000000000005940",
+                true
+            ),
+            (WkbDialect::Wkb, "POINT(10 -20 100)".to_string())
+        );
+        // SELECT 'SRID=4326;LINESTRING (10 -20 100, 0 -0.5 101)'::geometry
+        assert_eq!(
+            auto_to_wkt("01020000A0E610000002000000000000000000244000000000000034C000000000000059400000000000000000000000000000E0BF0000000000405940", true),
+            (WkbDialect::Ewkb, "LINESTRING(10 -20 100,0 -0.5 101)".to_string())
+        );
+        // GeoPackage pt2d
+        assert_eq!(
+            auto_to_wkt("47500003E61000009A9999999999F13F9A9999999999F13F9A9999999999F13F9A9999999999F13F01010000009A9999999999F13F9A9999999999F13F", false),
+            (WkbDialect::Geopackage, "POINT(1.1 1.1)".to_string())
+        );
+    }
+
+    #[test]
+    fn force_dims() {
+        // LINESTRING(10 -20 100, 0 -0.5 101) with Z flag missing in the header
+        let ewkb = hex::decode("010200000002000000000000000000244000000000000034C000000000000059400000000000000000000000000000E0BF0000000000405940").unwrap();
+        let opts = WkbReadOptions {
+            force_dims: Some(CoordDimensions::xyz()),
+            ..Default::default()
+        };
+        let mut wkt_data: Vec<u8> = Vec::new();
+        let mut writer = WktWriter::new(&mut wkt_data);
+        writer.dims.z = true;
+        assert!(process_wkb_type_geom_with_options(
+            &mut ewkb.as_slice(),
+            &mut writer,
+            WkbDialect::Ewkb,
+            &opts
+        )
+        .is_ok());
+        assert_eq!(
+            std::str::from_utf8(&wkt_data).unwrap(),
+            "LINESTRING(10 -20 100,0 -0.5 101)"
+        );
+    }
+
+    #[test]
+    fn m_as_z() {
+        // POINT M (1 2 3)
+        let mut wkb = vec![1, 0xD1, 0x07, 0, 0];
+        for ord in [1f64, 2.0, 3.0] {
+            wkb.extend_from_slice(&ord.to_le_bytes());
+        }
+        let opts = WkbReadOptions {
+            m_as_z: true,
+            ..Default::default()
+        };
+        let mut wkt_data: Vec<u8> = Vec::new();
+        let mut writer = WktWriter::new(&mut wkt_data);
+        writer.dims.z = true;
+        process_wkb_type_geom_with_options(
+            &mut wkb.as_slice(),
+            &mut writer,
+            WkbDialect::Wkb,
+            &opts,
+        )
+        .unwrap();
+        assert_eq!(std::str::from_utf8(&wkt_data).unwrap(), "POINT(1 2 3)");
+    }
+
+    #[test]
+    fn zero_count_bodies() {
+        assert_eq!(
+            &ewkb_to_wkt("010200000000000000", false),
+            "LINESTRING EMPTY"
+        );
+        assert_eq!(&ewkb_to_wkt("010300000000000000", false), "POLYGON EMPTY");
+        assert_eq!(
+            &ewkb_to_wkt("010600000000000000", false),
+            "MULTIPOLYGON EMPTY"
+        );
+        // GEOMETRYCOLLECTION(LINESTRING EMPTY,POINT(1 2))
+        assert_eq!(
+            &ewkb_to_wkt(
+                "0107000000020000000102000000000000000101000000000000000000F03F0000000000000040",
+                false
+            ),
+            "GEOMETRYCOLLECTION(LINESTRING EMPTY,POINT(1 2))"
+        );
+    }
+
+    #[test]
+    fn xy_block_reading() {
+        struct Coords(Vec<(usize, f64, f64)>);
+        impl GeomProcessor for Coords {
+            fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+                self.0.push((idx, x, y));
+                Ok(())
+            }
+        }
+
+        // Big endian linestring spanning multiple read blocks
+        let mut wkb = vec![0, 0, 0, 0, 2];
+        wkb.extend_from_slice(&300u32.to_be_bytes());
+        for i in 0..300 {
+            wkb.extend_from_slice(&(i as f64).to_be_bytes());
+            wkb.extend_from_slice(&(-(i as f64)).to_be_bytes());
+        }
+        let mut processor = Coords(Vec::new());
+        process_wkb_geom(&mut wkb.as_slice(), &mut processor).unwrap();
+        assert_eq!(processor.0.len(), 300);
+        assert_eq!(processor.0[299], (299, 299.0, -299.0));
+
+        // truncated input: complete coordinates are processed before the error
+        let mut processor = Coords(Vec::new());
+        assert!(process_wkb_geom(&mut &wkb[..wkb.len() - 1], &mut processor).is_err());
+        assert_eq!(processor.0.len(), 299);
+    }
+
+    #[test]
+    fn mixed_dims_multipoint() {
+        // MULTIPOINT with a 2D member followed by a 3D member
+        let ewkb = "0104000000020000000101000000000000000000F03F00000000000000400101000080000000000000084000000000000010400000000000001440";
+        assert_eq!(&ewkb_to_wkt(ewkb, false), "MULTIPOINT(1 2,3 4)");
+        assert_eq!(&ewkb_to_wkt(ewkb, true), "MULTIPOINT(1 2,3 4 5)");
+    }
+
+    #[test]
+    fn member_srids() {
+        struct SridLog(Vec<Option<i32>>);
+        impl GeomProcessor for SridLog {
+            fn srid(&mut self, srid: Option<i32>) -> Result<()> {
+                self.0.push(srid);
+                Ok(())
+            }
+        }
+
+        // SRID=4326;GEOMETRYCOLLECTION(SRID=4326;POINT(1 2),SRID=3857;POINT(3 4))
+        let ewkb = hex::decode("0107000020E6100000020000000101000020E6100000000000000000F03F00000000000000400101000020110F000000000000000008400000000000001040").unwrap();
+        assert_eq!(
+            &ewkb_to_wkt(&hex::encode(&ewkb), false),
+            "GEOMETRYCOLLECTION(POINT(1 2),POINT(3 4))"
+        );
+
+        let mut processor = SridLog(Vec::new());
+        process_ewkb_geom(&mut ewkb.as_slice(), &mut processor).unwrap();
+        assert_eq!(processor.0, vec![Some(4326)]);
+
+        let opts = WkbReadOptions {
+            member_srids: true,
+            ..Default::default()
+        };
+        let mut processor = SridLog(Vec::new());
+        process_wkb_type_geom_with_options(
+            &mut ewkb.as_slice(),
+            &mut processor,
+            WkbDialect::Ewkb,
+            &opts,
+        )
+        .unwrap();
+        assert_eq!(processor.0, vec![Some(4326), Some(4326), Some(3857)]);
+    }
+
+    #[test]
+    fn srid_hook() {
+        struct SridCapture(Option<i32>);
+        impl GeomProcessor for SridCapture {
+            fn srid(&mut self, srid: Option<i32>) -> Result<()> {
+                self.0 = srid;
+                Ok(())
+            }
+        }
+
+        // SELECT 'SRID=4326;MULTIPOINT (10 -20 100, 0 -0.5 101)'::geometry
+        let ewkb = hex::decode("01040000A0E6100000020000000101000080000000000000244000000000000034C0000000000000594001010000800000000000000000000000000000E0BF0000000000405940").unwrap();
+        let mut processor = SridCapture(None);
+        process_ewkb_geom(&mut ewkb.as_slice(), &mut processor).unwrap();
+        assert_eq!(processor.0, Some(4326));
+
+        // SELECT 'POINT(10 -20)'::geometry
+        let ewkb = hex::decode("0101000000000000000000244000000000000034C0").unwrap();
+        process_ewkb_geom(&mut ewkb.as_slice(), &mut processor).unwrap();
+        assert_eq!(processor.0, None);
+    }
+
+    #[test]
+    #[cfg(feature = "with-gml")]
+    fn srid_reset() {
+        let mut gml: Vec<u8> = Vec::new();
+        let mut writer = crate::gml::GmlWriter::new(&mut gml);
+        // SELECT 'SRID=4326;POINT(10 -20)'::geometry
+        let ewkb = hex::decode("0101000020E6100000000000000000244000000000000034C0").unwrap();
+        process_ewkb_geom(&mut ewkb.as_slice(), &mut writer).unwrap();
+        // SELECT 'POINT(10 -20)'::geometry
+        let ewkb = hex::decode("0101000000000000000000244000000000000034C0").unwrap();
+        process_ewkb_geom(&mut ewkb.as_slice(), &mut writer).unwrap();
+        assert_eq!(
+            std::str::from_utf8(&gml).unwrap(),
+            concat!(
+                r#"<gml:Point gml:id="geom.0" srsName="urn:ogc:def:crs:EPSG::4326" srsDimension="2"><gml:pos>10 -20</gml:pos></gml:Point>"#,
+                r#"<gml:Point gml:id="geom.1" srsDimension="2"><gml:pos>10 -20</gml:pos></gml:Point>"#
+            )
+        );
+    }
+
+    #[test]
+    fn fixed_point_coords() {
+        // LINESTRING(10.5 -20,0 1) with i64 ordinates scaled by 1e6
+        let mut wkb = vec![1, 2, 0, 0, 0, 2, 0, 0, 0];
+        for ord in [10_500_000i64, -20_000_000, 0, 1_000_000] {
+            wkb.extend_from_slice(&ord.to_le_bytes());
+        }
+        let opts = WkbReadOptions {
+            fixed_point_scale: Some(1e6),
+            ..Default::default()
+        };
+        let mut wkt_data: Vec<u8> = Vec::new();
+        assert!(process_wkb_type_geom_with_options(
+            &mut wkb.as_slice(),
+            &mut WktWriter::new(&mut wkt_data),
+            WkbDialect::Wkb,
+            &opts
+        )
+        .is_ok());
+        assert_eq!(
+            std::str::from_utf8(&wkt_data).unwrap(),
+            "LINESTRING(10.5 -20,0 1)"
+        );
+    }
+
+    #[test]
+    fn max_depth() {
+        fn nested_collection(depth: usize) -> Vec<u8> {
+            let mut wkb = Vec::new();
+            for _ in 0..depth {
+                wkb.extend_from_slice(&[1, 7, 0, 0, 0, 1, 0, 0, 0]);
+            }
+            // POINT(1 2)
+            wkb.extend_from_slice(&[1, 1, 0, 0, 0]);
+            wkb.extend_from_slice(&1f64.to_le_bytes());
+            wkb.extend_from_slice(&2f64.to_le_bytes());
+            wkb
+        }
+        let process = |wkb: Vec<u8>, opts: &WkbReadOptions| {
+            process_wkb_type_geom_with_options(
+                &mut wkb.as_slice(),
+                &mut WktWriter::new(&mut Vec::new()),
+                WkbDialect::Wkb,
+                opts,
+            )
+        };
+
+        let opts = WkbReadOptions {
+            max_depth: 3,
+            ..Default::default()
+        };
+        assert!(process(nested_collection(3), &opts).is_ok());
+        assert!(matches!(
+            process(nested_collection(4), &opts),
+            Err(GeozeroError::GeometryFormat)
+        ));
+
+        let opts = WkbReadOptions::default();
+        assert!(process(nested_collection(100), &opts).is_ok());
+        assert!(matches!(
+            process(nested_collection(10_000), &opts),
+            Err(GeozeroError::GeometryFormat)
+        ));
+    }
+
+    #[test]
+    fn normalize_endian() {
+        // SRID=4326;MULTIPOLYGON(((0 0,1 0,1 1,0 0))) as big endian EWKB
+        let be = hex::decode("0020000006000010E60000000100000000030000000100000004000000000000000000000000000000003FF000000000000000000000000000003FF00000000000003FF000000000000000000000000000000000000000000000").unwrap();
+        let le = normalize_wkb_endian(&be, WKBByteOrder::Ndr).unwrap();
+        assert_eq!(le[0], WKBByteOrder::Ndr as u8);
+        assert_eq!(le.len(), be.len());
+
+        let mut wkt_data: Vec<u8> = Vec::new();
+        let info = read_ewkb_header(&mut le.as_slice(), &WkbReadOptions::default()).unwrap();
+        assert_eq!(info.srid, Some(4326));
+        process_ewkb_geom(&mut le.as_slice(), &mut WktWriter::new(&mut wkt_data)).unwrap();
+        assert_eq!(
+            std::str::from_utf8(&wkt_data).unwrap(),
+            "MULTIPOLYGON(((0 0,1 0,1 1,0 0)))"
+        );
+
+        assert_eq!(normalize_wkb_endian(&le, WKBByteOrder::Xdr).unwrap(), be);
+    }
+
+    #[test]
+    fn first_coordinate() {
+        // POINT(10 -20)
+        let wkb = [
+            1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 64, 0, 0, 0, 0, 0, 0, 52, 192,
+        ];
+        assert_eq!(
+            first_coord(&mut wkb.as_slice(), WkbDialect::Wkb).unwrap(),
+            Some((10.0, -20.0))
+        );
+
+        // POLYGON((3 4,5 4,5 6,3 4)), truncated after the first vertex
+        let wkb = hex::decode("0103000000010000000400000000000000000008400000000000001040000000000000144000000000000010400000000000001440000000000000184000000000000008400000000000001040").unwrap();
+        assert_eq!(
+            first_coord(&mut &wkb[..29], WkbDialect::Wkb).unwrap(),
+            Some((3.0, 4.0))
+        );
+
+        // POINT EMPTY
+        let wkb = hex::decode("0101000000000000000000F87F000000000000F87F").unwrap();
+        assert_eq!(
+            first_coord(&mut wkb.as_slice(), WkbDialect::Wkb).unwrap(),
+            None
+        );
+        // POLYGON EMPTY
+        let wkb = [1, 3, 0, 0, 0, 0, 0, 0, 0];
+        assert_eq!(
+            first_coord(&mut wkb.as_slice(), WkbDialect::Wkb).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn collection_members() {
+        // SELECT 'GeometryCollection(POINT (10 10),POINT (30 30),LINESTRING (15 15, 20 20))'::geometry
+        let ewkb = hex::decode("01070000000300000001010000000000000000002440000000000000244001010000000000000000003E400000000000003E400102000000020000000000000000002E400000000000002E4000000000000034400000000000003440").unwrap();
+        let members = wkb_collection_members(&ewkb, WkbDialect::Ewkb)
+            .unwrap()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(members.len(), 3);
+        assert_eq!(members[0].0, WKBGeometryType::Point);
+        assert_eq!(members[2].0, WKBGeometryType::LineString);
+        let wkts: Vec<String> = members
+            .iter()
+            .map(|(_, raw)| Ewkb(raw.to_vec()).to_wkt().unwrap())
+            .collect();
+        assert_eq!(
+            wkts,
+            vec!["POINT(10 10)", "POINT(30 30)", "LINESTRING(15 15,20 20)"]
+        );
+
+        // Truncated member
+        let mut members =
+            wkb_collection_members(&ewkb[..ewkb.len() - 8], WkbDialect::Ewkb).unwrap();
+        assert!(members.next().unwrap().is_ok());
+        assert!(members.next().unwrap().is_ok());
+        assert!(members.next().unwrap().is_err());
+        assert!(members.next().is_none());
+
+        // Not a collection
+        let ewkb = hex::decode("0101000000000000000000244000000000000034C0").unwrap();
+        assert!(wkb_collection_members(&ewkb, WkbDialect::Ewkb).is_err());
+    }
+
+    #[test]
+    fn processor_error() {
+        struct CoordLimit(usize);
+        impl GeomProcessor for CoordLimit {
+            fn xy(&mut self, _x: f64, _y: f64, idx: usize) -> Result<()> {
+                if idx >= self.0 {
+                    return Err(GeozeroError::Geometry(
+                        "coordinate limit exceeded".to_string(),
+                    ));
+                }
+                Ok(())
+            }
+        }
+        // SELECT 'LINESTRING (10 -20, 0 -0.5)'::geometry
+        let ewkb = hex::decode(
+            "010200000002000000000000000000244000000000000034C00000000000000000000000000000E0BF",
+        )
+        .unwrap();
+        let err = process_ewkb_geom(&mut ewkb.as_slice(), &mut CoordLimit(1)).unwrap_err();
+        assert!(
+            matches!(err, GeozeroError::Geometry(ref msg) if msg == "coordinate limit exceeded")
+        );
+        assert!(process_ewkb_geom(&mut ewkb.as_slice(), &mut CoordLimit(2)).is_ok());
+    }
+
     #[test]
     fn scroll_error() {
-        let err = read_ewkb_header(&mut std::io::Cursor::new(b"")).unwrap_err();
+        let err = read_ewkb_header(&mut std::io::Cursor::new(b""), &WkbReadOptions::default())
+            .unwrap_err();
         assert_eq!(err.to_string(), "I/O error");
     }
 
@@ -1,4 +1,5 @@
-use crate::error::Result;
+use crate::algorithm::extend_bbox;
+use crate::error::{GeozeroError, Result};
 use crate::wkb::{WKBByteOrder, WKBGeometryType, WkbDialect};
 use crate::{CoordDimensions, FeatureProcessor, GeomProcessor, PropertyProcessor};
 use scroll::IOwrite;
@@ -6,9 +7,15 @@ use std::io::Write;
 
 /// WKB writer.
 pub struct WkbWriter<'a, W: Write> {
+    /// Written dimensions
+    ///
+    /// Determine the geometry type codes and the written ordinates, e.g. M values are
+    /// omitted without `dims.m`. Missing Z or M values are written as NaN.
     pub dims: CoordDimensions,
     pub srid: Option<i32>,
     /// Geometry envelope (GPKG)
+    ///
+    /// Without envelope, the 2D envelope is computed from the written coordinates.
     pub envelope: Vec<f64>,
     /// Envelope dimensions (GPKG)
     pub envelope_dims: CoordDimensions,
@@ -16,10 +23,18 @@ pub struct WkbWriter<'a, W: Write> {
     pub extended_gpkg: bool,
     /// Empty geometry flag (GPKG)
     pub empty: bool,
-    endian: scroll::Endian,
+    /// Byte order of the output (default: little endian)
+    ///
+    /// All values are converted explicitly, so the output is independent of the
+    /// byte order of the host, e.g. identical on big endian platforms.
+    pub endian: WKBByteOrder,
     dialect: WkbDialect,
     first_header: bool,
     geom_state: GeomState,
+    depth: usize,
+    /// Geometry buffered while computing the GPKG envelope
+    body: Option<Vec<u8>>,
+    bbox: Option<[f64; 4]>,
     out: &'a mut W,
 }
 
@@ -39,22 +54,88 @@ impl<'a, W: Write> WkbWriter<'a, W> {
             envelope_dims: CoordDimensions::default(),
             extended_gpkg: false,
             empty: false,
-            endian: scroll::LE,
+            endian: WKBByteOrder::Ndr,
             dialect,
             first_header: true,
             geom_state: GeomState::Normal,
+            depth: 0,
+            body: None,
+            bbox: None,
             out,
         }
     }
 
+    /// Use a precomputed 2D envelope `[minx, miny, maxx, maxy]` for the GPKG header.
+    ///
+    /// Skips computing the envelope from the written coordinates.
+    pub fn with_envelope(mut self, bounds: [f64; 4]) -> Self {
+        let [minx, miny, maxx, maxy] = bounds;
+        self.envelope = vec![minx, maxx, miny, maxy];
+        self.envelope_dims = CoordDimensions::xy();
+        self
+    }
+
+    /// Write a value to the output, or to the buffered geometry
+    fn write<N: scroll::ctx::SizeWith<scroll::Endian> + scroll::ctx::IntoCtx<scroll::Endian>>(
+        &mut self,
+        n: N,
+    ) -> Result<()> {
+        let endian = self.endian.endian();
+        match &mut self.body {
+            Some(body) => body.iowrite_with(n, endian)?,
+            None => self.out.iowrite_with(n, endian)?,
+        }
+        Ok(())
+    }
+
+    fn extend_envelope(&mut self, x: f64, y: f64) {
+        if self.body.is_some() {
+            extend_bbox(&mut self.bbox, x, y);
+        }
+    }
+
+    /// Write GPKG header with computed envelope and buffered geometry at the end of the geometry
+    fn geom_end(&mut self) -> Result<()> {
+        self.depth = self.depth.saturating_sub(1);
+        if self.depth > 0 {
+            return Ok(());
+        }
+        if let Some(body) = self.body.take() {
+            if let Some([minx, miny, maxx, maxy]) = self.bbox.take() {
+                self.envelope = vec![minx, maxx, miny, maxy];
+                self.envelope_dims = CoordDimensions::xy();
+            }
+            self.write_gpkg_header()?;
+            self.out.write_all(&body)?;
+        }
+        Ok(())
+    }
+
+    /// Write element count, which is limited to u32 in WKB
+    fn write_count(&mut self, size: usize) -> Result<()> {
+        let count = u32::try_from(size).map_err(|_| {
+            GeozeroError::Geometry(format!("{size} elements exceed the WKB count limit"))
+        })?;
+        self.write(count)?;
+        Ok(())
+    }
+
     /// Write header in selected format
     fn write_header(&mut self, wkb_type: WKBGeometryType) -> Result<()> {
+        if self.geom_state != GeomState::MultiPointGeom {
+            self.depth += 1;
+        }
         match self.dialect {
             WkbDialect::Wkb => self.write_wkb_header(wkb_type),
             WkbDialect::Ewkb => self.write_ewkb_header(wkb_type),
             WkbDialect::Geopackage => {
                 if self.first_header {
-                    self.write_gpkg_header()?;
+                    if self.envelope.is_empty() && !self.empty {
+                        // header is written at the end of the geometry
+                        self.body = Some(Vec::new());
+                    } else {
+                        self.write_gpkg_header()?;
+                    }
                     self.first_header = false;
                 }
                 self.write_wkb_header(wkb_type)
@@ -63,12 +144,7 @@ impl<'a, W: Write> WkbWriter<'a, W> {
     }
     /// OGC WKB header
     fn write_wkb_header(&mut self, wkb_type: WKBGeometryType) -> Result<()> {
-        let byte_order = if self.endian == scroll::BE {
-            WKBByteOrder::Xdr
-        } else {
-            WKBByteOrder::Ndr
-        };
-        self.out.iowrite(byte_order as u8)?;
+        self.write(self.endian as u8)?;
         let mut type_id = wkb_type as u32;
         if self.dims.z {
             type_id += 1000;
@@ -76,18 +152,13 @@ impl<'a, W: Write> WkbWriter<'a, W> {
         if self.dims.m {
             type_id += 2000;
         }
-        self.out.iowrite_with(type_id, self.endian)?;
+        self.write(type_id)?;
         Ok(())
     }
 
     /// EWKB header according to https://git.osgeo.org/gitea/postgis/postgis/src/branch/master/doc/ZMSgeoms.txt
     fn write_ewkb_header(&mut self, wkb_type: WKBGeometryType) -> Result<()> {
-        let byte_order = if self.endian == scroll::BE {
-            WKBByteOrder::Xdr
-        } else {
-            WKBByteOrder::Ndr
-        };
-        self.out.iowrite(byte_order as u8)?;
+        self.write(self.endian as u8)?;
 
         let mut type_id = wkb_type as u32;
         if self.dims.z {
@@ -99,12 +170,12 @@ impl<'a, W: Write> WkbWriter<'a, W> {
         if self.srid.is_some() && self.first_header {
             type_id |= 0x2000_0000;
         }
-        self.out.iowrite_with(type_id, self.endian)?;
+        self.write(type_id)?;
 
         if self.first_header {
             // write SRID in main header only
             if let Some(srid) = self.srid {
-                self.out.iowrite_with(srid, self.endian)?;
+                self.write(srid)?;
             }
             self.first_header = false;
         }
@@ -137,7 +208,7 @@ impl<'a, W: Write> WkbWriter<'a, W> {
             }
         };
         flags |= env_info << 1;
-        if self.endian == scroll::LE {
+        if self.endian == WKBByteOrder::Ndr {
             flags |= 0b0000_0001;
         }
         // println!("flags: {:#010b}", flags);
@@ -146,10 +217,11 @@ impl<'a, W: Write> WkbWriter<'a, W> {
         // srs_id
         // 0: undefined geographic coordinate reference systems
         // -1: undefined Cartesian coordinate reference systems
-        self.out.iowrite_with(self.srid.unwrap_or(0), self.endian)?;
+        self.out
+            .iowrite_with(self.srid.unwrap_or(0), self.endian.endian())?;
 
         for val in &self.envelope {
-            self.out.iowrite_with(*val, self.endian)?;
+            self.out.iowrite_with(*val, self.endian.endian())?;
         }
 
         Ok(())
@@ -164,8 +236,9 @@ impl<W: Write> GeomProcessor for WkbWriter<'_, W> {
         if self.geom_state == GeomState::MultiPointGeom {
             self.write_header(WKBGeometryType::Point)?;
         }
-        self.out.iowrite_with(x, self.endian)?;
-        self.out.iowrite_with(y, self.endian)?;
+        self.extend_envelope(x, y);
+        self.write(x)?;
+        self.write(y)?;
         Ok(())
     }
     fn coordinate(
@@ -181,108 +254,148 @@ impl<W: Write> GeomProcessor for WkbWriter<'_, W> {
         if self.geom_state == GeomState::MultiPointGeom {
             self.write_header(WKBGeometryType::Point)?;
         }
-        self.out.iowrite_with(x, self.endian)?;
-        self.out.iowrite_with(y, self.endian)?;
-        if let Some(z) = z {
-            self.out.iowrite_with(z, self.endian)?;
+        self.extend_envelope(x, y);
+        self.write(x)?;
+        self.write(y)?;
+        if self.dims.z {
+            self.write(z.unwrap_or(f64::NAN))?;
         }
-        if let Some(m) = m {
-            self.out.iowrite_with(m, self.endian)?;
+        if self.dims.m {
+            self.write(m.unwrap_or(f64::NAN))?;
         }
         Ok(())
     }
     fn point_begin(&mut self, _idx: usize) -> Result<()> {
         self.write_header(WKBGeometryType::Point)
     }
+    fn point_end(&mut self, _idx: usize) -> Result<()> {
+        self.geom_end()
+    }
     fn multipoint_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
         self.write_header(WKBGeometryType::MultiPoint)?;
-        self.out.iowrite_with(size as u32, self.endian)?;
+        self.write_count(size)?;
         self.geom_state = GeomState::MultiPointGeom;
         Ok(())
     }
     fn multipoint_end(&mut self, _idx: usize) -> Result<()> {
         self.geom_state = GeomState::Normal;
-        Ok(())
+        self.geom_end()
     }
     fn linestring_begin(&mut self, _tagged: bool, size: usize, _idx: usize) -> Result<()> {
         if self.geom_state != GeomState::RingGeom {
             self.write_header(WKBGeometryType::LineString)?;
         }
-        self.out.iowrite_with(size as u32, self.endian)?;
+        self.write_count(size)?;
+        Ok(())
+    }
+    fn linestring_end(&mut self, _tagged: bool, _idx: usize) -> Result<()> {
+        if self.geom_state != GeomState::RingGeom {
+            self.geom_end()?;
+        }
         Ok(())
     }
     fn multilinestring_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
         self.write_header(WKBGeometryType::MultiLineString)?;
-        self.out.iowrite_with(size as u32, self.endian)?;
+        self.write_count(size)?;
         Ok(())
     }
+    fn multilinestring_end(&mut self, _idx: usize) -> Result<()> {
+        self.geom_end()
+    }
     fn polygon_begin(&mut self, _tagged: bool, size: usize, _idx: usize) -> Result<()> {
         self.write_header(WKBGeometryType::Polygon)?;
-        self.out.iowrite_with(size as u32, self.endian)?;
+        self.write_count(size)?;
         self.geom_state = GeomState::RingGeom;
         Ok(())
     }
     fn polygon_end(&mut self, _tagged: bool, _idx: usize) -> Result<()> {
         self.geom_state = GeomState::Normal;
-        Ok(())
+        self.geom_end()
     }
     fn multipolygon_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
         self.write_header(WKBGeometryType::MultiPolygon)?;
-        self.out.iowrite_with(size as u32, self.endian)?;
+        self.write_count(size)?;
         Ok(())
     }
+    fn multipolygon_end(&mut self, _idx: usize) -> Result<()> {
+        self.geom_end()
+    }
     fn geometrycollection_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
         self.write_header(WKBGeometryType::GeometryCollection)?;
-        self.out.iowrite_with(size as u32, self.endian)?;
+        self.write_count(size)?;
         Ok(())
     }
+    fn geometrycollection_end(&mut self, _idx: usize) -> Result<()> {
+        self.geom_end()
+    }
 
     fn circularstring_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
         self.write_header(WKBGeometryType::CircularString)?;
-        self.out.iowrite_with(size as u32, self.endian)?;
+        self.write_count(size)?;
         Ok(())
     }
+    fn circularstring_end(&mut self, _idx: usize) -> Result<()> {
+        self.geom_end()
+    }
     fn compoundcurve_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
         self.write_header(WKBGeometryType::CompoundCurve)?;
-        self.out.iowrite_with(size as u32, self.endian)?;
+        self.write_count(size)?;
         Ok(())
     }
+    fn compoundcurve_end(&mut self, _idx: usize) -> Result<()> {
+        self.geom_end()
+    }
     fn curvepolygon_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
         self.write_header(WKBGeometryType::CurvePolygon)?;
-        self.out.iowrite_with(size as u32, self.endian)?;
+        self.write_count(size)?;
         Ok(())
     }
+    fn curvepolygon_end(&mut self, _idx: usize) -> Result<()> {
+        self.geom_end()
+    }
     fn multicurve_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
         self.write_header(WKBGeometryType::MultiCurve)?;
-        self.out.iowrite_with(size as u32, self.endian)?;
+        self.write_count(size)?;
         Ok(())
     }
+    fn multicurve_end(&mut self, _idx: usize) -> Result<()> {
+        self.geom_end()
+    }
     fn multisurface_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
         self.write_header(WKBGeometryType::MultiSurface)?;
-        self.out.iowrite_with(size as u32, self.endian)?;
+        self.write_count(size)?;
         Ok(())
     }
+    fn multisurface_end(&mut self, _idx: usize) -> Result<()> {
+        self.geom_end()
+    }
 
     fn triangle_begin(&mut self, _tagged: bool, size: usize, _idx: usize) -> Result<()> {
         self.write_header(WKBGeometryType::Triangle)?;
-        self.out.iowrite_with(size as u32, self.endian)?;
+        self.write_count(size)?;
         self.geom_state = GeomState::RingGeom;
         Ok(())
     }
     fn triangle_end(&mut self, _tagged: bool, _idx: usize) -> Result<()> {
         self.geom_state = GeomState::Normal;
-        Ok(())
+        self.geom_end()
     }
     fn polyhedralsurface_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
         self.write_header(WKBGeometryType::PolyhedralSurface)?;
-        self.out.iowrite_with(size as u32, self.endian)?;
+        self.write_count(size)?;
         Ok(())
     }
+    fn polyhedralsurface_end(&mut self, _idx: usize) -> Result<()> {
+        self.geom_end()
+    }
     fn tin_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
         self.write_header(WKBGeometryType::Tin)?;
-        self.out.iowrite_with(size as u32, self.endian)?;
+        self.write_count(size)?;
         Ok(())
     }
+    fn tin_end(&mut self, _idx: usize) -> Result<()> {
+        self.geom_end()
+    }
 }
 
 impl<W: Write> PropertyProcessor for WkbWriter<'_, W> {}
@@ -309,6 +422,49 @@ mod test {
         ok
     }
 
+    #[test]
+    fn dimension_subset() {
+        // LINESTRING ZM (1 2 3 4,5 6 7 8)
+        let zm = hex::decode("01020000C002000000000000000000F03F000000000000004000000000000008400000000000001040000000000000144000000000000018400000000000001C400000000000002040").unwrap();
+        let mut wkb: Vec<u8> = Vec::new();
+        let mut writer = WkbWriter::new(&mut wkb, WkbDialect::Wkb);
+        writer.dims = CoordDimensions::xyz();
+        process_ewkb_geom(&mut zm.as_slice(), &mut writer).unwrap();
+        // LINESTRING Z type code and 2 * 3 ordinates
+        assert_eq!(wkb[1..5], 1002u32.to_le_bytes());
+        assert_eq!(wkb.len(), 1 + 4 + 4 + 2 * 3 * 8);
+
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = crate::wkt::WktWriter::new(&mut out);
+        writer.dims = CoordDimensions::xyzm();
+        crate::wkb::process_wkb_geom(&mut wkb.as_slice(), &mut writer).unwrap();
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            "LINESTRING(1 2 3,5 6 7)"
+        );
+    }
+
+    #[test]
+    fn fixed_byte_order() {
+        let write_point = |endian| {
+            let mut wkb: Vec<u8> = Vec::new();
+            let mut writer = WkbWriter::new(&mut wkb, WkbDialect::Wkb);
+            writer.endian = endian;
+            writer.point_begin(0).unwrap();
+            writer.xy(1.0, 2.0, 0).unwrap();
+            writer.point_end(0).unwrap();
+            hex::encode(wkb)
+        };
+        assert_eq!(
+            write_point(WKBByteOrder::Ndr),
+            "0101000000000000000000f03f0000000000000040"
+        );
+        assert_eq!(
+            write_point(WKBByteOrder::Xdr),
+            "00000000013ff00000000000004000000000000000"
+        );
+    }
+
     #[test]
     fn ewkb_geometries() {
         // SELECT 'POINT(10 -20)'::geometry
@@ -337,6 +493,92 @@ mod test {
         assert!(ewkb_roundtrip("01070000000300000001010000000000000000002440000000000000244001010000000000000000003E400000000000003E400102000000020000000000000000002E400000000000002E4000000000000034400000000000003440", false, None));
     }
 
+    fn to_ewkb(
+        dims: CoordDimensions,
+        srid: Option<i32>,
+        process: impl FnOnce(&mut WkbWriter<Vec<u8>>) -> Result<()>,
+    ) -> String {
+        let mut wkb_out: Vec<u8> = Vec::new();
+        let mut writer = WkbWriter::new(&mut wkb_out, WkbDialect::Ewkb);
+        writer.dims = dims;
+        writer.srid = srid;
+        process(&mut writer).unwrap();
+        hex::encode_upper(wkb_out)
+    }
+
+    #[test]
+    fn postgis_ewkb_output() {
+        // Expected values are the output of PostGIS ST_AsEWKB
+        use crate::wkt::WktStr;
+        use crate::GeozeroGeometry;
+        let xy = CoordDimensions::xy();
+
+        // SELECT ST_AsEWKB('POINT(10 -20)'::geometry)
+        assert_eq!(
+            to_ewkb(xy, None, |w| WktStr("POINT(10 -20)").process_geom(w)),
+            "0101000000000000000000244000000000000034C0"
+        );
+
+        // SELECT ST_AsEWKB('SRID=4326;POINT(10 -20)'::geometry)
+        assert_eq!(
+            to_ewkb(xy, Some(4326), |w| WktStr("POINT(10 -20)").process_geom(w)),
+            "0101000020E6100000000000000000244000000000000034C0"
+        );
+
+        // SELECT ST_AsEWKB('SRID=4326;POINTM(1 2 3)'::geometry)
+        assert_eq!(
+            to_ewkb(CoordDimensions::xym(), Some(4326), |w| {
+                w.point_begin(0)?;
+                w.coordinate(1.0, 2.0, None, Some(3.0), None, None, 0)?;
+                w.point_end(0)
+            }),
+            "0101000060E6100000000000000000F03F00000000000000400000000000000840"
+        );
+
+        // SELECT ST_AsEWKB('SRID=3857;LINESTRING(10 -20 100, 0 -0.5 101)'::geometry)
+        assert_eq!(
+            to_ewkb(CoordDimensions::xyz(), Some(3857), |w| {
+                w.linestring_begin(true, 2, 0)?;
+                w.coordinate(10.0, -20.0, Some(100.0), None, None, None, 0)?;
+                w.coordinate(0.0, -0.5, Some(101.0), None, None, None, 1)?;
+                w.linestring_end(true, 0)
+            }),
+            "01020000A0110F000002000000000000000000244000000000000034C000000000000059400000000000000000000000000000E0BF0000000000405940"
+        );
+
+        // SELECT ST_AsEWKB('SRID=4326;POLYGON((0 0,10 0,10 10,0 10,0 0),(2 2,2 4,4 4,4 2,2 2))'::geometry)
+        assert_eq!(
+            to_ewkb(xy, Some(4326), |w| WktStr("POLYGON((0 0,10 0,10 10,0 10,0 0),(2 2,2 4,4 4,4 2,2 2))").process_geom(w)),
+            "0103000020E610000002000000050000000000000000000000000000000000000000000000000024400000000000000000000000000000244000000000000024400000000000000000000000000000244000000000000000000000000000000000050000000000000000000040000000000000004000000000000000400000000000001040000000000000104000000000000010400000000000001040000000000000004000000000000000400000000000000040"
+        );
+
+        // SELECT ST_AsEWKB('SRID=4326;MULTIPOINT(1 2 3 4,5 6 7 8)'::geometry)
+        assert_eq!(
+            to_ewkb(CoordDimensions::xyzm(), Some(4326), |w| {
+                w.multipoint_begin(2, 0)?;
+                w.coordinate(1.0, 2.0, Some(3.0), Some(4.0), None, None, 0)?;
+                w.coordinate(5.0, 6.0, Some(7.0), Some(8.0), None, None, 1)?;
+                w.multipoint_end(0)
+            }),
+            "01040000E0E61000000200000001010000C0000000000000F03F00000000000000400000000000000840000000000000104001010000C0000000000000144000000000000018400000000000001C400000000000002040"
+        );
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn count_overflow() {
+        let mut wkb_out: Vec<u8> = Vec::new();
+        let mut writer = WkbWriter::new(&mut wkb_out, WkbDialect::Wkb);
+        let size = u32::MAX as usize + 1;
+        assert_eq!(
+            writer
+                .linestring_begin(true, size, 0)
+                .unwrap_err()
+                .to_string(),
+            "processing geometry `4294967296 elements exceed the WKB count limit`"
+        );
+    }
+
     #[test]
     fn ewkb_curves() {
         // SELECT 'CIRCULARSTRING(0 0,1 1,2 0)'::geometry
@@ -402,6 +644,44 @@ mod test {
             CoordDimensions::default(), Some(4326), vec![1.0, 22.0, 3.0, 22.0]));
     }
 
+    #[test]
+    fn gpkg_precomputed_envelope() {
+        let mut wkb_out: Vec<u8> = Vec::new();
+        let mut writer = WkbWriter::new(&mut wkb_out, WkbDialect::Geopackage)
+            .with_envelope([1.0, 2.0, 3.0, 4.0]);
+        writer.srid = Some(4326);
+        writer.linestring_begin(true, 2, 0).unwrap();
+        writer.xy(1.0, 2.0, 0).unwrap();
+        writer.xy(3.0, 4.0, 1).unwrap();
+        writer.linestring_end(true, 0).unwrap();
+        // flags: little endian, envelope [minx, maxx, miny, maxy]
+        assert_eq!(
+            &wkb_out[..8],
+            &[b'G', b'P', 0, 0b0000_0011, 0xE6, 0x10, 0, 0]
+        );
+        let envelope: Vec<f64> = wkb_out[8..40]
+            .chunks_exact(8)
+            .map(|b| f64::from_le_bytes(b.try_into().unwrap()))
+            .collect();
+        assert_eq!(envelope, [1.0, 3.0, 2.0, 4.0]);
+    }
+
+    #[test]
+    fn gpkg_computed_envelope() {
+        // mln3dzm without envelope
+        let wkb_in = hex::decode("47500001E610000001BD0B00000100000001BA0B0000020000000000000000003440000000000000244000000000000008400000000000001440000000000000244000000000000034400000000000001C400000000000000040").unwrap();
+        let mut wkb_out: Vec<u8> = Vec::new();
+        let mut writer = WkbWriter::new(&mut wkb_out, WkbDialect::Geopackage);
+        writer.dims = CoordDimensions::xyzm();
+        writer.srid = Some(4326);
+        process_gpkg_geom(&mut wkb_in.as_slice(), &mut writer).unwrap();
+        assert_eq!(writer.envelope, [10.0, 20.0, 10.0, 20.0]);
+        assert_eq!(
+            hex::encode(&wkb_out),
+            "47500003e6100000000000000000244000000000000034400000000000002440000000000000344001bd0b00000100000001ba0b0000020000000000000000003440000000000000244000000000000008400000000000001440000000000000244000000000000034400000000000001c400000000000000040"
+        );
+    }
+
     #[test]
     #[cfg(feature = "with-geo")]
     fn conversions() {
@@ -9,7 +9,8 @@ pub use wkt_writer::*;
 
 pub(crate) mod conversion {
     use crate::error::Result;
-    use crate::wkt::WktWriter;
+    use crate::processors::ExplodeProcessor;
+    use crate::wkt::{FmtWrite, WktWriter};
     use crate::{CoordDimensions, GeozeroGeometry};
 
     /// Convert to WKT.
@@ -18,6 +19,8 @@ pub(crate) mod conversion {
         fn to_wkt(&self) -> Result<String>;
         /// Convert to WKT String with dimensions.
         fn to_wkt_ndim(&self, dims: CoordDimensions) -> Result<String>;
+        /// Convert to 2D WKT Strings, one for each component of a multi geometry or collection.
+        fn to_wkt_components(&self) -> Result<Vec<String>>;
     }
 
     impl<T: GeozeroGeometry> ToWkt for T {
@@ -25,13 +28,51 @@ pub(crate) mod conversion {
             self.to_wkt_ndim(CoordDimensions::default())
         }
         fn to_wkt_ndim(&self, dims: CoordDimensions) -> Result<String> {
-            let mut out: Vec<u8> = Vec::new();
+            let mut out = FmtWrite(String::new());
             let mut writer = WktWriter::new(&mut out);
             writer.dims = dims;
             self.process_geom(&mut writer)?;
-            String::from_utf8(out).map_err(|_| {
-                crate::error::GeozeroError::Geometry("Invalid UTF-8 encoding".to_string())
-            })
+            Ok(out.0)
+        }
+        fn to_wkt_components(&self) -> Result<Vec<String>> {
+            let mut out = FmtWrite(String::new());
+            let mut writer = WktWriter::new(&mut out);
+            // the output is sliced at the terminators of the exploded top-level geometries
+            writer.record_terminator = Some("\n".to_string());
+            self.process_geom(&mut ExplodeProcessor::new(writer))?;
+            Ok(out.0.split_terminator('\n').map(String::from).collect())
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::*;
+        use crate::wkt::WktStr;
+
+        #[test]
+        fn components() {
+            assert_eq!(
+                WktStr("MULTILINESTRING((1 2,3 4),(5 6,7 8))")
+                    .to_wkt_components()
+                    .unwrap(),
+                vec!["LINESTRING(1 2,3 4)", "LINESTRING(5 6,7 8)"]
+            );
+            assert_eq!(
+                WktStr("GEOMETRYCOLLECTION(POINT EMPTY,POINT(1 2))")
+                    .to_wkt_components()
+                    .unwrap(),
+                vec!["POINT EMPTY", "POINT(1 2)"]
+            );
+            assert_eq!(
+                WktStr("GEOMETRYCOLLECTION(LINESTRING EMPTY,POLYGON((0 0,1 0,1 1,0 0)))")
+                    .to_wkt_components()
+                    .unwrap(),
+                vec!["LINESTRING EMPTY", "POLYGON((0 0,1 0,1 1,0 0))"]
+            );
+            assert_eq!(
+                WktStr("POINT(1 2)").to_wkt_components().unwrap(),
+                vec!["POINT(1 2)"]
+            );
         }
     }
 }
@@ -40,18 +81,15 @@ pub(crate) mod conversion {
 mod wkb {
     use crate::error::Result;
     use crate::wkb::{FromWkb, WkbDialect};
-    use crate::wkt::{WktString, WktWriter};
+    use crate::wkt::{FmtWrite, WktString, WktWriter};
     use std::io::Read;
 
     impl FromWkb for WktString {
         fn from_wkb<R: Read>(rdr: &mut R, dialect: WkbDialect) -> Result<Self> {
-            let mut out: Vec<u8> = Vec::new();
+            let mut out = FmtWrite(String::new());
             let mut writer = WktWriter::new(&mut out);
             crate::wkb::process_wkb_type_geom(rdr, &mut writer, dialect)?;
-            let wkt = String::from_utf8(out).map_err(|_| {
-                crate::error::GeozeroError::Geometry("Invalid UTF-8 encoding".to_string())
-            })?;
-            Ok(WktString(wkt))
+            Ok(WktString(out.0))
         }
     }
 }
@@ -1,54 +1,278 @@
-use crate::error::Result;
-use crate::{CoordDimensions, FeatureProcessor, GeomProcessor, PropertyProcessor};
-use std::io::Write;
+use crate::algorithm::{srid_precision, CoordFormat};
+use crate::error::{GeozeroError, Result};
+use crate::{AxisOrder, CoordDimensions, FeatureProcessor, GeomProcessor, PropertyProcessor};
+use std::fmt;
+use std::io;
+
+/// Output target of [WktWriter].
+///
+/// Implemented for all [std::io::Write] types. Use [FmtWrite] to write into
+/// a [std::fmt::Write] target like `String`.
+pub trait WktOutput {
+    fn write_fmt(&mut self, args: fmt::Arguments) -> Result<()>;
+}
+
+impl<W: io::Write> WktOutput for W {
+    fn write_fmt(&mut self, args: fmt::Arguments) -> Result<()> {
+        io::Write::write_fmt(self, args)?;
+        Ok(())
+    }
+}
+
+/// [std::fmt::Write] target for [WktWriter].
+pub struct FmtWrite<F: fmt::Write>(pub F);
+
+impl<F: fmt::Write> WktOutput for FmtWrite<F> {
+    fn write_fmt(&mut self, args: fmt::Arguments) -> Result<()> {
+        self.0.write_fmt(args).map_err(io::Error::other)?;
+        Ok(())
+    }
+}
+
+/// Formatting rules of [WktWriter].
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+pub enum WktDialect {
+    /// Geometry types without dimension tag, spacing according to the writer options.
+    ///
+    /// Write `POINT (10 20)` and `, ` separators with `space_before_paren` and
+    /// `space_after_comma`. No `Z`/`M` tags are written for any geometry type,
+    /// e.g. `POINT(1 2 3)` or `TIN(((0 0 0,0 1 0,1 0 1,0 0 0)))`, unlike ISO SQL/MM WKT.
+    #[default]
+    Untagged,
+    /// Output of PostGIS 3.x `ST_AsText`, e.g. `GEOMETRYCOLLECTION(POINT(1 2),POINT(3 4))`.
+    ///
+    /// All geometry types, including Triangle, TIN and PolyhedralSurface, get a dimension
+    /// tag according to `dims`, followed by a space, e.g. `POINT Z (1 2 3)`.
+    /// The spacing options of the writer are ignored.
+    PostGis,
+}
 
 /// WKT Writer.
-pub struct WktWriter<'a, W: Write> {
+///
+/// Coordinates are always written in positional decimal notation (e.g. `100000000000000000000`
+/// instead of `1e20`), since some WKT parsers don't accept scientific notation.
+/// Each value is written with the shortest digits parsing back to the same `f64`
+/// (e.g. `0.1` instead of `0.10000000000000001`), so written coordinates round-trip exactly,
+/// unless `precision` is set.
+pub struct WktWriter<'a, W: WktOutput> {
     pub dims: CoordDimensions,
+    /// Axis order of written coordinates
+    pub axis_order: AxisOrder,
+    /// Write a space between geometry type and opening paren, e.g. `POINT (10 20)`
+    pub space_before_paren: bool,
+    /// Write a space after commas, e.g. `LINESTRING(10 20, 30 40)`
+    pub space_after_comma: bool,
+    /// Write standalone linestrings as `LINEARRING` (extended WKT for rings)
+    pub linearring: bool,
+    /// Written after each top-level geometry, e.g. `"\n"` for newline-delimited WKT
+    pub record_terminator: Option<String>,
+    /// Token written for NaN Z and M values instead of `NaN`, e.g. `NULL`
+    ///
+    /// Some datasets use NaN measures to mark missing values (e.g. LiDAR intensities).
+    /// An empty token is rejected, since omitting single ordinates would mix dimensions.
+    pub nan_ordinate: Option<String>,
+    /// Number of decimal places of coordinate values, `None` for full precision
+    pub precision: Option<usize>,
+    /// Use [srid_precision] of the geometry SRID if `precision` is not set
+    pub precision_from_srid: bool,
+    /// Decimal separator of coordinate values (non-standard if not `.`)
+    pub decimal_separator: char,
+    /// Separator between coordinates and geometries (non-standard if not `,`)
+    ///
+    /// Writing coordinates fails if it is the same as `decimal_separator`.
+    pub coordinate_separator: char,
+    /// Write EWKT like PostGIS `ST_AsEWKT`
+    ///
+    /// Geometries are prefixed with `SRID=...;` and geometry types get an `M` suffix
+    /// if `dims` has M but no Z, e.g. `SRID=4326;POINTM(1 2 3)`.
+    pub ewkt: bool,
+    /// Formatting rules, ignored when writing EWKT
+    pub dialect: WktDialect,
+    pub(crate) state: WktState,
+    srid_precision: Option<usize>,
     out: &'a mut W,
 }
 
-impl<'a, W: Write> WktWriter<'a, W> {
+/// Nesting state of [WktWriter], kept by wrappers creating a writer per call.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct WktState {
+    depth: usize,
+    /// Opening paren of the current geometry not written yet
+    pending_open: bool,
+}
+
+impl<'a, W: WktOutput> WktWriter<'a, W> {
     pub fn new(out: &'a mut W) -> WktWriter<'a, W> {
         WktWriter {
             dims: CoordDimensions::default(),
+            axis_order: AxisOrder::default(),
+            space_before_paren: false,
+            space_after_comma: false,
+            linearring: false,
+            record_terminator: None,
+            nan_ordinate: None,
+            precision: None,
+            precision_from_srid: false,
+            srid_precision: None,
+            decimal_separator: '.',
+            coordinate_separator: ',',
+            ewkt: false,
+            dialect: WktDialect::default(),
+            state: WktState::default(),
             out,
         }
     }
     fn comma(&mut self, idx: usize) -> Result<()> {
+        self.flush_open()?;
         if idx > 0 {
-            self.out.write_all(b",")?;
+            write!(self.out, "{}", self.coordinate_separator)?;
+            if self.space_after_comma && !self.postgis() {
+                write!(self.out, " ")?;
+            }
         }
         Ok(())
     }
-    fn geom_begin(&mut self, idx: usize, tag: &[u8]) -> Result<()> {
-        self.comma(idx)?;
-        self.out.write_all(tag)?;
+    fn postgis(&self) -> bool {
+        self.dialect == WktDialect::PostGis && !self.ewkt
+    }
+    fn type_suffix(&self) -> &'static str {
+        match (self.ewkt, self.postgis(), self.dims.z, self.dims.m) {
+            (true, _, false, true) => "M",
+            (_, true, true, true) => " ZM",
+            (_, true, true, false) => " Z",
+            (_, true, false, true) => " M",
+            _ => "",
+        }
+    }
+    /// Write a geometry tag, the opening paren is deferred until content follows.
+    fn tag(&mut self, tag: &str) -> Result<()> {
+        self.flush_open()?;
+        write!(self.out, "{tag}{}", self.type_suffix())?;
+        self.state.depth += 1;
+        self.state.pending_open = true;
+        Ok(())
+    }
+    fn flush_open(&mut self) -> Result<()> {
+        if self.state.pending_open {
+            self.state.pending_open = false;
+            let space = if self.postgis() {
+                !self.type_suffix().is_empty()
+            } else {
+                self.space_before_paren
+            };
+            if space {
+                write!(self.out, " ")?;
+            }
+            write!(self.out, "(")?;
+        }
+        Ok(())
+    }
+    fn open(&mut self) -> Result<()> {
+        self.state.depth += 1;
+        write!(self.out, "(")?;
+        Ok(())
+    }
+    fn terminate(&mut self) -> Result<()> {
+        if self.state.depth == 0 {
+            // the SRID of the next geometry is reported separately
+            self.srid_precision = None;
+            if let Some(terminator) = &self.record_terminator {
+                write!(self.out, "{terminator}")?;
+            }
+        }
+        Ok(())
+    }
+    fn ordinate(&mut self, value: f64) -> Result<()> {
+        match &self.nan_ordinate {
+            Some(token) if token.is_empty() => {
+                return Err(GeozeroError::Geometry(
+                    "empty NaN ordinate token would mix dimensions".to_string(),
+                ));
+            }
+            Some(token) if value.is_nan() => {
+                write!(self.out, " {token}")?;
+            }
+            _ => {
+                write!(self.out, " ")?;
+                self.number(value)?;
+            }
+        }
         Ok(())
     }
-    fn tagged_geom_begin(&mut self, tagged: bool, idx: usize, tag: &[u8]) -> Result<()> {
+    fn number(&mut self, value: f64) -> Result<()> {
+        let value = CoordFormat {
+            value,
+            precision: self.precision.or(self.srid_precision),
+        };
+        if self.decimal_separator == '.' {
+            write!(self.out, "{value}")?;
+        } else {
+            let text = value.to_string();
+            write!(
+                self.out,
+                "{}",
+                text.replace('.', &self.decimal_separator.to_string())
+            )?;
+        }
+        Ok(())
+    }
+    fn xy_pair(&mut self, x: f64, y: f64) -> Result<()> {
+        if self.decimal_separator == self.coordinate_separator
+            || self.decimal_separator.is_whitespace()
+        {
+            return Err(GeozeroError::Geometry(format!(
+                "decimal separator '{}' collides with WKT separators",
+                self.decimal_separator
+            )));
+        }
+        let (x, y) = self.axis_order.order(x, y);
+        self.number(x)?;
+        write!(self.out, " ")?;
+        self.number(y)
+    }
+    fn geom_begin(&mut self, idx: usize, tag: &str) -> Result<()> {
+        self.comma(idx)?;
+        self.tag(tag)
+    }
+    fn tagged_geom_begin(&mut self, tagged: bool, idx: usize, tag: &str) -> Result<()> {
         self.comma(idx)?;
         if tagged {
-            self.out.write_all(tag)?;
+            self.tag(tag)?;
         } else {
-            self.out.write_all(b"(")?;
+            self.open()?;
         }
         Ok(())
     }
     fn geom_end(&mut self) -> Result<()> {
-        self.out.write_all(b")")?;
-        Ok(())
+        self.state.depth = self.state.depth.saturating_sub(1);
+        if self.state.pending_open {
+            self.state.pending_open = false;
+            write!(self.out, " EMPTY")?;
+        } else {
+            write!(self.out, ")")?;
+        }
+        self.terminate()
     }
 }
 
-impl<W: Write> GeomProcessor for WktWriter<'_, W> {
+impl<W: WktOutput> GeomProcessor for WktWriter<'_, W> {
     fn dimensions(&self) -> CoordDimensions {
         self.dims
     }
 
+    fn srid(&mut self, srid: Option<i32>) -> Result<()> {
+        if self.state.depth == 0 && self.precision_from_srid {
+            self.srid_precision = srid.and_then(srid_precision);
+        }
+        if let (true, 0, Some(srid)) = (self.ewkt, self.state.depth, srid) {
+            write!(self.out, "SRID={srid};")?;
+        }
+        Ok(())
+    }
+
     fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
         self.comma(idx)?;
-        self.out.write_all(format!("{x} {y}").as_bytes())?;
+        self.xy_pair(x, y)?;
         Ok(())
     }
 
@@ -63,119 +287,126 @@ impl<W: Write> GeomProcessor for WktWriter<'_, W> {
         idx: usize,
     ) -> Result<()> {
         self.comma(idx)?;
-        self.out.write_all(format!("{x} {y}").as_bytes())?;
+        self.xy_pair(x, y)?;
         if let Some(z) = z {
-            self.out.write_all(format!(" {z}").as_bytes())?;
+            self.ordinate(z)?;
         }
         if let Some(m) = m {
-            self.out.write_all(format!(" {m}").as_bytes())?;
+            self.ordinate(m)?;
         }
         Ok(())
     }
 
     fn empty_point(&mut self, idx: usize) -> Result<()> {
-        self.geom_begin(idx, b"POINT EMPTY")
+        self.comma(idx)?;
+        write!(self.out, "POINT{} EMPTY", self.type_suffix())?;
         // we intentionally omit calling geom_end(), because POINT EMPTY has no closing paren
+        self.terminate()
     }
     fn point_begin(&mut self, idx: usize) -> Result<()> {
-        self.geom_begin(idx, b"POINT(")
+        self.geom_begin(idx, "POINT")
     }
     fn point_end(&mut self, _idx: usize) -> Result<()> {
         self.geom_end()
     }
     fn multipoint_begin(&mut self, _size: usize, idx: usize) -> Result<()> {
-        self.geom_begin(idx, b"MULTIPOINT(")
+        self.geom_begin(idx, "MULTIPOINT")
     }
     fn multipoint_end(&mut self, _idx: usize) -> Result<()> {
         self.geom_end()
     }
     fn linestring_begin(&mut self, tagged: bool, _size: usize, idx: usize) -> Result<()> {
-        self.tagged_geom_begin(tagged, idx, b"LINESTRING(")
+        let tag = if self.linearring {
+            "LINEARRING"
+        } else {
+            "LINESTRING"
+        };
+        self.tagged_geom_begin(tagged, idx, tag)
     }
     fn linestring_end(&mut self, _tagged: bool, _idx: usize) -> Result<()> {
         self.geom_end()
     }
     fn multilinestring_begin(&mut self, _size: usize, idx: usize) -> Result<()> {
-        self.geom_begin(idx, b"MULTILINESTRING(")
+        self.geom_begin(idx, "MULTILINESTRING")
     }
     fn multilinestring_end(&mut self, _idx: usize) -> Result<()> {
         self.geom_end()
     }
     fn polygon_begin(&mut self, tagged: bool, _size: usize, idx: usize) -> Result<()> {
-        self.tagged_geom_begin(tagged, idx, b"POLYGON(")
+        self.tagged_geom_begin(tagged, idx, "POLYGON")
     }
     fn polygon_end(&mut self, _tagged: bool, _idx: usize) -> Result<()> {
         self.geom_end()
     }
     fn multipolygon_begin(&mut self, _size: usize, idx: usize) -> Result<()> {
-        self.geom_begin(idx, b"MULTIPOLYGON(")
+        self.geom_begin(idx, "MULTIPOLYGON")
     }
     fn multipolygon_end(&mut self, _idx: usize) -> Result<()> {
         self.geom_end()
     }
     fn geometrycollection_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
-        self.out.write_all(b"GEOMETRYCOLLECTION(")?;
-        Ok(())
+        self.tag("GEOMETRYCOLLECTION")
     }
     fn geometrycollection_end(&mut self, _idx: usize) -> Result<()> {
         self.geom_end()
     }
     fn circularstring_begin(&mut self, _size: usize, idx: usize) -> Result<()> {
-        self.geom_begin(idx, b"CIRCULARSTRING(")
+        self.geom_begin(idx, "CIRCULARSTRING")
     }
     fn circularstring_end(&mut self, _idx: usize) -> Result<()> {
         self.geom_end()
     }
     fn compoundcurve_begin(&mut self, _size: usize, idx: usize) -> Result<()> {
-        self.geom_begin(idx, b"COMPOUNDCURVE(")
+        self.geom_begin(idx, "COMPOUNDCURVE")
     }
     fn compoundcurve_end(&mut self, _idx: usize) -> Result<()> {
         self.geom_end()
     }
     fn curvepolygon_begin(&mut self, _size: usize, idx: usize) -> Result<()> {
-        self.geom_begin(idx, b"CURVEPOLYGON(")
+        self.geom_begin(idx, "CURVEPOLYGON")
     }
     fn curvepolygon_end(&mut self, _idx: usize) -> Result<()> {
         self.geom_end()
     }
     fn multicurve_begin(&mut self, _size: usize, idx: usize) -> Result<()> {
-        self.geom_begin(idx, b"MULTICURVE(")
+        self.geom_begin(idx, "MULTICURVE")
     }
     fn multicurve_end(&mut self, _idx: usize) -> Result<()> {
         self.geom_end()
     }
     fn multisurface_begin(&mut self, _size: usize, idx: usize) -> Result<()> {
-        self.geom_begin(idx, b"MULTISURFACE(")
+        self.geom_begin(idx, "MULTISURFACE")
     }
     fn multisurface_end(&mut self, _idx: usize) -> Result<()> {
         self.geom_end()
     }
     fn triangle_begin(&mut self, tagged: bool, _size: usize, idx: usize) -> Result<()> {
-        self.tagged_geom_begin(tagged, idx, b"TRIANGLE(")
+        self.tagged_geom_begin(tagged, idx, "TRIANGLE")
     }
     fn triangle_end(&mut self, _tagged: bool, _idx: usize) -> Result<()> {
         self.geom_end()
     }
     fn polyhedralsurface_begin(&mut self, _size: usize, idx: usize) -> Result<()> {
-        self.geom_begin(idx, b"POLYHEDRALSURFACE(")
+        self.geom_begin(idx, "POLYHEDRALSURFACE")
     }
     fn polyhedralsurface_end(&mut self, _idx: usize) -> Result<()> {
         self.geom_end()
     }
     fn tin_begin(&mut self, _size: usize, idx: usize) -> Result<()> {
-        self.geom_begin(idx, b"TIN(")
+        self.geom_begin(idx, "TIN")
     }
     fn tin_end(&mut self, _idx: usize) -> Result<()> {
         self.geom_end()
     }
 }
 
-impl<W: Write> PropertyProcessor for WktWriter<'_, W> {}
+impl<W: WktOutput> PropertyProcessor for WktWriter<'_, W> {}
 
-impl<W: Write> FeatureProcessor for WktWriter<'_, W> {}
+impl<W: WktOutput> FeatureProcessor for WktWriter<'_, W> {}
 
 #[cfg(test)]
 mod test {
+    use super::*;
     use crate::ToWkt;
 
     #[test]
@@ -184,4 +415,334 @@ mod test {
         let geom: geo_types::Geometry<f64> = geo_types::Point::new(10.0, 20.0).into();
         assert_eq!(&geom.to_wkt().unwrap(), "POINT(10 20)");
     }
+
+    #[test]
+    #[cfg(feature = "with-geo")]
+    fn axis_order() {
+        use crate::GeozeroGeometry;
+        let geom: geo_types::Geometry<f64> = geo_types::Point::new(10.0, 20.0).into();
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = WktWriter::new(&mut out);
+        writer.axis_order = AxisOrder::LatLon;
+        geom.process_geom(&mut writer).unwrap();
+        assert_eq!(std::str::from_utf8(&out).unwrap(), "POINT(20 10)");
+    }
+
+    #[test]
+    fn no_scientific_notation() {
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = WktWriter::new(&mut out);
+        writer.point_begin(0).unwrap();
+        writer.xy(1e20, 1e-20, 0).unwrap();
+        writer.point_end(0).unwrap();
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            "POINT(100000000000000000000 0.00000000000000000001)"
+        );
+    }
+
+    #[test]
+    fn shortest_roundtrip() {
+        use crate::wkt::WktStr;
+        use crate::GeozeroGeometry;
+
+        struct Coords(Vec<f64>);
+        impl GeomProcessor for Coords {
+            fn xy(&mut self, x: f64, y: f64, _idx: usize) -> Result<()> {
+                self.0.extend([x, y]);
+                Ok(())
+            }
+        }
+
+        let values = [
+            0.1,
+            1.0 / 3.0,
+            -0.0,
+            0.1 + 0.2,
+            f64::MIN_POSITIVE,
+            5e-324,
+            f64::MAX,
+            -1.2345678901234567e-7,
+            9007199254740993.0,
+            123456.789e3,
+        ];
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = WktWriter::new(&mut out);
+        writer.multipoint_begin(values.len() / 2, 0).unwrap();
+        for (i, xy) in values.chunks(2).enumerate() {
+            writer.xy(xy[0], xy[1], i).unwrap();
+        }
+        writer.multipoint_end(0).unwrap();
+        let wkt = String::from_utf8(out).unwrap();
+        assert!(wkt.starts_with("MULTIPOINT(0.1 0.3333333333333333,-0 0.30000000000000004,"));
+
+        let mut coords = Coords(Vec::new());
+        WktStr(&wkt).process_geom(&mut coords).unwrap();
+        let bits = |v: &[f64]| v.iter().map(|v| v.to_bits()).collect::<Vec<_>>();
+        assert_eq!(bits(&coords.0), bits(&values));
+    }
+
+    #[test]
+    fn separator_styles() {
+        use crate::wkt::WktStr;
+        use crate::GeozeroGeometry;
+        let wkt = WktStr("GEOMETRYCOLLECTION(POINT(1 2),POLYGON((0 0,1 0,1 1,0 0)))");
+
+        let mut out: Vec<u8> = Vec::new();
+        wkt.process_geom(&mut WktWriter::new(&mut out)).unwrap();
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            "GEOMETRYCOLLECTION(POINT(1 2),POLYGON((0 0,1 0,1 1,0 0)))"
+        );
+
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = WktWriter::new(&mut out);
+        writer.space_before_paren = true;
+        writer.space_after_comma = true;
+        wkt.process_geom(&mut writer).unwrap();
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            "GEOMETRYCOLLECTION (POINT (1 2), POLYGON ((0 0, 1 0, 1 1, 0 0)))"
+        );
+    }
+
+    #[test]
+    fn postgis_dialect() {
+        use crate::wkt::WktStr;
+        use crate::GeozeroGeometry;
+
+        let astext = |wkt: &str, dims: CoordDimensions| {
+            let mut out: Vec<u8> = Vec::new();
+            let mut writer = WktWriter::new(&mut out);
+            writer.dialect = WktDialect::PostGis;
+            writer.dims = dims;
+            writer.space_before_paren = true;
+            writer.space_after_comma = true;
+            WktStr(wkt).process_geom(&mut writer).unwrap();
+            String::from_utf8(out).unwrap()
+        };
+        // SELECT ST_AsText('POINT(1 2)')
+        assert_eq!(astext("POINT(1 2)", CoordDimensions::xy()), "POINT(1 2)");
+        // SELECT ST_AsText('GEOMETRYCOLLECTION(POINT(1 2), LINESTRING(0 0, 1 1))')
+        assert_eq!(
+            astext(
+                "GEOMETRYCOLLECTION(POINT(1 2),LINESTRING(0 0,1 1))",
+                CoordDimensions::xy()
+            ),
+            "GEOMETRYCOLLECTION(POINT(1 2),LINESTRING(0 0,1 1))"
+        );
+
+        // SELECT ST_AsText('GEOMETRYCOLLECTION(POINT(1 2 3),MULTIPOINT(0 0 0,1 1 1))')
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = WktWriter::new(&mut out);
+        writer.dialect = WktDialect::PostGis;
+        writer.dims = CoordDimensions::xyz();
+        writer.geometrycollection_begin(2, 0).unwrap();
+        writer.point_begin(0).unwrap();
+        writer
+            .coordinate(1.0, 2.0, Some(3.0), None, None, None, 0)
+            .unwrap();
+        writer.point_end(0).unwrap();
+        writer.multipoint_begin(2, 1).unwrap();
+        writer
+            .coordinate(0.0, 0.0, Some(0.0), None, None, None, 0)
+            .unwrap();
+        writer
+            .coordinate(1.0, 1.0, Some(1.0), None, None, None, 1)
+            .unwrap();
+        writer.multipoint_end(1).unwrap();
+        writer.geometrycollection_end(0).unwrap();
+        writer.empty_point(0).unwrap();
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            "GEOMETRYCOLLECTION Z (POINT Z (1 2 3),MULTIPOINT Z (0 0 0,1 1 1))POINT Z EMPTY"
+        );
+    }
+
+    #[test]
+    fn dialect_dimension_tags() {
+        // SELECT ST_AsText('TIN Z (((0 0 0,0 1 0,1 0 1,0 0 0)))')
+        let write_tin = |dialect| {
+            let mut out: Vec<u8> = Vec::new();
+            let mut writer = WktWriter::new(&mut out);
+            writer.dialect = dialect;
+            writer.dims = CoordDimensions::xyz();
+            writer.tin_begin(1, 0).unwrap();
+            writer.triangle_begin(false, 1, 0).unwrap();
+            writer.linestring_begin(false, 4, 0).unwrap();
+            for (i, (x, y, z)) in [(0., 0., 0.), (0., 1., 0.), (1., 0., 1.), (0., 0., 0.)]
+                .into_iter()
+                .enumerate()
+            {
+                writer
+                    .coordinate(x, y, Some(z), None, None, None, i)
+                    .unwrap();
+            }
+            writer.linestring_end(false, 0).unwrap();
+            writer.triangle_end(false, 0).unwrap();
+            writer.tin_end(0).unwrap();
+            String::from_utf8(out).unwrap()
+        };
+        assert_eq!(
+            write_tin(WktDialect::PostGis),
+            "TIN Z (((0 0 0,0 1 0,1 0 1,0 0 0)))"
+        );
+        assert_eq!(
+            write_tin(WktDialect::Untagged),
+            "TIN(((0 0 0,0 1 0,1 0 1,0 0 0)))"
+        );
+    }
+
+    #[test]
+    fn decimal_separator() {
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = WktWriter::new(&mut out);
+        writer.decimal_separator = ',';
+        writer.linestring_begin(true, 2, 0).unwrap();
+        assert!(writer.xy(1.5, 2.0, 0).is_err());
+
+        writer.coordinate_separator = ';';
+        writer.dims = CoordDimensions::xyz();
+        writer.xy(1.5, 2.0, 0).unwrap();
+        writer
+            .coordinate(3.0, -4.25, Some(0.5), None, None, None, 1)
+            .unwrap();
+        writer.linestring_end(true, 0).unwrap();
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            "LINESTRING(1,5 2;3 -4,25 0,5)"
+        );
+    }
+
+    #[test]
+    fn fmt_write() {
+        let mut out = FmtWrite(String::new());
+        let mut writer = WktWriter::new(&mut out);
+        writer.point_begin(0).unwrap();
+        writer.xy(10.0, 20.0, 0).unwrap();
+        writer.point_end(0).unwrap();
+        assert_eq!(out.0, "POINT(10 20)");
+    }
+
+    #[test]
+    fn linearring() {
+        use crate::wkt::WktStr;
+        use crate::GeozeroGeometry;
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = WktWriter::new(&mut out);
+        writer.linearring = true;
+        WktStr("LINESTRING(0 0,1 0,1 1,0 0)")
+            .process_geom(&mut writer)
+            .unwrap();
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            "LINEARRING(0 0,1 0,1 1,0 0)"
+        );
+    }
+
+    #[test]
+    fn record_terminator() {
+        use crate::wkt::WktStr;
+        use crate::GeozeroGeometry;
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = WktWriter::new(&mut out);
+        writer.record_terminator = Some("\n".to_string());
+        WktStr("GEOMETRYCOLLECTION(POINT(1 2),LINESTRING(0 0,1 1))")
+            .process_geom(&mut writer)
+            .unwrap();
+        WktStr("POINT EMPTY").process_geom(&mut writer).unwrap();
+        WktStr("POINT(3 4)").process_geom(&mut writer).unwrap();
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            "GEOMETRYCOLLECTION(POINT(1 2),LINESTRING(0 0,1 1))\nPOINT EMPTY\nPOINT(3 4)\n"
+        );
+    }
+
+    #[test]
+    fn precision_from_srid() {
+        let write_point = |srid: i32, precision: Option<usize>| {
+            let mut out: Vec<u8> = Vec::new();
+            let mut writer = WktWriter::new(&mut out);
+            writer.precision_from_srid = true;
+            writer.precision = precision;
+            writer.srid(Some(srid)).unwrap();
+            writer.point_begin(0).unwrap();
+            writer.xy(8.123456789, 47.123456789, 0).unwrap();
+            writer.point_end(0).unwrap();
+            String::from_utf8(out).unwrap()
+        };
+        assert_eq!(write_point(4326, None), "POINT(8.1234568 47.1234568)");
+        assert_eq!(write_point(3857, None), "POINT(8.12 47.12)");
+        assert_eq!(write_point(3857, Some(4)), "POINT(8.1235 47.1235)");
+        assert_eq!(write_point(0, None), "POINT(8.123456789 47.123456789)");
+
+        // geometry without SRID after a 4326 geometry
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = WktWriter::new(&mut out);
+        writer.precision_from_srid = true;
+        writer.record_terminator = Some("\n".to_string());
+        writer.srid(Some(4326)).unwrap();
+        for _ in 0..2 {
+            writer.point_begin(0).unwrap();
+            writer.xy(8.123456789, 47.123456789, 0).unwrap();
+            writer.point_end(0).unwrap();
+        }
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "POINT(8.1234568 47.1234568)\nPOINT(8.123456789 47.123456789)\n"
+        );
+    }
+
+    #[test]
+    fn nan_ordinate() {
+        let write_line = |nan_ordinate: Option<&str>, z: f64, m: f64| {
+            let mut out: Vec<u8> = Vec::new();
+            let mut writer = WktWriter::new(&mut out);
+            writer.dims = CoordDimensions::xyzm();
+            writer.nan_ordinate = nan_ordinate.map(str::to_string);
+            writer.linestring_begin(true, 2, 0)?;
+            writer.coordinate(1.0, 2.0, Some(z), Some(m), None, None, 0)?;
+            writer.coordinate(4.0, 5.0, Some(6.0), Some(7.0), None, None, 1)?;
+            writer.linestring_end(true, 0)?;
+            Ok::<_, GeozeroError>(String::from_utf8(out).unwrap())
+        };
+        let nan = f64::NAN;
+        assert_eq!(
+            write_line(None, 3.0, nan).unwrap(),
+            "LINESTRING(1 2 3 NaN,4 5 6 7)"
+        );
+        assert_eq!(
+            write_line(Some("NULL"), 3.0, nan).unwrap(),
+            "LINESTRING(1 2 3 NULL,4 5 6 7)"
+        );
+        assert_eq!(
+            write_line(Some("NULL"), nan, 4.0).unwrap(),
+            "LINESTRING(1 2 NULL 4,4 5 6 7)"
+        );
+        // omitting ordinates would mix dimensions
+        assert!(write_line(Some(""), 3.0, nan).is_err());
+        assert!(write_line(Some(""), nan, 4.0).is_err());
+    }
+
+    #[test]
+    fn empty_geometries() {
+        use crate::wkt::WktStr;
+        use crate::GeozeroGeometry;
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = WktWriter::new(&mut out);
+        writer.linestring_begin(true, 0, 0).unwrap();
+        writer.linestring_end(true, 0).unwrap();
+        assert_eq!(std::str::from_utf8(&out).unwrap(), "LINESTRING EMPTY");
+
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = WktWriter::new(&mut out);
+        writer.space_before_paren = true;
+        WktStr("GEOMETRYCOLLECTION(POLYGON EMPTY,POINT(1 2))")
+            .process_geom(&mut writer)
+            .unwrap();
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            "GEOMETRYCOLLECTION (POLYGON EMPTY,POINT (1 2))"
+        );
+    }
 }